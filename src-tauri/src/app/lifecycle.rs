@@ -3,14 +3,22 @@
 //! This module handles window events and application run events.
 //! Platform-specific behavior is delegated to the platform modules.
 
-use tauri::{AppHandle, RunEvent, WindowEvent};
+use crate::events::EventSubscriptions;
+use tauri::{AppHandle, Manager, RunEvent, WindowEvent};
 
 /// Handle window events
 ///
 /// On macOS, windows are hidden instead of closed to keep the app in the dock.
 /// On Linux and Windows, the default close behavior (quit app) is used.
+/// Destroyed windows have their event subscriptions released.
 #[allow(unused_variables)]
 pub fn handle_window_event(window: &tauri::Window, event: &WindowEvent) {
+    if let WindowEvent::Destroyed = event {
+        if let Some(subscriptions) = window.app_handle().try_state::<EventSubscriptions>() {
+            subscriptions.unsubscribe(window.label());
+        }
+    }
+
     #[cfg(target_os = "macos")]
     if let WindowEvent::CloseRequested { api, .. } = event {
         use crate::platform::macos::lifecycle;
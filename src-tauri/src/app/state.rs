@@ -4,21 +4,38 @@
 //! including the database and background service states.
 
 use crate::db::Database;
-use crate::events::EventBus;
+use crate::events::{EventBus, EventSubscriptions};
+use crate::services::activity::ProjectActivityCache;
+use crate::services::backup::BackupServiceState;
+use crate::services::batch::BatchServiceState;
 use crate::services::cleanup::CleanupServiceState;
+use crate::services::clone_queue::CloneQueueState;
 use crate::services::diagnostics::DiagnosticsServiceState;
+use crate::services::fetch_scheduler::FetchSchedulerState;
 use crate::services::folder_scanner::FolderScanServiceState;
+use crate::services::health::ProjectHealthCache;
+use crate::services::providers::ProviderStatusState;
 use tauri::{App, Manager};
 
 /// Initialize all managed state for the application
 pub fn init_state(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     // Initialize database
     let database = Database::new().expect("Failed to initialize database");
+
+    // Load telemetry consent and install the crash reporter
+    crate::services::telemetry::init(&database);
+
+    // Restore persisted per-module log level overrides
+    restore_log_overrides(&database);
+
     app.manage(database);
 
     // Initialize event bus
     app.manage(EventBus::new());
 
+    // Initialize per-window event subscriptions
+    app.manage(EventSubscriptions::new());
+
     // Initialize cleanup service state
     app.manage(CleanupServiceState::default());
 
@@ -28,9 +45,47 @@ pub fn init_state(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     // Initialize diagnostics service state
     app.manage(DiagnosticsServiceState::default());
 
+    // Initialize background fetch scheduler state
+    app.manage(FetchSchedulerState::default());
+
+    // Initialize batch queue state
+    app.manage(BatchServiceState::default());
+
+    // Initialize backup service state
+    app.manage(BackupServiceState::default());
+
+    // Initialize project health cache
+    app.manage(ProjectHealthCache::default());
+
+    // Initialize project activity cache
+    app.manage(ProjectActivityCache::default());
+
+    // Initialize clone queue state
+    app.manage(CloneQueueState::default());
+
+    // Initialize provider status refresh state
+    app.manage(ProviderStatusState::default());
+
     Ok(())
 }
 
+/// Apply log level overrides saved by `set_log_level`
+///
+/// Overrides missing from the settings are cleared, so this also resets the
+/// live levels after the settings were replaced by a backup import.
+pub(crate) fn restore_log_overrides(database: &Database) {
+    let overrides = database
+        .get_setting(crate::logging::LOG_LEVEL_OVERRIDES_KEY)
+        .ok()
+        .flatten()
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+
+    if let Err(e) = crate::logging::set_log_overrides(overrides) {
+        tracing::warn!("Ignoring invalid log level overrides: {}", e);
+    }
+}
+
 /// Run startup tasks that depend on state being initialized
 pub fn run_startup_tasks(app: &App) {
     // Sync editors on startup
@@ -66,4 +121,31 @@ pub fn start_background_services(app: &App) {
     tauri::async_runtime::spawn(async move {
         crate::services::diagnostics::start_diagnostics_service(app_handle).await;
     });
+
+    // Start batch queue worker
+    let app_handle = app.handle().clone();
+    tauri::async_runtime::spawn(async move {
+        crate::services::batch::start_batch_service(app_handle).await;
+    });
+
+    // Start background fetch scheduler
+    let app_handle = app.handle().clone();
+    tauri::async_runtime::spawn(async move {
+        crate::services::fetch_scheduler::start_fetch_scheduler(app_handle).await;
+    });
+
+    // Start scheduled backup service
+    let app_handle = app.handle().clone();
+    tauri::async_runtime::spawn(async move {
+        crate::services::backup::start_backup_service(app_handle).await;
+    });
+
+    // Detect primary languages for `lang:` search in the background
+    crate::commands::projects::spawn_language_backfill(app.handle().clone());
+
+    // Start pull request / CI status refresh
+    let app_handle = app.handle().clone();
+    tauri::async_runtime::spawn(async move {
+        crate::services::providers::start_provider_status_service(app_handle).await;
+    });
 }
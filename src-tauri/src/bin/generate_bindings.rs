@@ -23,10 +23,37 @@ use panager_lib::db::models::{
     CreateProjectLinkRequest, CreateProjectGroupRequest, CreateProjectCommandRequest,
     CreateScopeRequest, CreateSshAliasRequest, TempProjectProgress, TempProjectRequest,
     TempProjectResult, CommandResult,
+    // Template models
+    ProjectTemplate, TemplateKind, TemplateVariable, CreateProjectTemplateRequest,
+    UpdateProjectTemplateRequest, CreateFromTemplateRequest,
 };
+use panager_lib::events::{EventCategory, EventSubscription};
+use panager_lib::logging::LogSettings;
+use panager_lib::services::importers::{
+    ExternalProjectImport, ImportCandidate, ImportResult, ImportSource,
+};
+use panager_lib::services::activity::{ActivityDay, AuthorActivity, ProjectActivity};
+use panager_lib::services::archive::{ArchiveFormat, ProjectArchive};
+use panager_lib::services::backup::{BackupFormat, BackupInfo, BackupSummary};
+use panager_lib::services::batch::{
+    BatchItemError, BatchItemStatus, BatchOperation, BatchProgress, BatchSummary,
+};
+use panager_lib::services::clone_queue::{
+    CloneJob, CloneJobStatus, CloneRequest, CloneStage, CredentialRequest,
+};
+use panager_lib::services::fetch_scheduler::{
+    PlatformConditions, ScopeFetchPolicy, ScopeFetchResult,
+};
+use panager_lib::git::{DirtyWorkPolicy, FolderOperation, ProjectPreflight, WorkInspection};
+use panager_lib::services::providers::{
+    CiState, CloneRemoteRequest, CloneRemoteResult, DeviceAuthorization, ProjectCiStatus,
+    ProviderAccount, ProviderKind, PullRequestSummary, RemoteRepository, RepositoryFilter,
+};
+use panager_lib::services::health::{HealthDiagnostics, HealthStatus, ProjectHealth};
+use panager_lib::services::telemetry::{TelemetryKind, TelemetryReport, TelemetryStatus};
 use panager_lib::services::diagnostics::{
-    DiagnosticFix, DiagnosticIssue, DisabledRule, RuleGroup, RuleMetadata, ScanState, Severity,
-    ScopeDiagnosticsSummary,
+    DiagnosticFix, DiagnosticIssue, DisabledRule, RuleGroup, RuleMetadata, ScanState, ScopePolicy,
+    Severity, ScopeDiagnosticsSummary,
 };
 
 fn main() {
@@ -121,7 +148,76 @@ fn main() {
         DiagnosticFix,
         DisabledRule,
         ScanState,
-        ScopeDiagnosticsSummary
+        ScopePolicy,
+        ScopeDiagnosticsSummary,
+        // Events
+        EventCategory,
+        EventSubscription,
+        // Telemetry
+        TelemetryKind,
+        TelemetryReport,
+        TelemetryStatus,
+        // Logging
+        LogSettings,
+        // Project Templates
+        TemplateKind,
+        TemplateVariable,
+        ProjectTemplate,
+        CreateProjectTemplateRequest,
+        UpdateProjectTemplateRequest,
+        CreateFromTemplateRequest,
+        // Project Archiving
+        ArchiveFormat,
+        ProjectArchive,
+        // Database Backup
+        BackupFormat,
+        BackupSummary,
+        BackupInfo,
+        // Batch Operations
+        BatchOperation,
+        BatchItemStatus,
+        BatchProgress,
+        BatchItemError,
+        BatchSummary,
+        // External Project Import
+        ImportSource,
+        ImportCandidate,
+        ExternalProjectImport,
+        ImportResult,
+        // Project Health
+        HealthStatus,
+        HealthDiagnostics,
+        ProjectHealth,
+        // Project Activity
+        ActivityDay,
+        AuthorActivity,
+        ProjectActivity,
+        // Background Fetch
+        ScopeFetchPolicy,
+        PlatformConditions,
+        ScopeFetchResult,
+        // Folder Operation Preflight
+        FolderOperation,
+        DirtyWorkPolicy,
+        WorkInspection,
+        ProjectPreflight,
+        // Clone Queue
+        CloneJobStatus,
+        CloneStage,
+        CloneRequest,
+        CloneJob,
+        CredentialRequest,
+        // Git Providers
+        ProviderKind,
+        DeviceAuthorization,
+        ProviderAccount,
+        RemoteRepository,
+        RepositoryFilter,
+        CloneRemoteRequest,
+        CloneRemoteResult,
+        CiState,
+        ProjectCiStatus,
+        PullRequestSummary
     );
 
     // Write to file
@@ -133,5 +229,19 @@ fn main() {
     println!("  - Project models: Project, GitStatusCache, ProjectWithStatus");
     println!("  - Editor models: Editor, SshAlias");
     println!("  - DTOs: CreateScopeRequest, CreateSshAliasRequest, CreateProjectRequest, CreateScopeLinkRequest, TempProjectRequest, TempProjectResult, TempProjectProgress, CloneOptions, CloneResult, CloneProgress");
-    println!("  - Diagnostics: Severity, RuleGroup, RuleMetadata, DiagnosticIssue, DiagnosticFix, DisabledRule, ScanState, ScopeDiagnosticsSummary");
+    println!("  - Diagnostics: Severity, RuleGroup, RuleMetadata, DiagnosticIssue, DiagnosticFix, DisabledRule, ScanState, ScopePolicy, ScopeDiagnosticsSummary");
+    println!("  - Events: EventCategory, EventSubscription");
+    println!("  - Telemetry: TelemetryKind, TelemetryReport, TelemetryStatus");
+    println!("  - Logging: LogSettings");
+    println!("  - Templates: TemplateKind, TemplateVariable, ProjectTemplate, CreateProjectTemplateRequest, UpdateProjectTemplateRequest, CreateFromTemplateRequest");
+    println!("  - Archiving: ArchiveFormat, ProjectArchive");
+    println!("  - Backup: BackupFormat, BackupSummary, BackupInfo");
+    println!("  - Batch: BatchOperation, BatchItemStatus, BatchProgress, BatchItemError, BatchSummary");
+    println!("  - Import: ImportSource, ImportCandidate, ExternalProjectImport, ImportResult");
+    println!("  - Project Health: HealthStatus, HealthDiagnostics, ProjectHealth");
+    println!("  - Project Activity: ActivityDay, AuthorActivity, ProjectActivity");
+    println!("  - Background Fetch: ScopeFetchPolicy, PlatformConditions, ScopeFetchResult");
+    println!("  - Preflight: FolderOperation, DirtyWorkPolicy, WorkInspection, ProjectPreflight");
+    println!("  - Clone Queue: CloneJobStatus, CloneStage, CloneRequest, CloneJob, CredentialRequest");
+    println!("  - Git Providers: ProviderKind, DeviceAuthorization, ProviderAccount, RemoteRepository, RepositoryFilter, CloneRemoteRequest, CloneRemoteResult, CiState, ProjectCiStatus, PullRequestSummary");
 }
@@ -0,0 +1,50 @@
+//! Event subscription commands
+//!
+//! This module lets frontend windows choose which application events
+//! they receive through the "app-event" channel.
+
+use crate::events::{EventSubscription, EventSubscriptions};
+use tauri::{State, WebviewWindow};
+
+/// Subscribe the calling window to a filtered set of application events
+///
+/// Replaces any previous subscription for the window. An empty category or
+/// scope list means "all" for that dimension.
+#[tauri::command]
+#[specta::specta]
+pub fn subscribe_events(
+    window: WebviewWindow,
+    subscriptions: State<EventSubscriptions>,
+    subscription: EventSubscription,
+) -> Result<(), String> {
+    tracing::debug!(
+        "Window '{}' subscribed to events: {:?}",
+        window.label(),
+        subscription
+    );
+    subscriptions.subscribe(window.label(), subscription);
+    Ok(())
+}
+
+/// Remove the calling window's subscription so it receives all events again
+#[tauri::command]
+#[specta::specta]
+pub fn unsubscribe_events(
+    window: WebviewWindow,
+    subscriptions: State<EventSubscriptions>,
+) -> Result<(), String> {
+    subscriptions.unsubscribe(window.label());
+    Ok(())
+}
+
+/// Get the calling window's current event subscription
+///
+/// Returns None when the window receives all events.
+#[tauri::command]
+#[specta::specta]
+pub fn get_event_subscription(
+    window: WebviewWindow,
+    subscriptions: State<EventSubscriptions>,
+) -> Result<Option<EventSubscription>, String> {
+    Ok(subscriptions.get(window.label()))
+}
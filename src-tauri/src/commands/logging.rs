@@ -0,0 +1,66 @@
+//! Logging commands
+//!
+//! This module exposes runtime log level control and recent log lines so
+//! users can attach logs to bug reports without locating the log directory.
+
+use crate::db::Database;
+use crate::logging::{self, LogSettings, LOG_LEVEL_OVERRIDES_KEY};
+use tauri::State;
+
+/// Default number of lines returned by `get_recent_logs`
+const DEFAULT_RECENT_LINES: u32 = 500;
+
+/// Upper bound on lines returned by `get_recent_logs`
+const MAX_RECENT_LINES: u32 = 10_000;
+
+/// Get the current log levels and the log file location
+#[tauri::command]
+#[specta::specta]
+pub fn get_log_settings() -> Result<LogSettings, String> {
+    let levels = logging::current_levels();
+    Ok(LogSettings {
+        base: levels.base,
+        overrides: levels.overrides,
+        log_file: logging::log_file_path().map(|p| p.to_string_lossy().to_string()),
+    })
+}
+
+/// Set the log level for a module at runtime
+///
+/// With no target, the global level is changed for this session only.
+/// With a target, the override is persisted and restored on next launch;
+/// passing no level removes the override.
+#[tauri::command]
+#[specta::specta]
+pub fn set_log_level(
+    db: State<Database>,
+    target: Option<String>,
+    level: Option<String>,
+) -> Result<LogSettings, String> {
+    let directives = logging::set_log_level(target.as_deref(), level.as_deref())?;
+    tracing::info!("Log filter updated: {}", directives);
+
+    if target.is_some() {
+        let overrides = logging::current_levels().overrides;
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        crate::db::repository::set_setting(
+            &conn,
+            LOG_LEVEL_OVERRIDES_KEY,
+            &serde_json::to_value(&overrides).map_err(|e| e.to_string())?,
+        )
+        .map_err(|e| e.to_string())?;
+    }
+
+    get_log_settings()
+}
+
+/// Get the most recent log lines, oldest first
+#[tauri::command]
+#[specta::specta]
+pub fn get_recent_logs(lines: Option<u32>) -> Result<Vec<String>, String> {
+    let path = logging::log_file_path()
+        .ok_or_else(|| "Failed to determine log directory".to_string())?;
+    let lines = lines.unwrap_or(DEFAULT_RECENT_LINES).min(MAX_RECENT_LINES);
+
+    logging::read_recent_lines(&path, lines as usize).map_err(|e| e.to_string())
+}
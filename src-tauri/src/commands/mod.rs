@@ -4,12 +4,15 @@
 //! to the frontend via IPC.
 
 pub mod editors;
+pub mod events;
 pub mod git;
 pub mod liquid_glass;
+pub mod logging;
 pub mod projects;
 pub mod scopes;
 pub mod settings;
 pub mod temp_projects;
+pub mod templates;
 pub mod terminal;
 pub mod terminals;
 
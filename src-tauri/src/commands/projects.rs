@@ -3,6 +3,7 @@ use crate::db::models::{
     CreateProjectLinkRequest, CreateProjectRequest, GitStatusCache, LanguageInfo, LastCommitInfo,
     Project, ProjectCommand, ProjectGroup, ProjectLink, ProjectStatistics, ProjectWithStatus,
 };
+use crate::db::queries::ProjectQuery;
 use crate::db::repository::{
     assign_project_to_group as repo_assign_project_to_group,
     create_project_command as repo_create_project_command,
@@ -15,15 +16,21 @@ use crate::db::repository::{
     get_project_commands as repo_get_project_commands,
     get_project_groups as repo_get_project_groups,
     get_project_links as repo_get_project_links,
+    search_projects_with_status,
 };
 use crate::db::Database;
+use crate::git::{
+    guard_folder_operation, inspect_work, DirtyWorkPolicy, FolderOperation, ProjectPreflight,
+};
+use crate::services::archive;
 use chrono::{DateTime, Utc};
 use git2::Repository;
 use ignore::WalkBuilder;
 use rusqlite::{Connection, OptionalExtension};
 use std::collections::HashMap;
 use std::path::Path;
-use tauri::State;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Manager, State};
 use tracing::instrument;
 use uuid::Uuid;
 use walkdir::WalkDir;
@@ -46,7 +53,7 @@ fn fetch_projects_internal(
                g.last_checked_at, g.remote_url
         FROM projects p
         LEFT JOIN git_status_cache g ON p.id = g.project_id
-        WHERE p.scope_id = ?1
+        WHERE p.scope_id = ?1 AND p.is_archived = 0
         ORDER BY p.is_pinned DESC, p.is_temp DESC, p.last_opened_at DESC NULLS LAST, p.name ASC
         "#
     } else {
@@ -59,6 +66,7 @@ fn fetch_projects_internal(
                g.last_checked_at, g.remote_url
         FROM projects p
         LEFT JOIN git_status_cache g ON p.id = g.project_id
+        WHERE p.is_archived = 0
         ORDER BY p.is_pinned DESC, p.is_temp DESC, p.last_opened_at DESC NULLS LAST, p.name ASC
         "#
     };
@@ -160,6 +168,24 @@ pub fn get_all_projects(db: State<Database>) -> Result<Vec<ProjectWithStatus>, S
     fetch_projects_internal(&conn, None)
 }
 
+/// Search projects with the query language used by the project switcher
+///
+/// See [`crate::db::queries::search_queries`] for the supported syntax,
+/// e.g. `api tag:backend lang:rust dirty:true last-opened:<30d`.
+#[tauri::command]
+#[specta::specta]
+pub fn search_projects(
+    db: State<Database>,
+    query: String,
+    scope_id: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<ProjectWithStatus>, String> {
+    let query = ProjectQuery::parse(&query)?;
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    search_projects_with_status(&conn, &query, scope_id.as_deref(), limit.unwrap_or(50).min(500))
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 #[instrument(skip(db), level = "info")]
@@ -306,29 +332,49 @@ pub fn get_project(db: State<Database>, id: String) -> Result<ProjectWithStatus,
 pub fn delete_project(db: State<Database>, id: String) -> Result<(), String> {
     let conn = db.conn.lock().map_err(|e| e.to_string())?;
 
+    let archives = archive::service::archive_files(&conn, Some(&id), None)?;
     conn.execute("DELETE FROM projects WHERE id = ?1", [&id])
         .map_err(|e| e.to_string())?;
+    archive::service::remove_archive_files(&archives);
 
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub fn delete_project_with_folder(db: State<Database>, id: String) -> Result<(), String> {
-    let conn = db.conn.lock().map_err(|e| e.to_string())?;
-
+pub fn delete_project_with_folder(
+    db: State<Database>,
+    id: String,
+    dirty_policy: Option<DirtyWorkPolicy>,
+) -> Result<(), String> {
     // Get the project path first
-    let path: String = conn
-        .query_row(
+    let path: String = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        conn.query_row(
             "SELECT path FROM projects WHERE id = ?1",
             [&id],
             |row| row.get(0),
         )
-        .map_err(|e| format!("Project not found: {}", e))?;
+        .map_err(|e| format!("Project not found: {}", e))?
+    };
+
+    // Refuse to silently delete uncommitted or unpushed work. Inspecting a
+    // large repository is slow, so this runs without holding the DB lock.
+    guard_folder_operation(
+        Path::new(&path),
+        FolderOperation::Delete,
+        dirty_policy.unwrap_or_default(),
+    )?;
 
     // Delete from database
-    conn.execute("DELETE FROM projects WHERE id = ?1", [&id])
-        .map_err(|e| e.to_string())?;
+    let archives = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        let archives = archive::service::archive_files(&conn, Some(&id), None)?;
+        conn.execute("DELETE FROM projects WHERE id = ?1", [&id])
+            .map_err(|e| e.to_string())?;
+        archives
+    };
+    archive::service::remove_archive_files(&archives);
 
     // Delete the folder
     let folder_path = std::path::Path::new(&path);
@@ -383,18 +429,18 @@ pub fn move_project_to_scope_with_folder(
     new_scope_id: String,
     target_folder: Option<String>,
     folder_name: Option<String>,
+    dirty_policy: Option<DirtyWorkPolicy>,
 ) -> Result<String, String> {
-    let conn = db.conn.lock().map_err(|e| e.to_string())?;
-    let now = Utc::now();
-
     // Get current project path
-    let project_path: String = conn
-        .query_row(
+    let project_path: String = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        conn.query_row(
             "SELECT path FROM projects WHERE id = ?1",
             [&project_id],
             |row| row.get(0),
         )
-        .map_err(|e| format!("Project not found: {}", e))?;
+        .map_err(|e| format!("Project not found: {}", e))?
+    };
 
     let mut final_path = project_path.clone();
 
@@ -423,6 +469,12 @@ pub fn move_project_to_scope_with_folder(
                 return Err(format!("Destination already exists: {}", new_path_str));
             }
 
+            guard_folder_operation(
+                current_path,
+                FolderOperation::Move,
+                dirty_policy.unwrap_or_default(),
+            )?;
+
             // Ensure target directory exists
             let target_dir = Path::new(&target);
             if !target_dir.exists() {
@@ -439,6 +491,8 @@ pub fn move_project_to_scope_with_folder(
     }
 
     // Update database with new scope and possibly new path
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let now = Utc::now();
     conn.execute(
         "UPDATE projects SET scope_id = ?1, path = ?2, is_temp = 0, updated_at = ?3 WHERE id = ?4",
         (&new_scope_id, &final_path, now.to_rfc3339(), &project_id),
@@ -448,6 +502,78 @@ pub fn move_project_to_scope_with_folder(
     Ok(final_path)
 }
 
+/// Check projects for work a folder move or delete could lose
+///
+/// Returns one entry per project so the UI can show what's at risk before
+/// calling `move_project_to_scope_with_folder` or `delete_project_with_folder`.
+#[tauri::command]
+#[specta::specta]
+pub fn preflight_folder_operation(
+    db: State<Database>,
+    project_ids: Vec<String>,
+    operation: FolderOperation,
+) -> Result<Vec<ProjectPreflight>, String> {
+    let projects: Vec<(String, String, String)> = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        project_ids
+            .iter()
+            .map(|id| {
+                conn.query_row(
+                    "SELECT id, name, path FROM projects WHERE id = ?1",
+                    [id],
+                    |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+                )
+                .map_err(|e| format!("Project not found: {}", e))
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    Ok(projects
+        .into_iter()
+        .map(|(project_id, project_name, path)| {
+            let inspection = inspect_work(Path::new(&path));
+            let risks = inspection.risks(operation);
+            ProjectPreflight {
+                project_id,
+                project_name,
+                path,
+                inspection,
+                risks,
+            }
+        })
+        .collect())
+}
+
+/// Stash uncommitted and untracked changes in several projects
+///
+/// Returns the IDs of projects that had changes stashed.
+#[tauri::command]
+#[specta::specta]
+pub fn stash_project_changes(
+    db: State<Database>,
+    project_ids: Vec<String>,
+) -> Result<Vec<String>, String> {
+    let mut stashed = Vec::new();
+    for project_id in project_ids {
+        let path: String = {
+            let conn = db.conn.lock().map_err(|e| e.to_string())?;
+            conn.query_row(
+                "SELECT path FROM projects WHERE id = ?1",
+                [&project_id],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Project not found: {}", e))?
+        };
+
+        let path = Path::new(&path);
+        if inspect_work(path).has_working_changes() {
+            crate::git::stash_changes(path, "Panager: stashed from project list")?;
+            stashed.push(project_id);
+        }
+    }
+    Ok(stashed)
+}
+
 // Project Tags
 #[tauri::command]
 #[specta::specta]
@@ -884,6 +1010,8 @@ pub fn unpin_project(db: State<Database>, project_id: String) -> Result<(), Stri
 #[tauri::command]
 #[specta::specta]
 pub fn get_project_statistics(
+    db: State<Database>,
+    project_id: String,
     project_path: String,
 ) -> Result<ProjectStatistics, String> {
     let path = Path::new(&project_path);
@@ -1016,6 +1144,16 @@ pub fn get_project_statistics(
         languages.truncate(10);
     }
 
+    // Keep the `lang:` search filter in sync with the full breakdown
+    if let Some(primary) = languages.iter().find(|l| counts_as_primary(&l.name)) {
+        if let Ok(conn) = db.conn.lock() {
+            let _ = conn.execute(
+                "UPDATE projects SET primary_language = ?1 WHERE id = ?2",
+                (&primary.name, &project_id),
+            );
+        }
+    }
+
     // Get git statistics if it's a git repository
     if let Ok(repo) = Repository::open(path) {
         // Count commits
@@ -1112,6 +1250,99 @@ pub fn get_project_statistics(
     })
 }
 
+/// Files inspected when detecting a project's primary language
+const LANGUAGE_SAMPLE_FILES: usize = 5000;
+
+/// Whether a language can be a project's primary language
+fn counts_as_primary(name: &str) -> bool {
+    !matches!(name, "Other" | "Binary")
+}
+
+/// Detect a project's primary language from its largest source files.
+///
+/// A cheaper version of the breakdown in `get_project_statistics`, bounded
+/// to the first few thousand non-ignored files, used to fill
+/// `projects.primary_language` for `lang:` searches.
+pub fn detect_primary_language(path: &Path) -> Option<String> {
+    let mut language_bytes: HashMap<String, u64> = HashMap::new();
+
+    let walker = WalkBuilder::new(path)
+        .hidden(false)
+        .git_ignore(true)
+        .git_exclude(true)
+        .git_global(true)
+        .filter_entry(|entry| entry.file_name() != ".git")
+        .build();
+
+    for entry in walker
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().map(|ft| ft.is_file()).unwrap_or(false))
+        .take(LANGUAGE_SAMPLE_FILES)
+    {
+        let Some(ext) = entry.path().extension() else {
+            continue;
+        };
+        let name = get_language_name(&ext.to_string_lossy().to_lowercase());
+        if counts_as_primary(&name) {
+            let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            *language_bytes.entry(name).or_insert(0) += size;
+        }
+    }
+
+    language_bytes
+        .into_iter()
+        .max_by_key(|(_, bytes)| *bytes)
+        .map(|(name, _)| name)
+}
+
+/// Whether a language backfill is currently running.
+static LANGUAGE_BACKFILL_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Detect languages of projects that don't have one yet, on a blocking thread.
+///
+/// Runs at startup (covering projects that existed before the column was
+/// filled) and after the folder scanner adds projects. Only one backfill
+/// runs at a time.
+pub fn spawn_language_backfill(app: AppHandle) {
+    if LANGUAGE_BACKFILL_RUNNING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let db = app.state::<Database>();
+        let pending: Vec<(String, String)> = match db.conn.lock() {
+            Ok(conn) => conn
+                .prepare("SELECT id, path FROM projects WHERE primary_language IS NULL AND is_archived = 0")
+                .and_then(|mut stmt| {
+                    stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .unwrap_or_default(),
+            Err(_) => Vec::new(),
+        };
+
+        for (project_id, path) in pending {
+            if let Err(e) = update_primary_language(&db, &project_id, Path::new(&path)) {
+                tracing::warn!("Failed to detect language for {}: {}", path, e);
+            }
+        }
+
+        LANGUAGE_BACKFILL_RUNNING.store(false, Ordering::SeqCst);
+    });
+}
+
+/// Store a project's detected primary language (empty if none was found).
+pub fn update_primary_language(db: &Database, project_id: &str, path: &Path) -> Result<(), String> {
+    let language = detect_primary_language(path).unwrap_or_default();
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    conn.execute(
+        "UPDATE projects SET primary_language = ?1 WHERE id = ?2",
+        (&language, project_id),
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
 /// Map file extension to language name
 fn get_language_name(ext: &str) -> String {
     match ext {
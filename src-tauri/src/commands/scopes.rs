@@ -200,8 +200,12 @@ pub fn update_scope(
 pub fn delete_scope(db: State<Database>, id: String) -> Result<(), String> {
     let conn = db.conn.lock().map_err(|e| e.to_string())?;
 
+    let archives = crate::services::archive::service::archive_files(&conn, None, Some(&id))?;
     crate::db::repository::scope_repo::delete_scope_cascade(&conn, &id)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    crate::services::archive::service::remove_archive_files(&archives);
+
+    Ok(())
 }
 
 #[tauri::command]
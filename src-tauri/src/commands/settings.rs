@@ -1,4 +1,5 @@
 use crate::db::Database;
+use crate::services::telemetry;
 use chrono::Utc;
 use tauri::State;
 
@@ -28,6 +29,14 @@ pub fn set_setting(
     key: String,
     value: serde_json::Value,
 ) -> Result<(), String> {
+    // Consent is mirrored in memory, keep both in sync
+    if key == telemetry::TELEMETRY_ENABLED_KEY {
+        let enabled = value
+            .as_bool()
+            .ok_or_else(|| format!("Setting '{}' must be a boolean", key))?;
+        return telemetry::set_consent(&db, enabled);
+    }
+
     let conn = db.conn.lock().map_err(|e| e.to_string())?;
     let now = Utc::now();
 
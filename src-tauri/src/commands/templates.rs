@@ -0,0 +1,348 @@
+//! Project template commands
+//!
+//! Templates are defined per scope and either clone a git repository or run
+//! a generator command. Creating a project from a template scaffolds it into
+//! the scope's default folder, applies the scope git identity, and registers
+//! the new project.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+
+use chrono::Utc;
+use tauri::{AppHandle, Emitter, State};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::db::models::{
+    CreateFromTemplateRequest, CreateProjectTemplateRequest, Project, ProjectTemplate,
+    RenderedCommand, TempProjectProgress, TemplateKind, UpdateProjectTemplateRequest,
+    validate_variables,
+};
+use crate::db::repository::{
+    create_project_template as repo_create_project_template,
+    delete_project_template as repo_delete_project_template,
+    get_project_template_by_id as repo_get_project_template_by_id,
+    get_project_templates as repo_get_project_templates,
+    update_project_template as repo_update_project_template,
+};
+use crate::db::Database;
+use crate::events::{AppEvent, EventBus};
+use crate::git::identity::get_scope_git_identity_tuple;
+
+/// Event channel for scaffolding progress
+const TEMPLATE_PROGRESS_EVENT: &str = "template-progress";
+
+fn emit_progress(app: &AppHandle, line: &str, is_error: bool, status: Option<&str>) {
+    let progress = TempProjectProgress {
+        line: line.to_string(),
+        is_error,
+        status: status.map(String::from),
+    };
+    let _ = app.emit(TEMPLATE_PROGRESS_EVENT, progress);
+}
+
+// Template CRUD
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_project_templates(
+    db: State<Database>,
+    scope_id: String,
+) -> Result<Vec<ProjectTemplate>, String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    repo_get_project_templates(&conn, &scope_id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn create_project_template(
+    db: State<Database>,
+    request: CreateProjectTemplateRequest,
+) -> Result<ProjectTemplate, String> {
+    if request.name.trim().is_empty() || request.source.trim().is_empty() {
+        return Err("Template name and source are required".to_string());
+    }
+    validate_variables(&request.variables)?;
+
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    repo_create_project_template(&conn, &request).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn update_project_template(
+    db: State<Database>,
+    template_id: String,
+    request: UpdateProjectTemplateRequest,
+) -> Result<ProjectTemplate, String> {
+    if let Some(variables) = &request.variables {
+        validate_variables(variables)?;
+    }
+
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    repo_update_project_template(&conn, &template_id, &request).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn delete_project_template(db: State<Database>, template_id: String) -> Result<(), String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    repo_delete_project_template(&conn, &template_id).map_err(|e| e.to_string())
+}
+
+// Scaffolding
+
+/// Create a new project from a template
+///
+/// Progress is streamed on the "template-progress" event. A partially
+/// scaffolded folder is removed if any step fails.
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app, db, event_bus), level = "info")]
+pub async fn create_project_from_template(
+    app: AppHandle,
+    db: State<'_, Database>,
+    event_bus: State<'_, EventBus>,
+    request: CreateFromTemplateRequest,
+) -> Result<Project, String> {
+    let name = request.name.trim().to_string();
+    if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+        return Err(format!("Invalid project folder name: '{}'", request.name));
+    }
+
+    let (template, default_folder) = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        let template = repo_get_project_template_by_id(&conn, &request.template_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Template not found: {}", request.template_id))?;
+        let folder: Option<String> = conn
+            .query_row(
+                "SELECT default_folder FROM scopes WHERE id = ?1",
+                [&template.scope_id],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Scope not found: {}", e))?;
+        (
+            template,
+            folder.ok_or("Scope has no default folder configured")?,
+        )
+    };
+
+    let base_path = Path::new(&default_folder);
+    let project_path = base_path.join(&name);
+    let project_path_str = project_path.to_string_lossy().to_string();
+
+    if project_path.exists() {
+        return Err(format!("Folder already exists: {}", project_path_str));
+    }
+
+    // Validate variables before touching the filesystem
+    template.render_source(&name, &request.variables)?;
+
+    std::fs::create_dir_all(base_path)
+        .map_err(|e| format!("Failed to create directory: {}", e))?;
+
+    emit_progress(
+        &app,
+        &format!("Creating '{}' from template '{}'", name, template.name),
+        false,
+        Some("Initializing"),
+    );
+
+    if let Err(e) = scaffold(&app, &template, &name, &request.variables, base_path, &project_path) {
+        let _ = std::fs::remove_dir_all(&project_path);
+        emit_progress(&app, &e, true, Some("Failed"));
+        return Err(e);
+    }
+
+    // Apply scope's git identity
+    if let Some((user_name, user_email)) = get_scope_git_identity_tuple(&db, &template.scope_id) {
+        emit_progress(&app, "Setting up git identity...", false, Some("Configuring git"));
+        for (key, value) in [("user.name", &user_name), ("user.email", &user_email)] {
+            if let Err(e) = run_git(&project_path, &["config", "--local", key, value]) {
+                emit_progress(&app, &format!("Warning: Failed to set {}: {}", key, e), true, None);
+            }
+        }
+    }
+
+    // Register the project
+    let project_id = Uuid::new_v4().to_string();
+    let now = Utc::now();
+    let inserted = db.conn.lock().map_err(|e| e.to_string()).and_then(|conn| {
+        conn.execute(
+            r#"
+            INSERT INTO projects (id, scope_id, name, path, description, is_temp, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, 0, ?6, ?7)
+            "#,
+            (
+                &project_id,
+                &template.scope_id,
+                &name,
+                &project_path_str,
+                &template.description,
+                now.to_rfc3339(),
+                now.to_rfc3339(),
+            ),
+        )
+        .map_err(|e| format!("Failed to create project: {}", e))
+    });
+    if let Err(e) = inserted {
+        let _ = std::fs::remove_dir_all(&project_path);
+        emit_progress(&app, &e, true, Some("Failed"));
+        return Err(e);
+    }
+
+    event_bus.emit(AppEvent::ProjectAdded {
+        project_id: project_id.clone(),
+        scope_id: template.scope_id.clone(),
+    });
+
+    emit_progress(&app, "Done!", false, Some("Complete"));
+
+    Ok(Project {
+        id: project_id,
+        scope_id: template.scope_id,
+        name,
+        path: project_path_str,
+        preferred_editor_id: None,
+        default_branch: None,
+        workspace_file: None,
+        is_temp: false,
+        is_pinned: false,
+        group_id: None,
+        notes: None,
+        description: template.description,
+        last_opened_at: None,
+        created_at: now,
+        updated_at: now,
+    })
+}
+
+/// Produce the project folder from the template source
+fn scaffold(
+    app: &AppHandle,
+    template: &ProjectTemplate,
+    name: &str,
+    values: &HashMap<String, String>,
+    base_path: &Path,
+    project_path: &Path,
+) -> Result<(), String> {
+    match template.kind {
+        TemplateKind::Git => {
+            let source = template.render_source(name, values)?;
+            emit_progress(app, &format!("Cloning {}", source), false, Some("Cloning..."));
+            let target = project_path.to_string_lossy();
+            run_git(base_path, &["clone", "--depth", "1", "--", &source, &target])?;
+
+            // Start the new project with fresh history instead of the template's
+            std::fs::remove_dir_all(project_path.join(".git"))
+                .map_err(|e| format!("Failed to reset git history: {}", e))?;
+        }
+        TemplateKind::Command => {
+            let display = template.render_source(name, values)?;
+            let command = template.render_command(name, values)?;
+            emit_progress(app, &format!("Running: {}", display), false, Some("Running generator"));
+            run_generator(app, &command, base_path)?;
+
+            if !project_path.is_dir() {
+                return Err(format!(
+                    "Generator did not create the folder {}. Use {{{{name}}}} in the command.",
+                    project_path.display()
+                ));
+            }
+        }
+    }
+
+    if !project_path.join(".git").exists() {
+        emit_progress(app, "Initializing git repository...", false, None);
+        run_git(project_path, &["init"])?;
+    }
+
+    Ok(())
+}
+
+/// Run a generator command, streaming its output as progress events
+fn run_generator(app: &AppHandle, command: &RenderedCommand, base_path: &Path) -> Result<(), String> {
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", &command.script]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", &command.script]);
+        cmd
+    };
+
+    let mut child = cmd
+        .envs(command.env.iter().map(|(k, v)| (k, v)))
+        .current_dir(base_path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to execute command: {}", e))?;
+
+    let (tx, rx) = mpsc::channel::<(String, bool)>();
+
+    let stdout = child.stdout.take();
+    let tx_stdout = tx.clone();
+    let stdout_handle = thread::spawn(move || {
+        if let Some(stdout) = stdout {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                let _ = tx_stdout.send((line, false));
+            }
+        }
+    });
+
+    let stderr = child.stderr.take();
+    let tx_stderr = tx;
+    let stderr_handle = thread::spawn(move || {
+        if let Some(stderr) = stderr {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                let _ = tx_stderr.send((line, true));
+            }
+        }
+    });
+
+    // Generators write progress to stderr, so only its tail marks a failure
+    let mut stderr_lines: Vec<String> = Vec::new();
+    for (line, is_stderr) in rx {
+        if is_stderr {
+            stderr_lines.push(line.clone());
+        }
+        emit_progress(app, &line, false, None);
+    }
+
+    let _ = stdout_handle.join();
+    let _ = stderr_handle.join();
+
+    let status = child.wait().map_err(|e| format!("Failed to wait for process: {}", e))?;
+    if status.success() {
+        return Ok(());
+    }
+
+    if stderr_lines.is_empty() {
+        Err(format!("Command failed with exit code: {:?}", status.code()))
+    } else {
+        let start = stderr_lines.len().saturating_sub(5);
+        Err(stderr_lines[start..].join("\n"))
+    }
+}
+
+/// Run a git command in `dir`, returning stderr on failure
+fn run_git(dir: &Path, args: &[&str]) -> Result<(), String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
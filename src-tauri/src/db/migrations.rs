@@ -1,7 +1,7 @@
 use rusqlite::{Connection, Result};
 
 /// Current schema version - increment this when adding new migrations
-const CURRENT_VERSION: i32 = 6;
+const CURRENT_VERSION: i32 = 16;
 
 /// Run all pending migrations
 pub fn run_migrations(conn: &Connection) -> Result<()> {
@@ -48,6 +48,56 @@ pub fn run_migrations(conn: &Connection) -> Result<()> {
         set_version(conn, 6)?;
     }
 
+    if current_version < 7 {
+        migrate_v7(conn)?;
+        set_version(conn, 7)?;
+    }
+
+    if current_version < 8 {
+        migrate_v8(conn)?;
+        set_version(conn, 8)?;
+    }
+
+    if current_version < 9 {
+        migrate_v9(conn)?;
+        set_version(conn, 9)?;
+    }
+
+    if current_version < 10 {
+        migrate_v10(conn)?;
+        set_version(conn, 10)?;
+    }
+
+    if current_version < 11 {
+        migrate_v11(conn)?;
+        set_version(conn, 11)?;
+    }
+
+    if current_version < 12 {
+        migrate_v12(conn)?;
+        set_version(conn, 12)?;
+    }
+
+    if current_version < 13 {
+        migrate_v13(conn)?;
+        set_version(conn, 13)?;
+    }
+
+    if current_version < 14 {
+        migrate_v14(conn)?;
+        set_version(conn, 14)?;
+    }
+
+    if current_version < 15 {
+        migrate_v15(conn)?;
+        set_version(conn, 15)?;
+    }
+
+    if current_version < 16 {
+        migrate_v16(conn)?;
+        set_version(conn, 16)?;
+    }
+
     Ok(())
 }
 
@@ -366,6 +416,254 @@ fn migrate_v6(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+/// Migration v7: Add opt-in telemetry settings (disabled by default)
+fn migrate_v7(conn: &Connection) -> Result<()> {
+    conn.execute(
+        r#"
+        INSERT OR IGNORE INTO settings (key, value) VALUES
+            ('telemetry_enabled', 'false'),
+            ('telemetry_endpoint', '""')
+        "#,
+        [],
+    )?;
+
+    Ok(())
+}
+
+/// Migration v8: Add scope-level project templates
+fn migrate_v8(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS project_templates (
+            id TEXT PRIMARY KEY,
+            scope_id TEXT NOT NULL REFERENCES scopes(id) ON DELETE CASCADE,
+            name TEXT NOT NULL,
+            description TEXT,
+            kind TEXT NOT NULL,
+            source TEXT NOT NULL,
+            variables TEXT NOT NULL DEFAULT '[]',
+            sort_order INTEGER NOT NULL DEFAULT 0,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_project_templates_scope ON project_templates(scope_id);
+        "#,
+    )?;
+
+    Ok(())
+}
+
+/// Migration v9: Add project archiving
+fn migrate_v9(conn: &Connection) -> Result<()> {
+    let project_columns: Vec<String> = conn
+        .prepare("PRAGMA table_info(projects)")?
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if !project_columns.contains(&"is_archived".to_string()) {
+        conn.execute_batch("ALTER TABLE projects ADD COLUMN is_archived INTEGER NOT NULL DEFAULT 0;")?;
+    }
+
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS project_archives (
+            id TEXT PRIMARY KEY,
+            project_id TEXT NOT NULL UNIQUE REFERENCES projects(id) ON DELETE CASCADE,
+            original_path TEXT NOT NULL,
+            archive_path TEXT NOT NULL,
+            format TEXT NOT NULL,
+            size_bytes INTEGER NOT NULL DEFAULT 0,
+            original_size_bytes INTEGER NOT NULL DEFAULT 0,
+            archived_at TEXT NOT NULL DEFAULT (datetime('now'))
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_projects_archived ON projects(is_archived);
+
+        INSERT OR IGNORE INTO settings (key, value) VALUES
+            ('archive_location', '""'),
+            ('archive_format', '"tar.zst"');
+        "#,
+    )?;
+
+    Ok(())
+}
+
+/// Migration v10: Add automatic backup settings
+fn migrate_v10(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        r#"
+        INSERT OR IGNORE INTO settings (key, value) VALUES
+            ('backup_interval_hours', '24'),
+            ('backup_retention_count', '7'),
+            ('backup_location', '""');
+        "#,
+    )?;
+
+    Ok(())
+}
+
+/// Migration v11: Add project search support
+///
+/// Stores the primary language detected by project statistics and adds
+/// indexes for the columns used by `search_projects` filters.
+fn migrate_v11(conn: &Connection) -> Result<()> {
+    let project_columns: Vec<String> = conn
+        .prepare("PRAGMA table_info(projects)")?
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if !project_columns.contains(&"primary_language".to_string()) {
+        conn.execute_batch("ALTER TABLE projects ADD COLUMN primary_language TEXT;")?;
+    }
+
+    conn.execute_batch(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_projects_language ON projects(primary_language COLLATE NOCASE);
+        CREATE INDEX IF NOT EXISTS idx_projects_last_opened ON projects(last_opened_at);
+        CREATE INDEX IF NOT EXISTS idx_project_tags_tag ON project_tags(tag COLLATE NOCASE);
+        "#,
+    )?;
+
+    Ok(())
+}
+
+/// Migration v12: Add per-scope background fetch policies
+fn migrate_v12(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS scope_fetch_policies (
+            scope_id TEXT PRIMARY KEY REFERENCES scopes(id) ON DELETE CASCADE,
+            enabled INTEGER NOT NULL DEFAULT 0,
+            interval_minutes INTEGER NOT NULL DEFAULT 30,
+            skip_on_battery INTEGER NOT NULL DEFAULT 1,
+            skip_on_metered INTEGER NOT NULL DEFAULT 1,
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        );
+        "#,
+    )?;
+
+    Ok(())
+}
+
+/// Migration v13: Add GitHub/GitLab provider accounts
+///
+/// Tokens stay in this table only. It isn't part of JSON exports, and
+/// SQLite backups empty it (see `backup::snapshot::strip_secrets`).
+fn migrate_v13(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS provider_accounts (
+            id TEXT PRIMARY KEY,
+            provider TEXT NOT NULL,
+            host TEXT NOT NULL,
+            username TEXT NOT NULL,
+            access_token TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            UNIQUE(provider, host, username)
+        );
+
+        INSERT OR IGNORE INTO settings (key, value) VALUES
+            ('github_oauth_client_id', '""'),
+            ('gitlab_oauth_client_id', '""');
+        "#,
+    )?;
+
+    Ok(())
+}
+
+/// Migration v14: Add cached pull request and CI status per project
+fn migrate_v14(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS project_ci_status (
+            project_id TEXT PRIMARY KEY REFERENCES projects(id) ON DELETE CASCADE,
+            provider TEXT NOT NULL,
+            state TEXT,
+            branch TEXT,
+            url TEXT,
+            pipeline_updated_at TEXT,
+            open_pr_count INTEGER NOT NULL DEFAULT 0,
+            checked_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS project_pull_requests (
+            project_id TEXT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+            number INTEGER NOT NULL,
+            title TEXT NOT NULL,
+            author TEXT,
+            url TEXT NOT NULL,
+            is_draft INTEGER NOT NULL DEFAULT 0,
+            source_branch TEXT,
+            target_branch TEXT,
+            updated_at TEXT,
+            PRIMARY KEY (project_id, number)
+        );
+        "#,
+    )?;
+
+    Ok(())
+}
+
+/// Migration v15: Add per-scope convention policies
+fn migrate_v15(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS scope_policies (
+            scope_id TEXT PRIMARY KEY REFERENCES scopes(id) ON DELETE CASCADE,
+            folder_layout TEXT,
+            repo_name_pattern TEXT,
+            default_branch TEXT,
+            required_remote_host TEXT,
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        );
+        "#,
+    )?;
+
+    Ok(())
+}
+
+/// Migration v16: Add a full-text index for project search
+///
+/// A trigram index (substring matching, like the `LIKE` filter it replaces)
+/// over the searchable project text, kept in sync by triggers.
+fn migrate_v16(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE VIRTUAL TABLE IF NOT EXISTS projects_fts USING fts5(
+            project_id UNINDEXED,
+            name,
+            path,
+            description,
+            notes,
+            tokenize = 'trigram'
+        );
+
+        CREATE TRIGGER IF NOT EXISTS projects_fts_insert AFTER INSERT ON projects BEGIN
+            INSERT INTO projects_fts (project_id, name, path, description, notes)
+            VALUES (new.id, new.name, new.path, new.description, new.notes);
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS projects_fts_update
+        AFTER UPDATE OF id, name, path, description, notes ON projects BEGIN
+            DELETE FROM projects_fts WHERE project_id = old.id;
+            INSERT INTO projects_fts (project_id, name, path, description, notes)
+            VALUES (new.id, new.name, new.path, new.description, new.notes);
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS projects_fts_delete AFTER DELETE ON projects BEGIN
+            DELETE FROM projects_fts WHERE project_id = old.id;
+        END;
+
+        DELETE FROM projects_fts;
+        INSERT INTO projects_fts (project_id, name, path, description, notes)
+        SELECT id, name, path, description, notes FROM projects;
+        "#,
+    )?;
+
+    Ok(())
+}
+
 /// Check if a specific migration has been applied
 #[allow(dead_code)]
 pub fn is_migration_applied(conn: &Connection, version: i32) -> Result<bool> {
@@ -9,7 +9,6 @@ pub use models::*;
 pub use queries::*;
 pub use repository::*;
 
-use directories::ProjectDirs;
 use rusqlite::Connection;
 use std::fs;
 use std::path::PathBuf;
@@ -66,10 +65,9 @@ impl Database {
 }
 
 fn get_database_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let proj_dirs = ProjectDirs::from("com", "krona", "panager")
+    let data_dir = crate::utils::paths::app_data_dir()
         .ok_or("Failed to determine project directories")?;
 
-    let data_dir = proj_dirs.data_dir();
     Ok(data_dir.join("panager.db"))
 }
 
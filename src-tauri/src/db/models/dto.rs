@@ -5,7 +5,8 @@
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
-use super::TempProjectSettings;
+use super::{TempProjectSettings, TemplateKind, TemplateVariable};
+use std::collections::HashMap;
 
 /// Request to create a new scope
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -173,3 +174,37 @@ pub struct CommandResult {
     pub error: Option<String>,
     pub exit_code: Option<i32>,
 }
+
+/// Request to create a new project template
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProjectTemplateRequest {
+    pub scope_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub kind: TemplateKind,
+    pub source: String,
+    pub variables: Vec<TemplateVariable>,
+}
+
+/// Request to update a project template (None = unchanged)
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProjectTemplateRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub kind: Option<TemplateKind>,
+    pub source: Option<String>,
+    pub variables: Option<Vec<TemplateVariable>>,
+}
+
+/// Request to create a project from a template
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateFromTemplateRequest {
+    pub template_id: String,
+    /// Folder name of the new project inside the scope's default folder
+    pub name: String,
+    /// Values for the template variables, keyed by variable name
+    pub variables: HashMap<String, String>,
+}
@@ -7,6 +7,7 @@ mod dto;
 mod editor;
 mod project;
 mod scope;
+mod template;
 mod terminal;
 
 // Re-export all models for convenience
@@ -14,4 +15,5 @@ pub use dto::*;
 pub use editor::*;
 pub use project::*;
 pub use scope::*;
+pub use template::*;
 pub use terminal::*;
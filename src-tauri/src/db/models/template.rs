@@ -0,0 +1,307 @@
+//! Project template models
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+
+/// How a template produces a new project folder
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateKind {
+    /// Clone a git repository URL into the new folder
+    Git,
+    /// Run a generator command in the scope folder (e.g. `npm create vite@latest {{name}}`)
+    Command,
+}
+
+impl TemplateKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TemplateKind::Git => "git",
+            TemplateKind::Command => "command",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "git" => Some(TemplateKind::Git),
+            "command" => Some(TemplateKind::Command),
+            _ => None,
+        }
+    }
+}
+
+/// A variable that is substituted into the template source as `{{name}}`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateVariable {
+    pub name: String,
+    pub label: Option<String>,
+    pub default_value: Option<String>,
+    pub required: bool,
+}
+
+/// A scope-level project template
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTemplate {
+    pub id: String,
+    pub scope_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub kind: TemplateKind,
+    /// Repository URL (git) or generator command line (command)
+    pub source: String,
+    pub variables: Vec<TemplateVariable>,
+    pub sort_order: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A generator command line with its variable values passed separately
+///
+/// Placeholders in `script` refer to environment variables, so values are
+/// never parsed by the shell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedCommand {
+    pub script: String,
+    pub env: Vec<(String, String)>,
+}
+
+impl ProjectTemplate {
+    /// Render the template source with variable values
+    ///
+    /// `{{name}}` always resolves to the project folder name. Other variables
+    /// fall back to their default value; a missing required variable or an
+    /// unknown placeholder is an error.
+    pub fn render_source(
+        &self,
+        project_name: &str,
+        values: &HashMap<String, String>,
+    ) -> Result<String, String> {
+        let resolved = self.resolve_values(project_name, values)?;
+        self.substitute(|key| {
+            resolved
+                .get(key)
+                .map(|value| value.to_string())
+                .ok_or_else(|| format!("Unknown template variable '{}'", key))
+        })
+    }
+
+    /// Render a generator command for the shell
+    ///
+    /// Each `{{var}}` becomes a double-quoted environment variable reference
+    /// (`"$PANAGER_VAR_NAME"`, or `"%PANAGER_VAR_NAME%"` on Windows) and the
+    /// values are returned in `env`, so names with spaces, `$` or `;` reach
+    /// the generator as a single literal argument.
+    pub fn render_command(
+        &self,
+        project_name: &str,
+        values: &HashMap<String, String>,
+    ) -> Result<RenderedCommand, String> {
+        let resolved = self.resolve_values(project_name, values)?;
+        let mut env: Vec<(String, String)> = Vec::new();
+
+        let script = self.substitute(|key| {
+            let value = resolved
+                .get(key)
+                .ok_or_else(|| format!("Unknown template variable '{}'", key))?;
+            let env_name = variable_env_name(key);
+            if cfg!(target_os = "windows") && value.contains(['"', '%']) {
+                return Err(format!(
+                    "Value for '{}' can't contain '\"' or '%' in a generator command",
+                    key
+                ));
+            }
+            if !env.iter().any(|(name, _)| *name == env_name) {
+                env.push((env_name.clone(), value.to_string()));
+            }
+            Ok(if cfg!(target_os = "windows") {
+                format!("\"%{}%\"", env_name)
+            } else {
+                format!("\"${{{}}}\"", env_name)
+            })
+        })?;
+
+        Ok(RenderedCommand { script, env })
+    }
+
+    /// Resolve every variable to its value or default
+    fn resolve_values<'a>(
+        &'a self,
+        project_name: &'a str,
+        values: &'a HashMap<String, String>,
+    ) -> Result<HashMap<&'a str, &'a str>, String> {
+        let mut resolved: HashMap<&str, &str> = HashMap::new();
+        resolved.insert("name", project_name);
+
+        for variable in &self.variables {
+            let value = values
+                .get(&variable.name)
+                .filter(|v| !v.is_empty())
+                .or(variable.default_value.as_ref());
+
+            match value {
+                Some(v) => {
+                    resolved.insert(&variable.name, v);
+                }
+                None if variable.required => {
+                    return Err(format!("Missing value for variable '{}'", variable.name));
+                }
+                None => {
+                    resolved.insert(&variable.name, "");
+                }
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Replace each `{{key}}` in the source with `replacement(key)`
+    fn substitute(
+        &self,
+        mut replacement: impl FnMut(&str) -> Result<String, String>,
+    ) -> Result<String, String> {
+        let mut output = String::with_capacity(self.source.len());
+        let mut rest = self.source.as_str();
+
+        while let Some(start) = rest.find("{{") {
+            output.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+            let end = after
+                .find("}}")
+                .ok_or_else(|| "Unclosed '{{' in template source".to_string())?;
+            output.push_str(&replacement(after[..end].trim())?);
+            rest = &after[end + 2..];
+        }
+        output.push_str(rest);
+
+        Ok(output)
+    }
+}
+
+/// Check that every variable maps to its own environment variable
+///
+/// Keys are sanitized for the environment, so `my-var` and `my_var` would
+/// both become `PANAGER_VAR_MY_VAR`; `name` is reserved for the folder name.
+pub fn validate_variables(variables: &[TemplateVariable]) -> Result<(), String> {
+    let mut seen: HashMap<String, &str> = HashMap::new();
+    seen.insert(variable_env_name("name"), "name");
+
+    for variable in variables {
+        if let Some(existing) = seen.insert(variable_env_name(&variable.name), &variable.name) {
+            return Err(format!(
+                "Variable '{}' conflicts with '{}'; use a distinct name",
+                variable.name, existing
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Environment variable carrying a template variable's value
+fn variable_env_name(key: &str) -> String {
+    let sanitized: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect();
+    format!("PANAGER_VAR_{}", sanitized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(source: &str, variables: Vec<TemplateVariable>) -> ProjectTemplate {
+        ProjectTemplate {
+            id: "t1".to_string(),
+            scope_id: "s1".to_string(),
+            name: "Test".to_string(),
+            description: None,
+            kind: TemplateKind::Command,
+            source: source.to_string(),
+            variables,
+            sort_order: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn variable(name: &str, default_value: Option<&str>, required: bool) -> TemplateVariable {
+        TemplateVariable {
+            name: name.to_string(),
+            label: None,
+            default_value: default_value.map(String::from),
+            required,
+        }
+    }
+
+    #[test]
+    fn test_render_source_with_defaults() {
+        let t = template(
+            "npm create vite@latest {{name}} -- --template {{ framework }}",
+            vec![variable("framework", Some("react-ts"), false)],
+        );
+
+        let rendered = t.render_source("my-app", &HashMap::new()).unwrap();
+        assert_eq!(rendered, "npm create vite@latest my-app -- --template react-ts");
+
+        let mut values = HashMap::new();
+        values.insert("framework".to_string(), "vue".to_string());
+        let rendered = t.render_source("my-app", &values).unwrap();
+        assert_eq!(rendered, "npm create vite@latest my-app -- --template vue");
+    }
+
+    #[test]
+    fn test_render_source_errors() {
+        let t = template("{{org}}/{{name}}", vec![variable("org", None, true)]);
+        assert!(t.render_source("repo", &HashMap::new()).is_err());
+
+        let t = template("{{unknown}}", vec![]);
+        assert!(t.render_source("repo", &HashMap::new()).is_err());
+
+        let t = template("{{name", vec![]);
+        assert!(t.render_source("repo", &HashMap::new()).is_err());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_render_command_passes_values_via_env() {
+        let t = template(
+            "npm create vite@latest {{name}} -- --template {{framework}}",
+            vec![variable("framework", None, true)],
+        );
+
+        let mut values = HashMap::new();
+        values.insert("framework".to_string(), "react; rm -rf ~".to_string());
+        let rendered = t.render_command("my $app", &values).unwrap();
+
+        assert_eq!(
+            rendered.script,
+            "npm create vite@latest \"${PANAGER_VAR_NAME}\" -- --template \"${PANAGER_VAR_FRAMEWORK}\""
+        );
+        assert_eq!(
+            rendered.env,
+            vec![
+                ("PANAGER_VAR_NAME".to_string(), "my $app".to_string()),
+                ("PANAGER_VAR_FRAMEWORK".to_string(), "react; rm -rf ~".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_variables_rejects_colliding_keys() {
+        assert!(validate_variables(&[variable("my-var", None, false), variable("other", None, false)]).is_ok());
+        assert!(validate_variables(&[variable("my-var", None, false), variable("my_var", None, false)]).is_err());
+        assert!(validate_variables(&[variable("Name", None, false)]).is_err());
+    }
+
+    #[test]
+    fn test_template_kind_roundtrip() {
+        assert_eq!(TemplateKind::parse("git"), Some(TemplateKind::Git));
+        assert_eq!(TemplateKind::parse(TemplateKind::Command.as_str()), Some(TemplateKind::Command));
+        assert_eq!(TemplateKind::parse("zip"), None);
+    }
+}
@@ -4,6 +4,8 @@
 
 pub mod project_queries;
 pub mod scope_queries;
+pub mod search_queries;
 
 pub use project_queries::*;
 pub use scope_queries::*;
+pub use search_queries::*;
@@ -0,0 +1,312 @@
+//! Project search query language
+//!
+//! Parses command-palette style queries into a SQL filter over `projects p`
+//! joined with `git_status_cache g`. Supported terms:
+//!
+//! - `tag:backend` - project has the tag
+//! - `scope:work` - scope name (or id) matches
+//! - `lang:rust` - primary language detected when the project is scanned or refreshed
+//! - `dirty:true` / `dirty:false` - uncommitted or untracked changes
+//! - `last-opened:<30d` / `last-opened:>6m` - opened within / not within a period
+//!   (units: `h`, `d`, `w`, `m`, `y`)
+//! - `path:src/work` - path contains the text
+//! - anything else - name, path, description or notes contain the text
+//!   (full-text `projects_fts` trigram index for 3+ characters, LIKE otherwise)
+//!
+//! Terms are ANDed together and may be negated with a leading `-`.
+//! Values containing spaces can be quoted: `tag:"needs review"`.
+
+use chrono::{DateTime, Duration, Utc};
+use rusqlite::types::Value;
+
+/// A single parsed search term
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchTerm {
+    Tag(String),
+    Scope(String),
+    Language(String),
+    Dirty(bool),
+    /// `true` = opened within the duration, `false` = not within (or never)
+    LastOpened { within: bool, duration: Duration },
+    PathContains(String),
+    Text(String),
+}
+
+/// A parsed search query
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProjectQuery {
+    /// Terms with their negation flag
+    pub terms: Vec<(bool, SearchTerm)>,
+}
+
+/// Split a query into tokens, keeping quoted sections together
+fn tokenize(query: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for c in query.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+/// Parse a relative period such as `30d`, `12h` or `6m`
+fn parse_duration(value: &str) -> Option<Duration> {
+    let split = value.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit) = value.split_at(split);
+    let amount: i64 = amount.parse().ok()?;
+
+    let duration = match unit {
+        "h" => Duration::try_hours(amount),
+        "d" => Duration::try_days(amount),
+        "w" => Duration::try_weeks(amount),
+        "m" => amount.checked_mul(30).and_then(Duration::try_days),
+        "y" => amount.checked_mul(365).and_then(Duration::try_days),
+        _ => None,
+    }?;
+
+    // Reject periods reaching before the earliest representable date
+    Utc::now().checked_sub_signed(duration)?;
+    Some(duration)
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "true" | "yes" | "1" => Some(true),
+        "false" | "no" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_term(key: &str, value: &str) -> Result<Option<SearchTerm>, String> {
+    let term = match key.to_lowercase().as_str() {
+        "tag" => SearchTerm::Tag(value.to_string()),
+        "scope" => SearchTerm::Scope(value.to_string()),
+        "lang" | "language" => SearchTerm::Language(value.to_string()),
+        "path" => SearchTerm::PathContains(value.to_string()),
+        "dirty" => SearchTerm::Dirty(
+            parse_bool(value).ok_or_else(|| format!("Invalid dirty value '{}'", value))?,
+        ),
+        "last-opened" | "opened" => {
+            let (within, period) = match value.chars().next() {
+                Some('<') => (true, &value[1..]),
+                Some('>') => (false, &value[1..]),
+                _ => (true, value),
+            };
+            let duration = parse_duration(period)
+                .ok_or_else(|| format!("Invalid period '{}' (e.g. <30d, >6m)", value))?;
+            SearchTerm::LastOpened { within, duration }
+        }
+        // Unknown keys (e.g. URLs) fall back to text search
+        _ => return Ok(None),
+    };
+    Ok(Some(term))
+}
+
+impl ProjectQuery {
+    /// Parse a query string
+    pub fn parse(query: &str) -> Result<Self, String> {
+        let mut terms = Vec::new();
+
+        for token in tokenize(query) {
+            let (negated, token) = match token.strip_prefix('-') {
+                Some(rest) if !rest.is_empty() => (true, rest.to_string()),
+                _ => (false, token),
+            };
+
+            let term = match token.split_once(':') {
+                Some((key, value)) if !value.is_empty() => parse_term(key, value)?,
+                _ => None,
+            };
+
+            terms.push((negated, term.unwrap_or(SearchTerm::Text(token))));
+        }
+
+        Ok(Self { terms })
+    }
+
+    /// Build the SQL condition and its parameters
+    ///
+    /// The condition references `p` (projects), `g` (git_status_cache) and
+    /// uses positional `?N` parameters starting at `first_param`.
+    pub fn to_sql(&self, now: DateTime<Utc>, first_param: usize) -> (String, Vec<Value>) {
+        let mut conditions = Vec::new();
+        let mut params: Vec<Value> = Vec::new();
+
+        for (negated, term) in &self.terms {
+            let mut next = |value: Value| {
+                params.push(value);
+                format!("?{}", first_param + params.len() - 1)
+            };
+
+            let condition = match term {
+                SearchTerm::Tag(tag) => format!(
+                    "EXISTS (SELECT 1 FROM project_tags t WHERE t.project_id = p.id AND t.tag = {} COLLATE NOCASE)",
+                    next(Value::Text(tag.clone()))
+                ),
+                SearchTerm::Scope(scope) => {
+                    let param = next(Value::Text(scope.clone()));
+                    format!(
+                        "EXISTS (SELECT 1 FROM scopes s WHERE s.id = p.scope_id AND (s.name = {0} COLLATE NOCASE OR s.id = {0}))",
+                        param
+                    )
+                }
+                SearchTerm::Language(lang) => format!(
+                    "p.primary_language = {} COLLATE NOCASE",
+                    next(Value::Text(lang.clone()))
+                ),
+                SearchTerm::Dirty(true) => {
+                    "(COALESCE(g.has_uncommitted, 0) = 1 OR COALESCE(g.has_untracked, 0) = 1)"
+                        .to_string()
+                }
+                SearchTerm::Dirty(false) => {
+                    "(COALESCE(g.has_uncommitted, 0) = 0 AND COALESCE(g.has_untracked, 0) = 0)"
+                        .to_string()
+                }
+                SearchTerm::LastOpened { within, duration } => {
+                    let cutoff = now
+                        .checked_sub_signed(*duration)
+                        .unwrap_or(DateTime::<Utc>::MIN_UTC);
+                    let cutoff = next(Value::Text(cutoff.to_rfc3339()));
+                    if *within {
+                        format!("p.last_opened_at >= {}", cutoff)
+                    } else {
+                        format!("(p.last_opened_at IS NULL OR p.last_opened_at < {})", cutoff)
+                    }
+                }
+                SearchTerm::PathContains(text) => format!(
+                    "p.path LIKE {} ESCAPE '\\'",
+                    next(Value::Text(like_pattern(text)))
+                ),
+                // The trigram tokenizer needs at least three characters
+                SearchTerm::Text(text) if text.chars().count() >= 3 => format!(
+                    "p.id IN (SELECT project_id FROM projects_fts WHERE projects_fts MATCH {})",
+                    next(Value::Text(fts_phrase(text)))
+                ),
+                SearchTerm::Text(text) => {
+                    let param = next(Value::Text(like_pattern(text)));
+                    format!(
+                        "(p.name LIKE {0} ESCAPE '\\' OR p.path LIKE {0} ESCAPE '\\' \
+                         OR COALESCE(p.description, '') LIKE {0} ESCAPE '\\' \
+                         OR COALESCE(p.notes, '') LIKE {0} ESCAPE '\\')",
+                        param
+                    )
+                }
+            };
+
+            conditions.push(if *negated {
+                format!("NOT {}", condition)
+            } else {
+                condition
+            });
+        }
+
+        if conditions.is_empty() {
+            ("1 = 1".to_string(), params)
+        } else {
+            (conditions.join(" AND "), params)
+        }
+    }
+}
+
+/// Wrap text in `%...%`, escaping LIKE wildcards
+fn like_pattern(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+/// Quote text as a single FTS5 phrase
+fn fts_phrase(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_terms() {
+        let query =
+            ProjectQuery::parse("api tag:backend -dirty:yes lang:Rust last-opened:<30d scope:\"My Work\"")
+                .unwrap();
+
+        assert_eq!(
+            query.terms,
+            vec![
+                (false, SearchTerm::Text("api".to_string())),
+                (false, SearchTerm::Tag("backend".to_string())),
+                (true, SearchTerm::Dirty(true)),
+                (false, SearchTerm::Language("Rust".to_string())),
+                (
+                    false,
+                    SearchTerm::LastOpened {
+                        within: true,
+                        duration: Duration::try_days(30).unwrap()
+                    }
+                ),
+                (false, SearchTerm::Scope("My Work".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_key_is_text() {
+        let query = ProjectQuery::parse("https://github.com").unwrap();
+        assert_eq!(
+            query.terms,
+            vec![(false, SearchTerm::Text("https://github.com".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_values() {
+        assert!(ProjectQuery::parse("dirty:maybe").is_err());
+        assert!(ProjectQuery::parse("last-opened:<soon").is_err());
+        assert!(ProjectQuery::parse("last-opened:<999999999999d").is_err());
+        assert!(ProjectQuery::parse("last-opened:>9223372036854775807y").is_err());
+    }
+
+    #[test]
+    fn test_to_sql_numbers_params() {
+        let query = ProjectQuery::parse("tag:a path:50%").unwrap();
+        let (sql, params) = query.to_sql(Utc::now(), 2);
+
+        assert!(sql.contains("t.tag = ?2"));
+        assert!(sql.contains("p.path LIKE ?3"));
+        assert_eq!(params[1], Value::Text("%50\\%%".to_string()));
+    }
+
+    #[test]
+    fn test_text_uses_fts_for_longer_terms() {
+        let query = ProjectQuery::parse("ab api-gateway").unwrap();
+        let (sql, params) = query.to_sql(Utc::now(), 1);
+
+        assert!(sql.contains("p.name LIKE ?1"));
+        assert!(sql.contains("projects_fts MATCH ?2"));
+        assert_eq!(params[1], Value::Text("\"api-gateway\"".to_string()));
+        assert_eq!(fts_phrase("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_empty_query_matches_all() {
+        let (sql, params) = ProjectQuery::default().to_sql(Utc::now(), 1);
+        assert_eq!(sql, "1 = 1");
+        assert!(params.is_empty());
+    }
+}
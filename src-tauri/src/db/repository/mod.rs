@@ -9,6 +9,7 @@ pub mod project_group_repo;
 pub mod project_command_repo;
 pub mod scope_repo;
 pub mod settings_repo;
+pub mod template_repo;
 
 pub use editor_repo::*;
 pub use project_repo::*;
@@ -17,3 +18,4 @@ pub use project_group_repo::*;
 pub use project_command_repo::*;
 pub use scope_repo::*;
 pub use settings_repo::*;
+pub use template_repo::*;
@@ -1,9 +1,11 @@
 //! Repository for project-related database operations
 
 use chrono::{DateTime, Utc};
+use rusqlite::types::Value;
 use rusqlite::{Connection, OptionalExtension};
 
 use crate::db::models::{GitStatusCache, Project, ProjectWithStatus};
+use crate::db::queries::ProjectQuery;
 use crate::error::{PanagerError, Result};
 
 /// Fetch projects with their git status and tags
@@ -30,7 +32,7 @@ pub fn fetch_projects_with_status(
                g.last_checked_at, g.remote_url
         FROM projects p
         LEFT JOIN git_status_cache g ON p.id = g.project_id
-        WHERE p.scope_id = ?1
+        WHERE p.scope_id = ?1 AND p.is_archived = 0
         ORDER BY p.is_pinned DESC, p.is_temp DESC, p.last_opened_at DESC NULLS LAST, p.name ASC
         "#
     } else {
@@ -43,64 +45,125 @@ pub fn fetch_projects_with_status(
                g.last_checked_at, g.remote_url
         FROM projects p
         LEFT JOIN git_status_cache g ON p.id = g.project_id
+        WHERE p.is_archived = 0
         ORDER BY p.is_pinned DESC, p.is_temp DESC, p.last_opened_at DESC NULLS LAST, p.name ASC
         "#
     };
 
     let mut stmt = conn.prepare(sql).map_err(PanagerError::Database)?;
 
-    let row_mapper = |row: &rusqlite::Row| -> rusqlite::Result<(Project, Option<GitStatusCache>)> {
-        let project = Project {
-            id: row.get(0)?,
-            scope_id: row.get(1)?,
-            name: row.get(2)?,
-            path: row.get(3)?,
-            preferred_editor_id: row.get(4)?,
-            default_branch: row.get(5)?,
-            workspace_file: row.get(6)?,
-            is_temp: row.get(7)?,
-            is_pinned: row.get::<_, i32>(8).unwrap_or(0) != 0,
-            group_id: row.get(9).ok().flatten(),
-            notes: row.get(10).ok().flatten(),
-            description: row.get(11).ok().flatten(),
-            last_opened_at: row.get::<_, Option<String>>(12)?.map(|s| {
-                s.parse::<DateTime<Utc>>().unwrap_or_else(|_| Utc::now())
-            }),
-            created_at: row.get::<_, String>(13)?.parse().unwrap_or_else(|_| Utc::now()),
-            updated_at: row.get::<_, String>(14)?.parse().unwrap_or_else(|_| Utc::now()),
-        };
-
-        let git_status = row.get::<_, Option<String>>(15)?.map(|branch| {
-            GitStatusCache {
-                project_id: project.id.clone(),
-                branch: Some(branch),
-                ahead: row.get(16).unwrap_or(0),
-                behind: row.get(17).unwrap_or(0),
-                has_uncommitted: row.get(18).unwrap_or(false),
-                has_untracked: row.get(19).unwrap_or(false),
-                last_checked_at: row.get::<_, Option<String>>(20).ok().flatten().map(|s| {
-                    s.parse::<DateTime<Utc>>().unwrap_or_else(|_| Utc::now())
-                }),
-                remote_url: row.get(21).ok().flatten(),
-            }
-        });
-
-        Ok((project, git_status))
-    };
-
     let projects: Vec<(Project, Option<GitStatusCache>)> = if let Some(scope_id) = scope_id {
-        stmt.query_map([scope_id], row_mapper)
+        stmt.query_map([scope_id], map_project_status_row)
             .map_err(PanagerError::Database)?
             .filter_map(|r: rusqlite::Result<(Project, Option<GitStatusCache>)>| r.ok())
             .collect()
     } else {
-        stmt.query_map([], row_mapper)
+        stmt.query_map([], map_project_status_row)
             .map_err(PanagerError::Database)?
             .filter_map(|r: rusqlite::Result<(Project, Option<GitStatusCache>)>| r.ok())
             .collect()
     };
 
-    // Fetch tags, links, and group for each project
+    with_tags(conn, projects)
+}
+
+/// Search projects with a query-language filter
+///
+/// # Arguments
+/// * `conn` - Database connection
+/// * `query` - Parsed search query
+/// * `scope_id` - Optional scope ID to restrict the search to
+/// * `limit` - Maximum number of results
+///
+/// # Returns
+/// Matching projects, most recently opened first
+pub fn search_projects_with_status(
+    conn: &Connection,
+    query: &ProjectQuery,
+    scope_id: Option<&str>,
+    limit: u32,
+) -> Result<Vec<ProjectWithStatus>> {
+    let mut params: Vec<Value> = vec![
+        Value::Text(scope_id.unwrap_or_default().to_string()),
+        Value::Integer(limit as i64),
+    ];
+    let (condition, query_params) = query.to_sql(Utc::now(), params.len() + 1);
+    params.extend(query_params);
+
+    let sql = format!(
+        r#"
+        SELECT p.id, p.scope_id, p.name, p.path, p.preferred_editor_id,
+               p.default_branch, p.workspace_file, p.is_temp, p.is_pinned, p.group_id,
+               p.notes, p.description, p.last_opened_at,
+               p.created_at, p.updated_at,
+               g.branch, g.ahead, g.behind, g.has_uncommitted, g.has_untracked,
+               g.last_checked_at, g.remote_url
+        FROM projects p
+        LEFT JOIN git_status_cache g ON p.id = g.project_id
+        WHERE p.is_archived = 0 AND (?1 = '' OR p.scope_id = ?1) AND {}
+        ORDER BY p.is_pinned DESC, p.last_opened_at DESC NULLS LAST, p.name ASC
+        LIMIT ?2
+        "#,
+        condition
+    );
+
+    let mut stmt = conn.prepare(&sql).map_err(PanagerError::Database)?;
+    let projects = stmt
+        .query_map(rusqlite::params_from_iter(params), map_project_status_row)
+        .map_err(PanagerError::Database)?
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .map_err(PanagerError::Database)?;
+
+    with_tags(conn, projects)
+}
+
+/// Map a row selected with the project + git status column list
+fn map_project_status_row(
+    row: &rusqlite::Row,
+) -> rusqlite::Result<(Project, Option<GitStatusCache>)> {
+    let project = Project {
+        id: row.get(0)?,
+        scope_id: row.get(1)?,
+        name: row.get(2)?,
+        path: row.get(3)?,
+        preferred_editor_id: row.get(4)?,
+        default_branch: row.get(5)?,
+        workspace_file: row.get(6)?,
+        is_temp: row.get(7)?,
+        is_pinned: row.get::<_, i32>(8).unwrap_or(0) != 0,
+        group_id: row.get(9).ok().flatten(),
+        notes: row.get(10).ok().flatten(),
+        description: row.get(11).ok().flatten(),
+        last_opened_at: row.get::<_, Option<String>>(12)?.map(|s| {
+            s.parse::<DateTime<Utc>>().unwrap_or_else(|_| Utc::now())
+        }),
+        created_at: row.get::<_, String>(13)?.parse().unwrap_or_else(|_| Utc::now()),
+        updated_at: row.get::<_, String>(14)?.parse().unwrap_or_else(|_| Utc::now()),
+    };
+
+    let git_status = row.get::<_, Option<String>>(15)?.map(|branch| {
+        GitStatusCache {
+            project_id: project.id.clone(),
+            branch: Some(branch),
+            ahead: row.get(16).unwrap_or(0),
+            behind: row.get(17).unwrap_or(0),
+            has_uncommitted: row.get(18).unwrap_or(false),
+            has_untracked: row.get(19).unwrap_or(false),
+            last_checked_at: row.get::<_, Option<String>>(20).ok().flatten().map(|s| {
+                s.parse::<DateTime<Utc>>().unwrap_or_else(|_| Utc::now())
+            }),
+            remote_url: row.get(21).ok().flatten(),
+        }
+    });
+
+    Ok((project, git_status))
+}
+
+/// Attach tags to fetched projects
+fn with_tags(
+    conn: &Connection,
+    projects: Vec<(Project, Option<GitStatusCache>)>,
+) -> Result<Vec<ProjectWithStatus>> {
     let mut result = Vec::with_capacity(projects.len());
     for (project, git_status) in projects {
         let tags = fetch_project_tags(conn, &project.id)?;
@@ -371,4 +434,41 @@ mod tests {
         let result = find_project_by_id(&conn, "nonexistent").unwrap();
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_search_projects_with_status() {
+        let db = crate::db::create_test_database();
+        let conn = db.conn.lock().unwrap();
+        conn.execute_batch(
+            r#"
+            INSERT INTO scopes (id, name, created_at, updated_at)
+                VALUES ('s1', 'Work', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z');
+            INSERT INTO projects (id, scope_id, name, path, primary_language, created_at, updated_at)
+                VALUES ('p1', 's1', 'api', '/work/api', 'Rust', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z'),
+                       ('p2', 's1', 'web', '/work/web', 'TypeScript', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z');
+            INSERT INTO project_tags (id, project_id, tag) VALUES ('t1', 'p1', 'backend');
+            INSERT INTO git_status_cache (project_id, branch, has_uncommitted)
+                VALUES ('p2', 'main', 1);
+            "#,
+        )
+        .unwrap();
+
+        let search = |q: &str| -> Vec<String> {
+            let query = ProjectQuery::parse(q).unwrap();
+            search_projects_with_status(&conn, &query, None, 50)
+                .unwrap()
+                .into_iter()
+                .map(|p| p.project.id)
+                .collect()
+        };
+
+        assert_eq!(search("tag:backend"), vec!["p1"]);
+        assert_eq!(search("lang:rust scope:work"), vec!["p1"]);
+        assert_eq!(search("dirty:true"), vec!["p2"]);
+        assert_eq!(search("-dirty:true"), vec!["p1"]);
+        assert_eq!(search("we"), vec!["p2"]);
+        assert_eq!(search("API"), vec!["p1"]);
+        assert_eq!(search("-work/w"), vec!["p1"]);
+        assert_eq!(search("last-opened:<30d"), Vec::<String>::new());
+    }
 }
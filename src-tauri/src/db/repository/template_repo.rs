@@ -0,0 +1,231 @@
+//! Repository for project template database operations
+
+use chrono::Utc;
+use rusqlite::{Connection, OptionalExtension};
+
+use crate::db::models::{
+    CreateProjectTemplateRequest, ProjectTemplate, TemplateKind, UpdateProjectTemplateRequest,
+};
+use crate::error::{PanagerError, Result};
+
+const TEMPLATE_COLUMNS: &str = "id, scope_id, name, description, kind, source, variables, sort_order, created_at, updated_at";
+
+/// Parse a row selected with `TEMPLATE_COLUMNS`
+fn parse_template_row(row: &rusqlite::Row) -> rusqlite::Result<ProjectTemplate> {
+    Ok(ProjectTemplate {
+        id: row.get(0)?,
+        scope_id: row.get(1)?,
+        name: row.get(2)?,
+        description: row.get(3)?,
+        kind: TemplateKind::parse(&row.get::<_, String>(4)?).unwrap_or(TemplateKind::Command),
+        source: row.get(5)?,
+        variables: serde_json::from_str(&row.get::<_, String>(6)?).unwrap_or_default(),
+        sort_order: row.get(7)?,
+        created_at: row
+            .get::<_, String>(8)?
+            .parse()
+            .unwrap_or_else(|_| Utc::now()),
+        updated_at: row
+            .get::<_, String>(9)?
+            .parse()
+            .unwrap_or_else(|_| Utc::now()),
+    })
+}
+
+/// Create a new project template
+pub fn create_project_template(
+    conn: &Connection,
+    request: &CreateProjectTemplateRequest,
+) -> Result<ProjectTemplate> {
+    let id = uuid::Uuid::new_v4().to_string();
+    let now = Utc::now();
+
+    let max_order: i32 = conn
+        .query_row(
+            "SELECT COALESCE(MAX(sort_order), -1) FROM project_templates WHERE scope_id = ?1",
+            [&request.scope_id],
+            |row| row.get(0),
+        )
+        .map_err(PanagerError::Database)?;
+
+    let variables = serde_json::to_string(&request.variables)?;
+
+    conn.execute(
+        r#"
+        INSERT INTO project_templates (id, scope_id, name, description, kind, source, variables, sort_order, created_at, updated_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+        "#,
+        (
+            &id,
+            &request.scope_id,
+            &request.name,
+            &request.description,
+            request.kind.as_str(),
+            &request.source,
+            &variables,
+            max_order + 1,
+            now.to_rfc3339(),
+            now.to_rfc3339(),
+        ),
+    )
+    .map_err(PanagerError::Database)?;
+
+    Ok(ProjectTemplate {
+        id,
+        scope_id: request.scope_id.clone(),
+        name: request.name.clone(),
+        description: request.description.clone(),
+        kind: request.kind,
+        source: request.source.clone(),
+        variables: request.variables.clone(),
+        sort_order: max_order + 1,
+        created_at: now,
+        updated_at: now,
+    })
+}
+
+/// Update a project template, returning the updated row
+pub fn update_project_template(
+    conn: &Connection,
+    template_id: &str,
+    request: &UpdateProjectTemplateRequest,
+) -> Result<ProjectTemplate> {
+    let existing = get_project_template_by_id(conn, template_id)?
+        .ok_or_else(|| PanagerError::not_found("Template", template_id))?;
+
+    let variables = match &request.variables {
+        Some(v) => serde_json::to_string(v)?,
+        None => serde_json::to_string(&existing.variables)?,
+    };
+
+    conn.execute(
+        r#"
+        UPDATE project_templates
+        SET name = ?1, description = ?2, kind = ?3, source = ?4, variables = ?5, updated_at = ?6
+        WHERE id = ?7
+        "#,
+        (
+            request.name.as_ref().unwrap_or(&existing.name),
+            request.description.as_ref().or(existing.description.as_ref()),
+            request.kind.unwrap_or(existing.kind).as_str(),
+            request.source.as_ref().unwrap_or(&existing.source),
+            &variables,
+            Utc::now().to_rfc3339(),
+            template_id,
+        ),
+    )
+    .map_err(PanagerError::Database)?;
+
+    get_project_template_by_id(conn, template_id)?
+        .ok_or_else(|| PanagerError::not_found("Template", template_id))
+}
+
+/// Delete a project template
+pub fn delete_project_template(conn: &Connection, template_id: &str) -> Result<()> {
+    conn.execute("DELETE FROM project_templates WHERE id = ?1", [template_id])
+        .map_err(PanagerError::Database)?;
+    Ok(())
+}
+
+/// Get all templates for a scope
+pub fn get_project_templates(conn: &Connection, scope_id: &str) -> Result<Vec<ProjectTemplate>> {
+    let sql = format!(
+        "SELECT {} FROM project_templates WHERE scope_id = ?1 ORDER BY sort_order ASC",
+        TEMPLATE_COLUMNS
+    );
+    let mut stmt = conn.prepare(&sql).map_err(PanagerError::Database)?;
+
+    let templates = stmt
+        .query_map([scope_id], parse_template_row)
+        .map_err(PanagerError::Database)?
+        .collect::<std::result::Result<Vec<_>, rusqlite::Error>>()
+        .map_err(PanagerError::Database)?;
+
+    Ok(templates)
+}
+
+/// Get a project template by ID
+pub fn get_project_template_by_id(
+    conn: &Connection,
+    template_id: &str,
+) -> Result<Option<ProjectTemplate>> {
+    let sql = format!(
+        "SELECT {} FROM project_templates WHERE id = ?1",
+        TEMPLATE_COLUMNS
+    );
+
+    conn.query_row(&sql, [template_id], parse_template_row)
+        .optional()
+        .map_err(PanagerError::Database)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::create_test_database;
+    use crate::db::models::TemplateVariable;
+
+    fn insert_scope(conn: &Connection, id: &str) {
+        let now = Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO scopes (id, name, created_at, updated_at) VALUES (?1, ?2, ?3, ?4)",
+            (id, "Test Scope", &now, &now),
+        )
+        .unwrap();
+    }
+
+    fn request(scope_id: &str, name: &str) -> CreateProjectTemplateRequest {
+        CreateProjectTemplateRequest {
+            scope_id: scope_id.to_string(),
+            name: name.to_string(),
+            description: None,
+            kind: TemplateKind::Git,
+            source: "git@github.com:org/{{name}}.git".to_string(),
+            variables: vec![TemplateVariable {
+                name: "org".to_string(),
+                label: Some("Organization".to_string()),
+                default_value: None,
+                required: true,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_template_crud() {
+        let db = create_test_database();
+        let conn = db.conn.lock().unwrap();
+        insert_scope(&conn, "scope-1");
+
+        let first = create_project_template(&conn, &request("scope-1", "Service")).unwrap();
+        let second = create_project_template(&conn, &request("scope-1", "Library")).unwrap();
+        assert_eq!(first.sort_order, 0);
+        assert_eq!(second.sort_order, 1);
+
+        let templates = get_project_templates(&conn, "scope-1").unwrap();
+        assert_eq!(templates.len(), 2);
+        assert_eq!(templates[0].variables.len(), 1);
+        assert_eq!(templates[0].kind, TemplateKind::Git);
+
+        let updated = update_project_template(
+            &conn,
+            &first.id,
+            &UpdateProjectTemplateRequest {
+                name: Some("Web Service".to_string()),
+                description: None,
+                kind: Some(TemplateKind::Command),
+                source: None,
+                variables: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(updated.name, "Web Service");
+        assert_eq!(updated.kind, TemplateKind::Command);
+        assert_eq!(updated.source, first.source);
+        assert_eq!(updated.variables.len(), 1);
+
+        delete_project_template(&conn, &second.id).unwrap();
+        assert!(get_project_template_by_id(&conn, &second.id)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -147,6 +147,13 @@ async fn handle_event(
             emit_all_diagnostics_updated(app)?;
         }
 
+        AppEvent::DatabaseImported { .. } => {
+            // Scopes, projects and policies were all replaced
+            tracing::debug!("Database imported, re-scanning all diagnostics");
+            scanner.scan_all_scopes(&db)?;
+            emit_all_diagnostics_updated(app)?;
+        }
+
         AppEvent::SettingChanged { key, .. } => {
             if matches!(key.as_str(), "diagnostics_enabled" | "diagnostics_scan_interval") {
                 tracing::debug!("Diagnostics setting '{}' changed", key);
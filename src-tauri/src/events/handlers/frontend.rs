@@ -3,8 +3,8 @@
 //! This handler forwards application events to the Tauri event system
 //! so the frontend can react to state changes.
 
-use crate::events::AppEvent;
-use tauri::{AppHandle, Emitter};
+use crate::events::{AppEvent, EventSubscriptions};
+use tauri::{AppHandle, Emitter, EventTarget, Manager};
 use tokio::sync::broadcast;
 
 /// The event name used for forwarding events to the frontend.
@@ -13,21 +13,17 @@ pub const FRONTEND_EVENT_NAME: &str = "app-event";
 /// Start the frontend event forwarder.
 ///
 /// This handler listens to all events from the event bus and forwards them
-/// to the Tauri event system using the "app-event" event name.
+/// to every open window using the "app-event" event name.
 ///
-/// The frontend can listen to this event to react to all application state changes.
+/// Windows that registered an event subscription only receive the events
+/// matching it; all other windows receive every event.
 pub fn start_handler(app_handle: AppHandle, mut receiver: broadcast::Receiver<AppEvent>) {
     tracing::debug!("Starting frontend event forwarder");
 
     tauri::async_runtime::spawn(async move {
         loop {
             match receiver.recv().await {
-                Ok(event) => {
-                    // Forward to frontend via Tauri events
-                    if let Err(e) = app_handle.emit(FRONTEND_EVENT_NAME, &event) {
-                        tracing::warn!("Failed to emit event to frontend: {}", e);
-                    }
-                }
+                Ok(event) => forward_event(&app_handle, &event),
                 Err(broadcast::error::RecvError::Lagged(n)) => {
                     tracing::warn!(
                         "Frontend event forwarder lagged {} events - some UI updates may be missed",
@@ -42,3 +38,27 @@ pub fn start_handler(app_handle: AppHandle, mut receiver: broadcast::Receiver<Ap
         }
     });
 }
+
+/// Deliver an event to each window interested in it.
+fn forward_event(app_handle: &AppHandle, event: &AppEvent) {
+    let subscriptions = app_handle.try_state::<EventSubscriptions>();
+
+    for label in app_handle.webview_windows().into_keys() {
+        let interested = subscriptions
+            .as_ref()
+            .map(|s| s.is_interested(&label, event))
+            .unwrap_or(true);
+
+        if !interested {
+            continue;
+        }
+
+        if let Err(e) = app_handle.emit_to(
+            EventTarget::webview_window(label.as_str()),
+            FRONTEND_EVENT_NAME,
+            event,
+        ) {
+            tracing::warn!("Failed to emit event to window '{}': {}", label, e);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Project health cache event handler.
+//!
+//! This handler drops cached [`ProjectHealth`](crate::services::health::ProjectHealth)
+//! entries whenever the signals they were computed from change, so the
+//! dashboard doesn't show stale status for up to the cache TTL.
+
+use crate::events::AppEvent;
+use crate::services::health::ProjectHealthCache;
+use tauri::{AppHandle, Manager};
+use tokio::sync::broadcast;
+
+/// Start the health cache handler.
+pub fn start_handler(app_handle: AppHandle, mut receiver: broadcast::Receiver<AppEvent>) {
+    tracing::debug!("Starting health cache event handler");
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let event = match receiver.recv().await {
+                Ok(event) => event,
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    tracing::warn!("Health handler lagged {} events", n);
+                    // Missed events may have touched any project
+                    app_handle.state::<ProjectHealthCache>().clear();
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    tracing::info!("Event bus closed, stopping health handler");
+                    break;
+                }
+            };
+
+            let cache = app_handle.state::<ProjectHealthCache>();
+            match event {
+                AppEvent::ProjectRemoved { project_id, .. }
+                | AppEvent::ProjectMoved { project_id, .. }
+                | AppEvent::ProjectPathChanged { project_id, .. }
+                | AppEvent::ProjectGitStatusChanged { project_id, .. } => {
+                    cache.invalidate(&project_id);
+                }
+                // Health includes diagnostics counts
+                AppEvent::DiagnosticsUpdated { scope_id }
+                | AppEvent::DiagnosticsCleared { scope_id, .. }
+                | AppEvent::ScopeDeleted { scope_id } => {
+                    cache.invalidate_scope(&scope_id);
+                }
+                AppEvent::DatabaseImported { .. } => cache.clear(),
+                _ => {}
+            }
+        }
+    });
+}
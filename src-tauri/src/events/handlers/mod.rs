@@ -5,3 +5,5 @@
 
 pub mod diagnostics;
 pub mod frontend;
+pub mod health;
+pub mod tray;
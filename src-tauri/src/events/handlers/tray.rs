@@ -0,0 +1,71 @@
+//! Tray and badge event handler.
+//!
+//! This handler keeps the system tray tooltip and the main window's badge
+//! count in sync with the number of open diagnostic issues across all scopes.
+
+use crate::db::Database;
+use crate::events::AppEvent;
+use crate::platform::TRAY_ID;
+use crate::services::diagnostics::DiagnosticsRepository;
+use tauri::{AppHandle, Manager};
+use tokio::sync::broadcast;
+
+/// Start the tray/badge handler.
+///
+/// Only diagnostics events are relevant here; everything else is ignored.
+pub fn start_handler(app_handle: AppHandle, mut receiver: broadcast::Receiver<AppEvent>) {
+    tracing::debug!("Starting tray event handler");
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(AppEvent::DiagnosticsUpdated { .. })
+                | Ok(AppEvent::DiagnosticsCleared { .. }) => {
+                    if let Err(e) = update_issue_badge(&app_handle) {
+                        tracing::warn!("Failed to update tray badge: {}", e);
+                    }
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    tracing::warn!("Tray handler lagged {} events", n);
+                    // Recompute from the database so we don't show stale counts
+                    let _ = update_issue_badge(&app_handle);
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    tracing::info!("Event bus closed, stopping tray handler");
+                    break;
+                }
+            }
+        }
+    });
+}
+
+/// Recompute the issue count and push it to the tray and window badge.
+fn update_issue_badge(app: &AppHandle) -> Result<(), String> {
+    let db = app.state::<Database>();
+    let summaries = DiagnosticsRepository::get_all_summaries(&db)?;
+
+    let issue_count: i64 = summaries
+        .iter()
+        .map(|s| (s.error_count + s.warning_count) as i64)
+        .sum();
+
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        let tooltip = match issue_count {
+            0 => "Panager".to_string(),
+            1 => "Panager - 1 issue".to_string(),
+            n => format!("Panager - {} issues", n),
+        };
+        tray.set_tooltip(Some(tooltip)).map_err(|e| e.to_string())?;
+    }
+
+    if let Some(window) = app.get_webview_window("main") {
+        let badge = if issue_count > 0 { Some(issue_count) } else { None };
+        // Badges are not supported on every platform/desktop environment
+        if let Err(e) = window.set_badge_count(badge) {
+            tracing::debug!("Window badge not updated: {}", e);
+        }
+    }
+
+    Ok(())
+}
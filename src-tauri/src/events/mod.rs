@@ -17,13 +17,16 @@
 //! });
 //!
 //! // The diagnostics handler will automatically scan the new project
-//! // The frontend handler will notify the UI to refresh
+//! // The frontend handler will notify every interested window to refresh
+//! // The tray handler will update the issue badge
 //! ```
 
 pub mod handlers;
+pub mod subscriptions;
 pub mod types;
 
-pub use types::AppEvent;
+pub use subscriptions::{EventSubscription, EventSubscriptions};
+pub use types::{AppEvent, EventCategory};
 
 use std::sync::Arc;
 use tauri::{AppHandle, Manager};
@@ -100,6 +103,12 @@ pub fn start_event_handlers(app_handle: AppHandle) {
     // Diagnostics handler - triggers scans when relevant events occur
     handlers::diagnostics::start_handler(app_handle.clone(), event_bus.subscribe());
 
+    // Tray handler - keeps the tray tooltip and window badge in sync
+    handlers::tray::start_handler(app_handle.clone(), event_bus.subscribe());
+
+    // Health handler - drops cached project health when its inputs change
+    handlers::health::start_handler(app_handle.clone(), event_bus.subscribe());
+
     tracing::info!("Event handlers started");
 }
 
@@ -0,0 +1,157 @@
+//! Per-window event subscriptions.
+//!
+//! By default every window receives every event. A window can narrow what it
+//! receives by registering an [`EventSubscription`] with a set of categories
+//! and/or scopes. The frontend forwarder consults [`EventSubscriptions`]
+//! before delivering an event to a window.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::types::{AppEvent, EventCategory};
+
+/// Filter describing which events a window wants to receive.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct EventSubscription {
+    /// Categories to receive (empty = all categories)
+    pub categories: Vec<EventCategory>,
+    /// Scopes to receive events for (empty = all scopes).
+    /// Global events without a scope are always delivered.
+    pub scope_ids: Vec<String>,
+}
+
+impl EventSubscription {
+    /// Check whether an event passes this subscription's filters.
+    pub fn matches(&self, event: &AppEvent) -> bool {
+        if !self.categories.is_empty() && !self.categories.contains(&event.category()) {
+            return false;
+        }
+
+        if self.scope_ids.is_empty() {
+            return true;
+        }
+
+        let targets = event.target_scope_ids();
+        targets.is_empty()
+            || targets
+                .iter()
+                .any(|scope_id| self.scope_ids.iter().any(|s| s == scope_id))
+    }
+}
+
+/// Registry of event subscriptions keyed by window label.
+#[derive(Default)]
+pub struct EventSubscriptions {
+    windows: Mutex<HashMap<String, EventSubscription>>,
+}
+
+impl EventSubscriptions {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register or replace the subscription for a window.
+    pub fn subscribe(&self, window_label: &str, subscription: EventSubscription) {
+        if let Ok(mut windows) = self.windows.lock() {
+            windows.insert(window_label.to_string(), subscription);
+        }
+    }
+
+    /// Remove a window's subscription, restoring the receive-everything default.
+    pub fn unsubscribe(&self, window_label: &str) {
+        if let Ok(mut windows) = self.windows.lock() {
+            windows.remove(window_label);
+        }
+    }
+
+    /// Get the current subscription for a window, if one is registered.
+    pub fn get(&self, window_label: &str) -> Option<EventSubscription> {
+        self.windows
+            .lock()
+            .ok()
+            .and_then(|windows| windows.get(window_label).cloned())
+    }
+
+    /// Check whether a window should receive an event.
+    ///
+    /// Windows without a registered subscription receive all events.
+    pub fn is_interested(&self, window_label: &str, event: &AppEvent) -> bool {
+        match self.windows.lock() {
+            Ok(windows) => windows
+                .get(window_label)
+                .map(|subscription| subscription.matches(event))
+                .unwrap_or(true),
+            Err(_) => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moved_event() -> AppEvent {
+        AppEvent::ProjectMoved {
+            project_id: "proj1".to_string(),
+            old_scope_id: "scope1".to_string(),
+            new_scope_id: "scope2".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_default_subscription_matches_everything() {
+        let subscription = EventSubscription::default();
+        assert!(subscription.matches(&moved_event()));
+    }
+
+    #[test]
+    fn test_category_filter() {
+        let subscription = EventSubscription {
+            categories: vec![EventCategory::Diagnostics],
+            scope_ids: vec![],
+        };
+        assert!(!subscription.matches(&moved_event()));
+        assert!(subscription.matches(&AppEvent::DiagnosticsUpdated {
+            scope_id: "scope1".to_string(),
+        }));
+    }
+
+    #[test]
+    fn test_scope_filter_includes_old_scope_and_global_events() {
+        let subscription = EventSubscription {
+            categories: vec![],
+            scope_ids: vec!["scope1".to_string()],
+        };
+        assert!(subscription.matches(&moved_event()));
+        assert!(!subscription.matches(&AppEvent::ScopeCreated {
+            scope_id: "scope3".to_string(),
+        }));
+        assert!(subscription.matches(&AppEvent::SettingChanged {
+            key: "theme".to_string(),
+            old_value: "light".to_string(),
+            new_value: "dark".to_string(),
+        }));
+    }
+
+    #[test]
+    fn test_registry_defaults_to_interested() {
+        let subscriptions = EventSubscriptions::new();
+        assert!(subscriptions.is_interested("main", &moved_event()));
+
+        subscriptions.subscribe(
+            "main",
+            EventSubscription {
+                categories: vec![EventCategory::Settings],
+                scope_ids: vec![],
+            },
+        );
+        assert!(!subscriptions.is_interested("main", &moved_event()));
+
+        subscriptions.unsubscribe("main");
+        assert!(subscriptions.is_interested("main", &moved_event()));
+    }
+}
@@ -6,6 +6,25 @@
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+/// Broad event categories used to filter event delivery.
+///
+/// Frontend windows subscribe to the categories they render so that, for
+/// example, a settings window is not woken up by every git status refresh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum EventCategory {
+    /// Project add/remove/move/path/git status events
+    Project,
+    /// Scope configuration events
+    Scope,
+    /// Settings and Max feature events
+    Settings,
+    /// Background folder scanner events
+    Scanner,
+    /// Diagnostics scan results
+    Diagnostics,
+}
+
 /// Application events that flow through the event bus.
 ///
 /// Events are categorized by their source domain:
@@ -99,6 +118,11 @@ pub enum AppEvent {
         enabled: bool,
     },
 
+    /// The database was replaced with the contents of a backup
+    DatabaseImported {
+        path: String,
+    },
+
     // =========================================================================
     // Folder Scanner Events
     // =========================================================================
@@ -124,6 +148,49 @@ pub enum AppEvent {
 }
 
 impl AppEvent {
+    /// Get the category this event belongs to.
+    pub fn category(&self) -> EventCategory {
+        match self {
+            AppEvent::ProjectAdded { .. }
+            | AppEvent::ProjectRemoved { .. }
+            | AppEvent::ProjectMoved { .. }
+            | AppEvent::ProjectPathChanged { .. }
+            | AppEvent::ProjectGitStatusChanged { .. } => EventCategory::Project,
+
+            AppEvent::ScopeCreated { .. }
+            | AppEvent::ScopeDeleted { .. }
+            | AppEvent::ScopeDefaultFolderChanged { .. }
+            | AppEvent::ScopeGitIdentityChanged { .. }
+            | AppEvent::ScopeSshAliasChanged { .. } => EventCategory::Scope,
+
+            AppEvent::SettingChanged { .. }
+            | AppEvent::MaxFeatureToggled { .. }
+            | AppEvent::DatabaseImported { .. } => EventCategory::Settings,
+
+            AppEvent::FolderScanCompleted { .. } => EventCategory::Scanner,
+
+            AppEvent::DiagnosticsUpdated { .. } | AppEvent::DiagnosticsCleared { .. } => {
+                EventCategory::Diagnostics
+            }
+        }
+    }
+
+    /// Get all scope IDs this event targets.
+    ///
+    /// Unlike [`AppEvent::scope_id`], this includes both the old and new scope
+    /// for moves, so consumers watching either scope are notified.
+    /// An empty result means the event is global (e.g., settings changes).
+    pub fn target_scope_ids(&self) -> Vec<&str> {
+        match self {
+            AppEvent::ProjectMoved {
+                old_scope_id,
+                new_scope_id,
+                ..
+            } => vec![old_scope_id.as_str(), new_scope_id.as_str()],
+            _ => self.scope_id().into_iter().collect(),
+        }
+    }
+
     /// Get the scope ID associated with this event, if any.
     pub fn scope_id(&self) -> Option<&str> {
         match self {
@@ -142,7 +209,9 @@ impl AppEvent {
 
             AppEvent::ProjectMoved { new_scope_id, .. } => Some(new_scope_id),
 
-            AppEvent::SettingChanged { .. } | AppEvent::MaxFeatureToggled { .. } => None,
+            AppEvent::SettingChanged { .. }
+            | AppEvent::MaxFeatureToggled { .. }
+            | AppEvent::DatabaseImported { .. } => None,
         }
     }
 
@@ -217,6 +286,9 @@ impl AppEvent {
                     if *enabled { "enabled" } else { "disabled" }
                 )
             }
+            AppEvent::DatabaseImported { path } => {
+                format!("Database imported from {}", path)
+            }
             AppEvent::FolderScanCompleted {
                 scope_id,
                 projects_found,
@@ -272,4 +344,34 @@ mod tests {
         };
         assert_eq!(event.project_id(), None);
     }
+
+    #[test]
+    fn test_event_category() {
+        let event = AppEvent::ProjectGitStatusChanged {
+            project_id: "proj1".to_string(),
+            scope_id: "scope1".to_string(),
+        };
+        assert_eq!(event.category(), EventCategory::Project);
+
+        let event = AppEvent::DiagnosticsUpdated {
+            scope_id: "scope1".to_string(),
+        };
+        assert_eq!(event.category(), EventCategory::Diagnostics);
+    }
+
+    #[test]
+    fn test_event_target_scope_ids() {
+        let event = AppEvent::ProjectMoved {
+            project_id: "proj1".to_string(),
+            old_scope_id: "scope1".to_string(),
+            new_scope_id: "scope2".to_string(),
+        };
+        assert_eq!(event.target_scope_ids(), vec!["scope1", "scope2"]);
+
+        let event = AppEvent::MaxFeatureToggled {
+            feature: "max_git_integration".to_string(),
+            enabled: true,
+        };
+        assert!(event.target_scope_ids().is_empty());
+    }
 }
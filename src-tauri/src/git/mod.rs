@@ -5,11 +5,14 @@
 //! - Git configuration management
 //! - Git identity handling
 //! - Git URL parsing
+//! - Dirty-work preflight checks for folder operations
 
 pub mod config;
 pub mod identity;
+pub mod preflight;
 pub mod url;
 
 pub use config::*;
 pub use identity::*;
+pub use preflight::*;
 pub use url::*;
@@ -0,0 +1,296 @@
+//! Dirty-work detection for destructive project operations
+//!
+//! Before a project folder is moved or deleted, [`inspect_work`] reports
+//! uncommitted files, unpushed commits and stashes so the operation can be
+//! refused, preceded by a stash, or explicitly forced.
+
+use std::path::Path;
+use std::process::Command;
+
+use git2::{BranchType, Repository, StatusOptions};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Folder operation being checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum FolderOperation {
+    Move,
+    Delete,
+}
+
+/// What to do when a project has work that could be lost
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum DirtyWorkPolicy {
+    /// Fail the operation (default)
+    #[default]
+    Refuse,
+    /// Stash uncommitted and untracked changes first (moves only)
+    Stash,
+    /// Proceed anyway
+    Force,
+}
+
+/// Unsaved work found in a project folder
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkInspection {
+    pub exists: bool,
+    pub is_git_repo: bool,
+    /// Modified or staged tracked files
+    pub uncommitted_files: u32,
+    pub untracked_files: u32,
+    /// Local branches with commits not on their upstream (or never pushed)
+    pub unpushed_branches: Vec<String>,
+    pub stash_count: u32,
+    pub has_remote: bool,
+}
+
+impl WorkInspection {
+    /// Reasons the operation could lose work (empty = safe)
+    ///
+    /// Moving keeps the whole folder, so only the working tree matters
+    /// (tools holding open files can clobber it). Deleting loses everything
+    /// that isn't on a remote. Plain folders have no git state to lose, so
+    /// they report nothing; callers can show `is_git_repo` separately.
+    pub fn risks(&self, operation: FolderOperation) -> Vec<String> {
+        let mut risks = Vec::new();
+        if !self.exists || !self.is_git_repo {
+            return risks;
+        }
+
+        if self.uncommitted_files > 0 {
+            risks.push(format!("{} uncommitted file(s)", self.uncommitted_files));
+        }
+        if self.untracked_files > 0 {
+            risks.push(format!("{} untracked file(s)", self.untracked_files));
+        }
+
+        if operation == FolderOperation::Delete {
+            if !self.has_remote {
+                risks.push("Repository has no remote; history exists only locally".to_string());
+            } else if !self.unpushed_branches.is_empty() {
+                risks.push(format!(
+                    "Unpushed commits on: {}",
+                    self.unpushed_branches.join(", ")
+                ));
+            }
+            if self.stash_count > 0 {
+                risks.push(format!("{} stash entr(ies)", self.stash_count));
+            }
+        }
+
+        risks
+    }
+
+    /// Whether the working tree has changes a stash would capture
+    pub fn has_working_changes(&self) -> bool {
+        self.uncommitted_files > 0 || self.untracked_files > 0
+    }
+}
+
+/// Preflight result for one project
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectPreflight {
+    pub project_id: String,
+    pub project_name: String,
+    pub path: String,
+    pub inspection: WorkInspection,
+    /// Reasons the operation could lose work (empty = safe)
+    pub risks: Vec<String>,
+}
+
+/// Inspect a folder for work that could be lost
+pub fn inspect_work(path: &Path) -> WorkInspection {
+    let mut inspection = WorkInspection {
+        exists: path.is_dir(),
+        ..Default::default()
+    };
+    if !inspection.exists {
+        return inspection;
+    }
+
+    let Ok(mut repo) = Repository::open(path) else {
+        return inspection;
+    };
+    inspection.is_git_repo = true;
+
+    let mut opts = StatusOptions::new();
+    opts.include_untracked(true).recurse_untracked_dirs(true);
+    if let Ok(statuses) = repo.statuses(Some(&mut opts)) {
+        for entry in statuses.iter() {
+            let status = entry.status();
+            if status.is_wt_new() {
+                inspection.untracked_files += 1;
+            } else if !status.is_ignored() {
+                inspection.uncommitted_files += 1;
+            }
+        }
+    }
+
+    inspection.has_remote = repo.remotes().map(|r| !r.is_empty()).unwrap_or(false);
+    inspection.unpushed_branches = unpushed_branches(&repo, inspection.has_remote);
+
+    let mut stash_count = 0;
+    let _ = repo.stash_foreach(|_, _, _| {
+        stash_count += 1;
+        true
+    });
+    inspection.stash_count = stash_count;
+
+    inspection
+}
+
+/// Local branches with commits that aren't on their upstream
+fn unpushed_branches(repo: &Repository, has_remote: bool) -> Vec<String> {
+    let Ok(branches) = repo.branches(Some(BranchType::Local)) else {
+        return Vec::new();
+    };
+
+    branches
+        .filter_map(|b| b.ok())
+        .filter_map(|(branch, _)| {
+            let name = branch.name().ok().flatten()?.to_string();
+            let local = branch.get().target()?;
+            match branch.upstream() {
+                Ok(upstream) => {
+                    let remote = upstream.get().target()?;
+                    let (ahead, _) = repo.graph_ahead_behind(local, remote).ok()?;
+                    (ahead > 0).then_some(name)
+                }
+                // Never pushed
+                Err(_) if has_remote => Some(name),
+                Err(_) => None,
+            }
+        })
+        .collect()
+}
+
+/// Stash uncommitted and untracked changes
+pub fn stash_changes(path: &Path, message: &str) -> Result<(), String> {
+    let output = Command::new("git")
+        .args(["stash", "push", "--include-untracked", "-m", message])
+        .current_dir(path)
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Failed to stash changes: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Apply the dirty-work policy before a folder operation
+///
+/// Returns an error describing the risks when the operation must not
+/// proceed. With [`DirtyWorkPolicy::Stash`] a move stashes the working
+/// tree first; a delete refuses since the stash would be deleted too.
+pub fn guard_folder_operation(
+    path: &Path,
+    operation: FolderOperation,
+    policy: DirtyWorkPolicy,
+) -> Result<(), String> {
+    if policy == DirtyWorkPolicy::Force {
+        return Ok(());
+    }
+
+    let inspection = inspect_work(path);
+    let risks = inspection.risks(operation);
+    if risks.is_empty() {
+        return Ok(());
+    }
+
+    if policy == DirtyWorkPolicy::Stash && operation == FolderOperation::Move {
+        // Only working-tree risks apply to moves, and a stash covers them
+        tracing::info!("Stashing changes in {} before moving", path.display());
+        return stash_changes(path, "Panager: changes stashed before moving project");
+    }
+
+    Err(format!(
+        "Refusing to {} {}: {}",
+        match operation {
+            FolderOperation::Move => "move",
+            FolderOperation::Delete => "delete",
+        },
+        path.display(),
+        risks.join("; ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_risks_by_operation() {
+        let inspection = WorkInspection {
+            exists: true,
+            is_git_repo: true,
+            uncommitted_files: 0,
+            untracked_files: 0,
+            unpushed_branches: vec!["feature".to_string()],
+            stash_count: 1,
+            has_remote: true,
+        };
+
+        assert!(inspection.risks(FolderOperation::Move).is_empty());
+        assert_eq!(inspection.risks(FolderOperation::Delete).len(), 2);
+
+        let dirty = WorkInspection {
+            uncommitted_files: 2,
+            ..inspection
+        };
+        assert_eq!(
+            dirty.risks(FolderOperation::Move),
+            vec!["2 uncommitted file(s)".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_missing_folder_is_safe() {
+        let inspection = inspect_work(Path::new("/nonexistent/panager_test_preflight"));
+        assert!(!inspection.exists);
+        assert!(inspection.risks(FolderOperation::Delete).is_empty());
+    }
+
+    #[test]
+    fn test_plain_folder_can_be_deleted() {
+        let dir = env::temp_dir().join("panager_test_preflight_plain");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let inspection = inspect_work(&dir);
+        assert!(inspection.exists && !inspection.is_git_repo);
+        assert!(inspection.risks(FolderOperation::Delete).is_empty());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_inspect_untracked_and_guard() {
+        let dir = env::temp_dir().join("panager_test_preflight_repo");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        Repository::init(&dir).unwrap();
+        fs::write(dir.join("notes.txt"), "draft").unwrap();
+
+        let inspection = inspect_work(&dir);
+        assert!(inspection.is_git_repo);
+        assert_eq!(inspection.untracked_files, 1);
+        assert!(!inspection.has_remote);
+
+        assert!(guard_folder_operation(&dir, FolderOperation::Delete, DirtyWorkPolicy::Refuse).is_err());
+        assert!(guard_folder_operation(&dir, FolderOperation::Delete, DirtyWorkPolicy::Stash).is_err());
+        assert!(guard_folder_operation(&dir, FolderOperation::Delete, DirtyWorkPolicy::Force).is_ok());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -51,6 +51,7 @@ pub fn run() {
             // Projects
             commands::projects::get_projects,
             commands::projects::get_all_projects,
+            commands::projects::search_projects,
             commands::projects::get_project,
             commands::projects::create_project,
             commands::projects::update_project,
@@ -59,6 +60,8 @@ pub fn run() {
             commands::projects::update_project_last_opened,
             commands::projects::move_project_to_scope,
             commands::projects::move_project_to_scope_with_folder,
+            commands::projects::preflight_folder_operation,
+            commands::projects::stash_project_changes,
             commands::projects::add_project_tag,
             commands::projects::remove_project_tag,
             commands::projects::scan_folder_for_projects,
@@ -144,12 +147,80 @@ pub fn run() {
             services::diagnostics::get_disabled_diagnostic_rules,
             services::diagnostics::get_diagnostic_rule_metadata,
             services::diagnostics::fix_diagnostic_issue,
+            services::diagnostics::get_scope_policy,
+            services::diagnostics::set_scope_policy,
             // Terminal
             commands::terminal::open_terminal,
             // Terminals
             commands::terminals::detect_terminals,
             commands::terminals::sync_terminals,
             commands::terminals::get_terminals,
+            // Telemetry
+            services::telemetry::get_telemetry_status,
+            services::telemetry::set_telemetry_consent,
+            services::telemetry::get_telemetry_queue,
+            services::telemetry::purge_telemetry_queue,
+            services::telemetry::upload_telemetry_queue,
+            // Event Subscriptions
+            commands::events::subscribe_events,
+            commands::events::unsubscribe_events,
+            commands::events::get_event_subscription,
+            // Project Templates
+            commands::templates::get_project_templates,
+            commands::templates::create_project_template,
+            commands::templates::update_project_template,
+            commands::templates::delete_project_template,
+            commands::templates::create_project_from_template,
+            // Project Archiving
+            services::archive::archive_project,
+            services::archive::unarchive_project,
+            services::archive::get_archived_projects,
+            // Database Backup
+            services::backup::export_database,
+            services::backup::import_database,
+            services::backup::create_backup_now,
+            services::backup::list_backups,
+            // Batch Operations
+            services::batch::move_projects_to_scope,
+            services::batch::add_tag_to_projects,
+            services::batch::delete_projects,
+            services::batch::git_fetch_projects,
+            // External Project Import
+            services::importers::get_external_project_candidates,
+            services::importers::import_external_projects,
+            // Background Fetch
+            services::fetch_scheduler::get_scope_fetch_policy,
+            services::fetch_scheduler::set_scope_fetch_policy,
+            services::fetch_scheduler::get_fetch_conditions,
+            services::fetch_scheduler::fetch_scope_now,
+            // Project Activity
+            services::activity::get_project_activity,
+            // Project Health
+            services::health::get_project_health,
+            services::health::get_projects_health,
+            services::health::invalidate_project_health,
+            // Clone Queue
+            services::clone_queue::queue_clone,
+            services::clone_queue::get_clone_jobs,
+            services::clone_queue::cancel_clone,
+            services::clone_queue::answer_clone_credential,
+            services::clone_queue::clear_finished_clones,
+            // Git Providers
+            services::providers::start_provider_auth,
+            services::providers::complete_provider_auth,
+            services::providers::get_provider_accounts,
+            services::providers::remove_provider_account,
+            services::providers::get_provider_owners,
+            services::providers::list_remote_repositories,
+            services::providers::clone_remote_repositories,
+            services::providers::get_project_ci_status,
+            services::providers::get_projects_ci_status,
+            services::providers::get_project_prs,
+            services::providers::refresh_project_ci_status,
+            // Logging
+            commands::logging::get_log_settings,
+            commands::logging::set_log_level,
+            commands::logging::get_recent_logs,
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
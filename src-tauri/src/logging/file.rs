@@ -0,0 +1,167 @@
+//! Rotating log file output
+//!
+//! Logs are written to `panager.log` in the app data `logs` directory.
+//! When the file exceeds [`MAX_LOG_FILE_BYTES`] it is rotated to
+//! `panager.log.1`, shifting older files up to [`MAX_ROTATED_FILES`].
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Maximum size of the active log file before rotation (5 MB)
+pub const MAX_LOG_FILE_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Number of rotated log files to keep
+pub const MAX_ROTATED_FILES: usize = 3;
+
+/// Name of the active log file
+const LOG_FILE_NAME: &str = "panager.log";
+
+/// Get the directory where log files are written
+pub fn log_dir() -> Option<PathBuf> {
+    crate::utils::paths::app_data_dir().map(|dir| dir.join("logs"))
+}
+
+/// Get the path of the active log file
+pub fn log_file_path() -> Option<PathBuf> {
+    log_dir().map(|dir| dir.join(LOG_FILE_NAME))
+}
+
+/// A size-based rotating file writer
+///
+/// Used as the writer for the file logging layer (wrapped in a Mutex).
+pub struct RollingFileWriter {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_bytes: u64,
+    max_files: usize,
+}
+
+impl RollingFileWriter {
+    /// Open (or create) the log file at `path` in append mode
+    pub fn open(path: impl Into<PathBuf>, max_bytes: u64, max_files: usize) -> io::Result<Self> {
+        let path = path.into();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+        Ok(Self {
+            path,
+            file,
+            size,
+            max_bytes,
+            max_files,
+        })
+    }
+
+    /// Shift `log.N` -> `log.N+1`, move the active file to `log.1`, and reopen
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        for index in (1..self.max_files).rev() {
+            let from = rotated_path(&self.path, index);
+            if from.exists() {
+                fs::rename(&from, rotated_path(&self.path, index + 1))?;
+            }
+        }
+        if self.max_files > 0 {
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        }
+
+        self.file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RollingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Path of the N-th rotated file (e.g. `panager.log.2`)
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}", index));
+    path.with_file_name(name)
+}
+
+/// Read the last `max_lines` lines across the active and rotated log files
+///
+/// Lines are returned oldest first. Missing files are skipped.
+pub fn read_recent_lines(path: &Path, max_lines: usize) -> io::Result<Vec<String>> {
+    let mut lines: Vec<String> = Vec::new();
+
+    // Walk from the active file backwards through rotated files
+    let candidates = std::iter::once(path.to_path_buf())
+        .chain((1..=MAX_ROTATED_FILES).map(|i| rotated_path(path, i)));
+
+    for file_path in candidates {
+        if lines.len() >= max_lines {
+            break;
+        }
+        let contents = match fs::read_to_string(&file_path) {
+            Ok(c) => c,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+
+        let needed = max_lines - lines.len();
+        let file_lines: Vec<&str> = contents.lines().collect();
+        let start = file_lines.len().saturating_sub(needed);
+
+        let mut older: Vec<String> = file_lines[start..].iter().map(|l| l.to_string()).collect();
+        older.append(&mut lines);
+        lines = older;
+    }
+
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_rotation_and_recent_lines() {
+        let dir = env::temp_dir().join("panager_test_log_rotation");
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("test.log");
+
+        let mut writer = RollingFileWriter::open(&path, 20, 2).unwrap();
+        writer.write_all(b"line one\n").unwrap();
+        writer.write_all(b"line two\n").unwrap();
+        // Exceeds 20 bytes -> rotates before writing
+        writer.write_all(b"line three\n").unwrap();
+        writer.flush().unwrap();
+
+        assert!(rotated_path(&path, 1).exists());
+
+        let recent = read_recent_lines(&path, 2).unwrap();
+        assert_eq!(recent, vec!["line two", "line three"]);
+
+        let all = read_recent_lines(&path, 10).unwrap();
+        assert_eq!(all, vec!["line one", "line two", "line three"]);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,109 @@
+//! Runtime log level configuration
+//!
+//! Tracks the base filter plus per-module overrides and renders them into
+//! an `EnvFilter` directive string.
+
+use std::collections::BTreeMap;
+
+/// Base filter directives plus per-target level overrides
+#[derive(Debug, Clone, Default)]
+pub struct LogLevels {
+    /// Base directives (default level or RUST_LOG value)
+    pub base: String,
+    /// Per-target overrides, e.g. `panager_lib::git` -> `trace`
+    pub overrides: BTreeMap<String, String>,
+}
+
+impl LogLevels {
+    /// Create with base directives and no overrides
+    pub fn new(base: impl Into<String>) -> Self {
+        Self {
+            base: base.into(),
+            overrides: BTreeMap::new(),
+        }
+    }
+
+    /// Render the full directive string for `EnvFilter`
+    ///
+    /// Base directives for a target that has an override are dropped so the
+    /// override always wins.
+    pub fn directives(&self) -> String {
+        let mut parts: Vec<String> = self
+            .base
+            .split(',')
+            .map(str::trim)
+            .filter(|d| !d.is_empty())
+            .filter(|d| match d.split_once('=') {
+                Some((target, _)) => !self.overrides.contains_key(target),
+                None => true,
+            })
+            .map(String::from)
+            .collect();
+
+        parts.extend(
+            self.overrides
+                .iter()
+                .map(|(target, level)| format!("{}={}", target, level)),
+        );
+
+        parts.join(",")
+    }
+}
+
+/// Validate and normalize a level name
+pub fn normalize_level(level: &str) -> Result<String, String> {
+    let level = level.trim().to_lowercase();
+    match level.as_str() {
+        "trace" | "debug" | "info" | "warn" | "error" | "off" => Ok(level),
+        _ => Err(format!(
+            "Invalid log level '{}'. Expected one of: trace, debug, info, warn, error, off",
+            level
+        )),
+    }
+}
+
+/// Validate a module target such as `panager_lib::services::diagnostics`
+pub fn validate_target(target: &str) -> Result<(), String> {
+    let valid = !target.is_empty()
+        && target
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':' || c == '-');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid log target '{}'", target))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directives_with_overrides() {
+        let mut levels = LogLevels::new("panager=info,warn");
+        assert_eq!(levels.directives(), "panager=info,warn");
+
+        levels
+            .overrides
+            .insert("panager".to_string(), "trace".to_string());
+        levels
+            .overrides
+            .insert("git2".to_string(), "debug".to_string());
+        assert_eq!(levels.directives(), "warn,git2=debug,panager=trace");
+    }
+
+    #[test]
+    fn test_normalize_level() {
+        assert_eq!(normalize_level("DEBUG").unwrap(), "debug");
+        assert!(normalize_level("verbose").is_err());
+    }
+
+    #[test]
+    fn test_validate_target() {
+        assert!(validate_target("panager_lib::services::diagnostics").is_ok());
+        assert!(validate_target("").is_err());
+        assert!(validate_target("bad target,x=trace").is_err());
+    }
+}
@@ -1,20 +1,57 @@
 //! Structured logging for Panager
 //!
 //! This module sets up tracing-based logging with configurable levels and outputs.
+//! Levels can be changed at runtime per module, and logs are mirrored to a
+//! rotating file in the app data directory so users can attach them to bug reports.
 
+mod file;
+mod filter;
+
+pub use file::{log_dir, log_file_path, read_recent_lines};
+pub use filter::LogLevels;
+
+use once_cell::sync::{Lazy, OnceCell};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
 use tracing_subscriber::{
     fmt,
     layer::SubscriberExt,
+    reload,
     util::SubscriberInitExt,
-    EnvFilter,
+    EnvFilter, Registry,
 };
 
+/// Setting key for persisted per-module level overrides
+pub const LOG_LEVEL_OVERRIDES_KEY: &str = "log_level_overrides";
+
+/// Current logging configuration exposed to the frontend
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct LogSettings {
+    /// Base filter directives (default level or RUST_LOG value)
+    pub base: String,
+    /// Per-module level overrides
+    pub overrides: BTreeMap<String, String>,
+    /// Path of the active log file, if file logging is available
+    pub log_file: Option<String>,
+}
+
+/// Handle used to swap the active filter at runtime
+static FILTER_HANDLE: OnceCell<reload::Handle<EnvFilter, Registry>> = OnceCell::new();
+
+/// Current base directives and per-module overrides
+static LOG_LEVELS: Lazy<Mutex<LogLevels>> = Lazy::new(|| Mutex::new(LogLevels::default()));
+
 /// Initialize the logging system
 ///
 /// This sets up tracing with:
 /// - Environment-based filtering via RUST_LOG env var
 /// - Default level of INFO in release builds, DEBUG in debug builds
 /// - Console output with timestamps and target information
+/// - Rotating file output in the app data `logs` directory
+/// - A reloadable filter so levels can be changed with [`set_log_level`]
 ///
 /// # Example
 /// ```ignore
@@ -31,12 +68,38 @@ pub fn init() {
     };
 
     // Allow override via RUST_LOG environment variable
-    let env_filter = EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| EnvFilter::new(default_level));
+    let base = std::env::var(EnvFilter::DEFAULT_ENV)
+        .ok()
+        .filter(|v| EnvFilter::try_new(v).is_ok())
+        .unwrap_or_else(|| default_level.to_string());
+
+    if let Ok(mut levels) = LOG_LEVELS.lock() {
+        *levels = LogLevels::new(base.clone());
+    }
+
+    let (filter_layer, handle) = reload::Layer::new(EnvFilter::new(&base));
+    let _ = FILTER_HANDLE.set(handle);
+
+    // File output is best-effort: keep console logging if the file can't be opened
+    let file_layer = log_file_path()
+        .and_then(|path| {
+            file::RollingFileWriter::open(path, file::MAX_LOG_FILE_BYTES, file::MAX_ROTATED_FILES)
+                .ok()
+        })
+        .map(|writer| {
+            fmt::layer()
+                .with_writer(Mutex::new(writer))
+                .with_ansi(false)
+                .with_target(true)
+                .with_thread_ids(false)
+                .with_file(true)
+                .with_line_number(true)
+                .compact()
+        });
 
-    // Build the subscriber with formatting layer
+    // Build the subscriber with formatting layers
     tracing_subscriber::registry()
-        .with(env_filter)
+        .with(filter_layer)
         .with(
             fmt::layer()
                 .with_target(true)
@@ -45,9 +108,81 @@ pub fn init() {
                 .with_line_number(true)
                 .compact()
         )
+        .with(file_layer)
         .init();
 }
 
+/// Set the log level for a module, or the base level when `target` is None
+///
+/// Passing `None` as the level removes the override for that target.
+/// Returns the resulting filter directives.
+pub fn set_log_level(target: Option<&str>, level: Option<&str>) -> Result<String, String> {
+    let mut levels = LOG_LEVELS.lock().map_err(|e| e.to_string())?;
+
+    match (target, level) {
+        (Some(target), Some(level)) => {
+            filter::validate_target(target)?;
+            levels
+                .overrides
+                .insert(target.to_string(), filter::normalize_level(level)?);
+        }
+        (Some(target), None) => {
+            levels.overrides.remove(target);
+        }
+        (None, Some(level)) => {
+            // Keep target-specific base directives, replace the global level
+            let level = filter::normalize_level(level)?;
+            let mut parts: Vec<String> = levels
+                .base
+                .split(',')
+                .map(str::trim)
+                .filter(|d| d.contains('='))
+                .map(String::from)
+                .collect();
+            parts.push(level);
+            levels.base = parts.join(",");
+        }
+        (None, None) => return Err("A target or a level is required".to_string()),
+    }
+
+    apply_levels(&levels)?;
+    Ok(levels.directives())
+}
+
+/// Replace all per-module overrides (e.g. when restoring persisted settings)
+pub fn set_log_overrides(overrides: BTreeMap<String, String>) -> Result<String, String> {
+    let mut levels = LOG_LEVELS.lock().map_err(|e| e.to_string())?;
+
+    let mut validated = BTreeMap::new();
+    for (target, level) in overrides {
+        filter::validate_target(&target)?;
+        validated.insert(target, filter::normalize_level(&level)?);
+    }
+    levels.overrides = validated;
+
+    apply_levels(&levels)?;
+    Ok(levels.directives())
+}
+
+/// Get a snapshot of the current base directives and overrides
+pub fn current_levels() -> LogLevels {
+    LOG_LEVELS
+        .lock()
+        .map(|levels| levels.clone())
+        .unwrap_or_default()
+}
+
+/// Push the given levels into the live subscriber
+fn apply_levels(levels: &LogLevels) -> Result<(), String> {
+    let filter = EnvFilter::try_new(levels.directives()).map_err(|e| e.to_string())?;
+
+    match FILTER_HANDLE.get() {
+        Some(handle) => handle.reload(filter).map_err(|e| e.to_string()),
+        // Logging not initialized (e.g. in tests) - nothing to reload
+        None => Ok(()),
+    }
+}
+
 /// Initialize logging for tests
 ///
 /// Similar to `init()` but with a test-friendly configuration.
@@ -2,5 +2,10 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
+    // Started by git/ssh as a credential helper for a queued clone
+    if panager_lib::services::clone_queue::askpass::is_askpass_invocation() {
+        std::process::exit(panager_lib::services::clone_queue::askpass::run_client());
+    }
+
     panager_lib::run()
 }
@@ -26,6 +26,9 @@ pub mod linux;
 #[cfg(target_os = "windows")]
 pub mod windows;
 
+/// Identifier of the system tray icon, used to look it up after creation.
+pub const TRAY_ID: &str = "panager-tray";
+
 /// Unified platform setup function
 ///
 /// Call this from lib.rs during app setup to initialize all platform-specific
@@ -21,7 +21,7 @@ pub fn build_tray_menu(app: &App) -> Result<Menu<Wry>, Box<dyn std::error::Error
 /// Sets up the menu, click handlers, and icon (if available).
 /// The icon is optional to handle platforms where it might not be set.
 pub fn create_tray_builder(app: &App, menu: &Menu<Wry>) -> TrayIconBuilder<Wry> {
-    let mut builder = TrayIconBuilder::new()
+    let mut builder = TrayIconBuilder::with_id(crate::platform::TRAY_ID)
         .menu(menu)
         .show_menu_on_left_click(false)
         .on_tray_icon_event(|tray, event| {
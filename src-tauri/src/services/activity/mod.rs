@@ -0,0 +1,59 @@
+//! Project activity statistics.
+//!
+//! Computes commit frequency, author activity, branch counts and
+//! contribution heatmap data from git history. History statistics are cached
+//! per repository HEAD, so they are only recomputed after the history
+//! changes; branch counts are re-read on every request.
+
+pub mod models;
+pub mod service;
+pub mod state;
+
+pub use models::{ActivityDay, AuthorActivity, ProjectActivity};
+pub use service::compute_project_activity;
+pub use state::ProjectActivityCache;
+
+use crate::db::repository::find_project_by_id;
+use crate::db::Database;
+use std::path::Path;
+use tauri::State;
+
+// =========================================================================
+// Tauri Commands
+// =========================================================================
+
+/// Get commit activity statistics for a project.
+///
+/// Cached results are reused while HEAD is unchanged, unless
+/// `force_refresh` is true.
+#[tauri::command]
+#[specta::specta]
+pub fn get_project_activity(
+    db: State<Database>,
+    cache: State<ProjectActivityCache>,
+    project_id: String,
+    force_refresh: Option<bool>,
+) -> Result<ProjectActivity, String> {
+    let project = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        find_project_by_id(&conn, &project_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Project not found: {}", project_id))?
+    };
+
+    let path = Path::new(&project.path);
+    if !force_refresh.unwrap_or(false) {
+        let head = service::head_commit(path);
+        if let Some(mut activity) = cache.get(&project.id, head.as_deref()) {
+            if let Some((local, remote)) = service::branch_counts(path) {
+                activity.local_branch_count = local;
+                activity.remote_branch_count = remote;
+            }
+            return Ok(activity);
+        }
+    }
+
+    let activity = compute_project_activity(&project.id, path)?;
+    cache.insert(activity.clone());
+    Ok(activity)
+}
@@ -0,0 +1,197 @@
+//! Project activity models.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Number of weeks in the commit frequency sparkline.
+pub const SPARKLINE_WEEKS: usize = 26;
+
+/// Number of days covered by the contribution heatmap.
+pub const HEATMAP_DAYS: i64 = 365;
+
+/// Maximum number of authors returned.
+pub const MAX_AUTHORS: usize = 10;
+
+/// Commit count for a single day of the heatmap.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityDay {
+    /// Date in `YYYY-MM-DD` (UTC)
+    pub date: String,
+    pub commits: u32,
+}
+
+/// Activity of a single commit author.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorActivity {
+    pub name: String,
+    pub email: String,
+    pub commit_count: u32,
+    pub last_commit_at: DateTime<Utc>,
+}
+
+/// Commit activity statistics for a project, computed from git history.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectActivity {
+    pub project_id: String,
+    /// HEAD commit the statistics were computed for
+    pub head: Option<String>,
+    /// History was longer than the walk limit: `total_commits` and
+    /// `first_commit_at` only cover the newest commits
+    pub truncated: bool,
+    pub total_commits: u32,
+    pub commits_last_30_days: u32,
+    /// Commits per week, oldest first, for sparkline rendering
+    pub weekly_commits: Vec<u32>,
+    /// Days with at least one commit in the last year, oldest first
+    pub heatmap: Vec<ActivityDay>,
+    /// Authors ordered by most recent activity
+    pub authors: Vec<AuthorActivity>,
+    pub local_branch_count: u32,
+    pub remote_branch_count: u32,
+    pub first_commit_at: Option<DateTime<Utc>>,
+    pub last_commit_at: Option<DateTime<Utc>>,
+    pub computed_at: DateTime<Utc>,
+}
+
+/// A commit reduced to what activity statistics need.
+#[derive(Debug, Clone)]
+pub struct CommitSample {
+    pub time: DateTime<Utc>,
+    pub author_name: String,
+    pub author_email: String,
+}
+
+/// Aggregated commit statistics (everything except branch info).
+#[derive(Debug, Clone, Default)]
+pub struct CommitAggregate {
+    pub total_commits: u32,
+    pub commits_last_30_days: u32,
+    pub weekly_commits: Vec<u32>,
+    pub heatmap: Vec<ActivityDay>,
+    pub authors: Vec<AuthorActivity>,
+    pub first_commit_at: Option<DateTime<Utc>>,
+    pub last_commit_at: Option<DateTime<Utc>>,
+}
+
+impl CommitAggregate {
+    /// Aggregate commits relative to `now`.
+    pub fn from_commits(commits: &[CommitSample], now: DateTime<Utc>) -> Self {
+        let mut weekly_commits = vec![0u32; SPARKLINE_WEEKS];
+        let mut days: BTreeMap<String, u32> = BTreeMap::new();
+        let mut authors: HashMap<String, AuthorActivity> = HashMap::new();
+        let mut aggregate = CommitAggregate::default();
+
+        for commit in commits {
+            aggregate.total_commits += 1;
+            aggregate.first_commit_at = Some(
+                aggregate
+                    .first_commit_at
+                    .map_or(commit.time, |t| t.min(commit.time)),
+            );
+            aggregate.last_commit_at = Some(
+                aggregate
+                    .last_commit_at
+                    .map_or(commit.time, |t| t.max(commit.time)),
+            );
+
+            let age = now - commit.time;
+            if age < Duration::days(30) {
+                aggregate.commits_last_30_days += 1;
+            }
+
+            let week = age.num_weeks();
+            if (0..SPARKLINE_WEEKS as i64).contains(&week) {
+                weekly_commits[SPARKLINE_WEEKS - 1 - week as usize] += 1;
+            }
+
+            if age < Duration::days(HEATMAP_DAYS) {
+                *days
+                    .entry(commit.time.format("%Y-%m-%d").to_string())
+                    .or_insert(0) += 1;
+            }
+
+            let author = authors
+                .entry(commit.author_email.to_lowercase())
+                .or_insert_with(|| AuthorActivity {
+                    name: commit.author_name.clone(),
+                    email: commit.author_email.clone(),
+                    commit_count: 0,
+                    last_commit_at: commit.time,
+                });
+            author.commit_count += 1;
+            if commit.time > author.last_commit_at {
+                author.last_commit_at = commit.time;
+                author.name = commit.author_name.clone();
+            }
+        }
+
+        let mut authors: Vec<AuthorActivity> = authors.into_values().collect();
+        authors.sort_by_key(|a| Reverse(a.last_commit_at));
+        authors.truncate(MAX_AUTHORS);
+
+        aggregate.weekly_commits = weekly_commits;
+        aggregate.heatmap = days
+            .into_iter()
+            .map(|(date, commits)| ActivityDay { date, commits })
+            .collect();
+        aggregate.authors = authors;
+        aggregate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(now: DateTime<Utc>, days_ago: i64, email: &str) -> CommitSample {
+        CommitSample {
+            time: now - Duration::days(days_ago),
+            author_name: email.split('@').next().unwrap().to_string(),
+            author_email: email.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_aggregate_commits() {
+        let now = "2024-06-30T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let commits = vec![
+            sample(now, 0, "ann@example.com"),
+            sample(now, 0, "ann@example.com"),
+            sample(now, 10, "bob@example.com"),
+            sample(now, 400, "ann@example.com"),
+        ];
+
+        let aggregate = CommitAggregate::from_commits(&commits, now);
+
+        assert_eq!(aggregate.total_commits, 4);
+        assert_eq!(aggregate.commits_last_30_days, 3);
+        assert_eq!(aggregate.weekly_commits.len(), SPARKLINE_WEEKS);
+        assert_eq!(aggregate.weekly_commits[SPARKLINE_WEEKS - 1], 2);
+        assert_eq!(aggregate.weekly_commits[SPARKLINE_WEEKS - 2], 1);
+        assert_eq!(
+            aggregate.heatmap,
+            vec![
+                ActivityDay { date: "2024-06-20".to_string(), commits: 1 },
+                ActivityDay { date: "2024-06-30".to_string(), commits: 2 },
+            ]
+        );
+        assert_eq!(aggregate.authors[0].email, "ann@example.com");
+        assert_eq!(aggregate.authors[0].commit_count, 3);
+        assert_eq!(aggregate.first_commit_at, Some(now - Duration::days(400)));
+    }
+
+    #[test]
+    fn test_aggregate_empty() {
+        let aggregate = CommitAggregate::from_commits(&[], Utc::now());
+        assert_eq!(aggregate.total_commits, 0);
+        assert!(aggregate.last_commit_at.is_none());
+        assert!(aggregate.heatmap.is_empty());
+    }
+}
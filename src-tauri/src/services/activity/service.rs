@@ -0,0 +1,102 @@
+//! Compute project activity from git history.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use git2::{BranchType, Repository};
+
+use super::models::{CommitAggregate, CommitSample, ProjectActivity};
+
+/// Upper bound on commits walked, to keep huge histories responsive.
+const MAX_COMMITS: usize = 50_000;
+
+/// Get the current HEAD commit of a repository, if any.
+pub fn head_commit(path: &Path) -> Option<String> {
+    let repo = Repository::open(path).ok()?;
+    let head = repo.head().ok()?;
+    head.target().map(|oid| oid.to_string())
+}
+
+/// Count local and remote branches.
+///
+/// Remote branches change with every fetch without moving HEAD, so callers
+/// recount these even when reusing cached history statistics.
+pub fn branch_counts(path: &Path) -> Option<(u32, u32)> {
+    Repository::open(path).ok().map(|repo| count_branches(&repo))
+}
+
+fn count_branches(repo: &Repository) -> (u32, u32) {
+    let count = |kind: BranchType| -> u32 {
+        repo.branches(Some(kind))
+            .map(|branches| {
+                branches
+                    .filter_map(|b| b.ok())
+                    // Skip symbolic refs like origin/HEAD
+                    .filter(|(b, _)| b.name().ok().flatten().is_none_or(|n| !n.ends_with("/HEAD")))
+                    .count() as u32
+            })
+            .unwrap_or(0)
+    };
+    (count(BranchType::Local), count(BranchType::Remote))
+}
+
+/// Walk the history reachable from HEAD and aggregate it.
+pub fn compute_project_activity(project_id: &str, path: &Path) -> Result<ProjectActivity, String> {
+    let repo = Repository::open(path).map_err(|e| format!("Not a git repository: {}", e))?;
+
+    let head = repo
+        .head()
+        .ok()
+        .and_then(|h| h.target())
+        .map(|oid| oid.to_string());
+
+    let mut commits: Vec<CommitSample> = Vec::new();
+    let mut truncated = false;
+    if let Some(head_oid) = repo.head().ok().and_then(|h| h.target()) {
+        let mut revwalk = repo
+            .revwalk()
+            .map_err(|e| format!("Failed to walk history: {}", e))?;
+        revwalk
+            .set_sorting(git2::Sort::TIME)
+            .map_err(|e| format!("Failed to set revwalk sorting: {}", e))?;
+        revwalk
+            .push(head_oid)
+            .map_err(|e| format!("Failed to walk history: {}", e))?;
+
+        for oid in revwalk.by_ref().take(MAX_COMMITS) {
+            let Ok(oid) = oid else { break };
+            let Ok(commit) = repo.find_commit(oid) else {
+                continue;
+            };
+
+            let author = commit.author();
+            commits.push(CommitSample {
+                time: DateTime::from_timestamp(commit.time().seconds(), 0)
+                    .unwrap_or_else(Utc::now),
+                author_name: author.name().unwrap_or("Unknown").to_string(),
+                author_email: author.email().unwrap_or("unknown@example.com").to_string(),
+            });
+        }
+        truncated = revwalk.next().is_some();
+    }
+
+    let (local_branch_count, remote_branch_count) = count_branches(&repo);
+    let now = Utc::now();
+    let aggregate = CommitAggregate::from_commits(&commits, now);
+
+    Ok(ProjectActivity {
+        project_id: project_id.to_string(),
+        head,
+        truncated,
+        total_commits: aggregate.total_commits,
+        commits_last_30_days: aggregate.commits_last_30_days,
+        weekly_commits: aggregate.weekly_commits,
+        heatmap: aggregate.heatmap,
+        authors: aggregate.authors,
+        local_branch_count,
+        remote_branch_count,
+        first_commit_at: aggregate.first_commit_at,
+        last_commit_at: aggregate.last_commit_at,
+        computed_at: now,
+    })
+}
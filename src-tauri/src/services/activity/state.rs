@@ -0,0 +1,40 @@
+//! Project activity cache.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::models::ProjectActivity;
+
+/// In-memory cache of computed activity, keyed by project ID.
+///
+/// Entries are valid for as long as the repository HEAD doesn't move, so
+/// walking a long history only happens again after new commits or checkouts.
+#[derive(Default)]
+pub struct ProjectActivityCache {
+    entries: Mutex<HashMap<String, ProjectActivity>>,
+}
+
+impl ProjectActivityCache {
+    /// Get a cached entry computed for the given HEAD.
+    pub fn get(&self, project_id: &str, head: Option<&str>) -> Option<ProjectActivity> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .get(project_id)
+            .filter(|activity| activity.head.as_deref() == head)
+            .cloned()
+    }
+
+    /// Store a freshly computed entry.
+    pub fn insert(&self, activity: ProjectActivity) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(activity.project_id.clone(), activity);
+        }
+    }
+
+    /// Drop all cached entries.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
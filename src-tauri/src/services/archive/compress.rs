@@ -0,0 +1,187 @@
+//! Archive creation and extraction.
+//!
+//! Archives always contain the project folder as their single top-level
+//! entry, so extracting into the original parent directory restores it.
+
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter};
+use std::path::Path;
+
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+
+use super::models::ArchiveFormat;
+
+/// zstd compression level (3 is zstd's default speed/ratio tradeoff)
+const ZSTD_LEVEL: i32 = 3;
+
+/// Compress `source_dir` into `archive_path`.
+pub fn create_archive(
+    source_dir: &Path,
+    archive_path: &Path,
+    format: ArchiveFormat,
+) -> Result<(), String> {
+    let root_name = source_dir
+        .file_name()
+        .ok_or_else(|| format!("Invalid project folder: {}", source_dir.display()))?;
+
+    if let Some(parent) = archive_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create archive folder: {}", e))?;
+    }
+    let file = File::create(archive_path).map_err(|e| format!("Failed to create archive: {}", e))?;
+
+    let result = match format {
+        ArchiveFormat::TarZst => write_tar_zst(source_dir, Path::new(root_name), file),
+        ArchiveFormat::Zip => write_zip(source_dir, Path::new(root_name), file),
+    };
+
+    if let Err(e) = result {
+        let _ = fs::remove_file(archive_path);
+        return Err(format!("Failed to write archive: {}", e));
+    }
+    Ok(())
+}
+
+/// Extract `archive_path` into `dest_parent`.
+pub fn extract_archive(
+    archive_path: &Path,
+    dest_parent: &Path,
+    format: ArchiveFormat,
+) -> Result<(), String> {
+    fs::create_dir_all(dest_parent).map_err(|e| format!("Failed to create folder: {}", e))?;
+    let file = File::open(archive_path).map_err(|e| format!("Failed to open archive: {}", e))?;
+
+    match format {
+        ArchiveFormat::TarZst => {
+            let decoder = zstd::Decoder::new(BufReader::new(file)).map_err(|e| e.to_string())?;
+            tar::Archive::new(decoder)
+                .unpack(dest_parent)
+                .map_err(|e| format!("Failed to extract archive: {}", e))
+        }
+        ArchiveFormat::Zip => {
+            let mut archive = zip::ZipArchive::new(BufReader::new(file))
+                .map_err(|e| format!("Failed to read archive: {}", e))?;
+            archive
+                .extract(dest_parent)
+                .map_err(|e| format!("Failed to extract archive: {}", e))
+        }
+    }
+}
+
+fn write_tar_zst(source_dir: &Path, root: &Path, file: File) -> io::Result<()> {
+    let encoder = zstd::Encoder::new(BufWriter::new(file), ZSTD_LEVEL)?;
+    let mut builder = tar::Builder::new(encoder);
+    // Keep symlinks as links instead of archiving their targets
+    builder.follow_symlinks(false);
+    builder.append_dir_all(root, source_dir)?;
+    builder.into_inner()?.finish()?;
+    Ok(())
+}
+
+fn write_zip(source_dir: &Path, root: &Path, file: File) -> io::Result<()> {
+    let mut writer = zip::ZipWriter::new(BufWriter::new(file));
+    let options = SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .large_file(true);
+
+    for entry in WalkDir::new(source_dir).follow_links(false) {
+        let entry = entry.map_err(io::Error::other)?;
+        let relative = entry
+            .path()
+            .strip_prefix(source_dir)
+            .map_err(io::Error::other)?;
+        // Zip entry names always use forward slashes
+        let name = root.join(relative).to_string_lossy().replace('\\', "/");
+        let file_type = entry.file_type();
+
+        if file_type.is_symlink() {
+            // Store the link itself, like tar does
+            let target = fs::read_link(entry.path())?;
+            writer
+                .add_symlink(name, target.to_string_lossy(), options)
+                .map_err(io::Error::other)?;
+        } else if file_type.is_dir() {
+            let options = with_mode(options, &entry.metadata().map_err(io::Error::other)?);
+            writer.add_directory(name, options).map_err(io::Error::other)?;
+        } else if file_type.is_file() {
+            let options = with_mode(options, &entry.metadata().map_err(io::Error::other)?);
+            writer.start_file(name, options).map_err(io::Error::other)?;
+            io::copy(&mut File::open(entry.path())?, &mut writer)?;
+        } else {
+            // Sockets and FIFOs hold no data that survives a restart
+            tracing::debug!("Skipping special file {}", entry.path().display());
+        }
+    }
+
+    writer.finish().map_err(io::Error::other)?;
+    Ok(())
+}
+
+/// Keep the entry's permission bits (exec bits in particular).
+#[cfg(unix)]
+fn with_mode(options: SimpleFileOptions, metadata: &fs::Metadata) -> SimpleFileOptions {
+    use std::os::unix::fs::PermissionsExt;
+    options.unix_permissions(metadata.permissions().mode())
+}
+
+#[cfg(not(unix))]
+fn with_mode(options: SimpleFileOptions, _metadata: &fs::Metadata) -> SimpleFileOptions {
+    options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn roundtrip(format: ArchiveFormat, name: &str) {
+        let root = env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&root);
+
+        let project = root.join("projects").join("demo");
+        fs::create_dir_all(project.join("src")).unwrap();
+        fs::write(project.join("README.md"), "# Demo").unwrap();
+        fs::write(project.join("src").join("main.rs"), "fn main() {}").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::os::unix::fs::symlink("README.md", project.join("readme-link")).unwrap();
+            fs::write(project.join("run.sh"), "#!/bin/sh\n").unwrap();
+            fs::set_permissions(project.join("run.sh"), fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        let archive = root.join("archives").join(format!("demo{}", format.extension()));
+        create_archive(&project, &archive, format).unwrap();
+        assert!(archive.exists());
+
+        fs::remove_dir_all(&project).unwrap();
+        extract_archive(&archive, project.parent().unwrap(), format).unwrap();
+
+        assert_eq!(fs::read_to_string(project.join("README.md")).unwrap(), "# Demo");
+        assert_eq!(
+            fs::read_to_string(project.join("src").join("main.rs")).unwrap(),
+            "fn main() {}"
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let link = fs::read_link(project.join("readme-link")).unwrap();
+            assert_eq!(link, Path::new("README.md"));
+            let mode = fs::metadata(project.join("run.sh")).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o755);
+        }
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_tar_zst_roundtrip() {
+        roundtrip(ArchiveFormat::TarZst, "panager_test_archive_tar_zst");
+    }
+
+    #[test]
+    fn test_zip_roundtrip() {
+        roundtrip(ArchiveFormat::Zip, "panager_test_archive_zip");
+    }
+}
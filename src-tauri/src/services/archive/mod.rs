@@ -0,0 +1,76 @@
+//! Project archiving.
+//!
+//! Archiving compresses a dormant project's folder into the configured
+//! archive location (`archive_location`, `archive_format` settings), deletes
+//! the folder to free disk space, and hides the project from normal listings.
+//! Unarchiving extracts the folder back to its original path.
+
+mod compress;
+pub mod models;
+pub mod service;
+
+pub use models::{ArchiveFormat, ProjectArchive};
+
+use crate::db::Database;
+use crate::events::{AppEvent, EventBus};
+use tauri::{AppHandle, Manager, State};
+
+// =========================================================================
+// Tauri Commands
+// =========================================================================
+
+/// Archive a project's folder and hide it from listings.
+#[tauri::command]
+#[specta::specta]
+pub async fn archive_project(app: AppHandle, project_id: String) -> Result<ProjectArchive, String> {
+    let handle = app.clone();
+    let id = project_id.clone();
+    // Compression can take a while for large repositories
+    let archive = tauri::async_runtime::spawn_blocking(move || {
+        service::archive_project(&handle.state::<Database>(), &id)
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    app.state::<EventBus>().emit(AppEvent::ProjectRemoved {
+        project_id,
+        scope_id: archive.scope_id.clone(),
+    });
+
+    Ok(archive)
+}
+
+/// Restore an archived project to its original location.
+///
+/// Returns the restored project path.
+#[tauri::command]
+#[specta::specta]
+pub async fn unarchive_project(app: AppHandle, project_id: String) -> Result<String, String> {
+    let handle = app.clone();
+    let id = project_id.clone();
+    let (scope_id, path) = tauri::async_runtime::spawn_blocking(move || {
+        let db = handle.state::<Database>();
+        let archive = service::get_project_archive(&db, &id)?
+            .ok_or_else(|| format!("Project is not archived: {}", id))?;
+        service::unarchive_project(&db, &id).map(|path| (archive.scope_id, path))
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    app.state::<EventBus>().emit(AppEvent::ProjectAdded {
+        project_id,
+        scope_id,
+    });
+
+    Ok(path)
+}
+
+/// List archived projects, optionally for a single scope.
+#[tauri::command]
+#[specta::specta]
+pub fn get_archived_projects(
+    db: State<Database>,
+    scope_id: Option<String>,
+) -> Result<Vec<ProjectArchive>, String> {
+    service::get_archives(&db, scope_id.as_deref())
+}
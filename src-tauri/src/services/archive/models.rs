@@ -0,0 +1,76 @@
+//! Project archive models.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Compression format for project archives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum ArchiveFormat {
+    #[serde(rename = "zip")]
+    Zip,
+    #[serde(rename = "tar.zst")]
+    TarZst,
+}
+
+impl ArchiveFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Zip => "zip",
+            ArchiveFormat::TarZst => "tar.zst",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "zip" => Some(ArchiveFormat::Zip),
+            "tar.zst" => Some(ArchiveFormat::TarZst),
+            _ => None,
+        }
+    }
+
+    /// File extension including the leading dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Zip => ".zip",
+            ArchiveFormat::TarZst => ".tar.zst",
+        }
+    }
+}
+
+/// A project whose folder has been compressed into an archive.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectArchive {
+    pub id: String,
+    pub project_id: String,
+    pub project_name: String,
+    pub scope_id: String,
+    /// Where the folder lived before archiving (and is restored to)
+    pub original_path: String,
+    pub archive_path: String,
+    pub format: ArchiveFormat,
+    /// Size of the archive file
+    pub size_bytes: u64,
+    /// Size of the folder before compression
+    pub original_size_bytes: u64,
+    pub archived_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archive_format_roundtrip() {
+        for format in [ArchiveFormat::Zip, ArchiveFormat::TarZst] {
+            assert_eq!(ArchiveFormat::parse(format.as_str()), Some(format));
+            assert!(format.extension().ends_with(format.as_str()));
+        }
+        assert_eq!(ArchiveFormat::parse("rar"), None);
+        assert_eq!(
+            serde_json::to_value(ArchiveFormat::TarZst).unwrap(),
+            serde_json::json!("tar.zst")
+        );
+    }
+}
@@ -0,0 +1,315 @@
+//! Archive and restore projects.
+
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use uuid::Uuid;
+
+use crate::db::repository::find_project_by_id;
+use crate::db::Database;
+use crate::utils::fs::dir_size;
+
+use super::compress::{create_archive, extract_archive};
+use super::models::{ArchiveFormat, ProjectArchive};
+
+/// Setting key for the archive folder (empty = app data `archives` folder).
+pub const ARCHIVE_LOCATION_KEY: &str = "archive_location";
+
+/// Setting key for the archive format ("zip" or "tar.zst").
+pub const ARCHIVE_FORMAT_KEY: &str = "archive_format";
+
+/// Resolve the folder archives are written to.
+pub fn get_archive_dir(db: &Database) -> Result<PathBuf, String> {
+    let configured = db
+        .get_setting(ARCHIVE_LOCATION_KEY)
+        .map_err(|e| e.to_string())?
+        .and_then(|v| v.as_str().map(String::from))
+        .filter(|s| !s.trim().is_empty());
+
+    match configured {
+        Some(dir) => Ok(PathBuf::from(crate::utils::paths::expand_tilde(&dir))),
+        None => crate::utils::paths::app_data_dir()
+            .map(|dir| dir.join("archives"))
+            .ok_or_else(|| "Failed to determine app data directory".to_string()),
+    }
+}
+
+/// Get the configured archive format (defaults to tar.zst).
+pub fn get_archive_format(db: &Database) -> ArchiveFormat {
+    db.get_setting(ARCHIVE_FORMAT_KEY)
+        .ok()
+        .flatten()
+        .and_then(|v| v.as_str().and_then(ArchiveFormat::parse))
+        .unwrap_or(ArchiveFormat::TarZst)
+}
+
+/// Compress a project's folder, remove the folder, and hide the project.
+///
+/// The folder is only deleted after the archive has been written and
+/// recorded successfully.
+pub fn archive_project(db: &Database, project_id: &str) -> Result<ProjectArchive, String> {
+    let project = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        find_project_by_id(&conn, project_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Project not found: {}", project_id))?
+    };
+
+    if get_project_archive(db, project_id)?.is_some() {
+        return Err(format!("Project '{}' is already archived", project.name));
+    }
+
+    let source = Path::new(&project.path);
+    if !source.is_dir() {
+        return Err(format!("Project folder not found: {}", project.path));
+    }
+
+    let format = get_archive_format(db);
+    let archive_dir = get_archive_dir(db)?;
+    if archive_dir.starts_with(source) {
+        return Err("Archive location cannot be inside the project folder".to_string());
+    }
+
+    let id = Uuid::new_v4().to_string();
+    // Include part of the ID so archiving two projects with the same name never collides
+    let archive_path = archive_dir.join(format!(
+        "{}-{}{}",
+        project.name.replace(['/', '\\'], "_"),
+        &id[..8],
+        format.extension()
+    ));
+
+    let original_size_bytes = dir_size(source);
+    create_archive(source, &archive_path, format)?;
+    let size_bytes = std::fs::metadata(&archive_path)
+        .map(|m| m.len())
+        .map_err(|e| format!("Failed to read archive: {}", e))?;
+
+    let archive = ProjectArchive {
+        id,
+        project_id: project.id.clone(),
+        project_name: project.name.clone(),
+        scope_id: project.scope_id.clone(),
+        original_path: project.path.clone(),
+        archive_path: archive_path.to_string_lossy().to_string(),
+        format,
+        size_bytes,
+        original_size_bytes,
+        archived_at: Utc::now(),
+    };
+
+    // Move the folder aside first: a rename either fully succeeds or leaves
+    // everything in place, so the project is never marked archived while its
+    // folder is half deleted.
+    let staging = staging_path(source);
+    if let Err(e) = std::fs::rename(source, &staging) {
+        let _ = std::fs::remove_file(&archive_path);
+        return Err(format!("Failed to remove project folder: {}", e));
+    }
+
+    if let Err(e) = record_archive(db, &archive) {
+        let _ = std::fs::rename(&staging, source);
+        let _ = std::fs::remove_file(&archive_path);
+        return Err(e);
+    }
+
+    // The original path is already free, so a leftover here doesn't block restoring
+    if let Err(e) = std::fs::remove_dir_all(&staging) {
+        tracing::warn!(
+            "Archived project but failed to remove {}: {}",
+            staging.display(),
+            e
+        );
+    }
+
+    tracing::info!(
+        "Archived project {} to {} ({} -> {} bytes)",
+        project.name,
+        archive.archive_path,
+        original_size_bytes,
+        size_bytes
+    );
+
+    Ok(archive)
+}
+
+/// Sibling path a folder is moved to while it is being archived.
+fn staging_path(source: &Path) -> PathBuf {
+    let name = source
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    source.with_file_name(format!(".{}.panager-archiving-{}", name, &Uuid::new_v4().to_string()[..8]))
+}
+
+/// Store the archive row and hide the project in one transaction.
+fn record_archive(db: &Database, archive: &ProjectArchive) -> Result<(), String> {
+    let mut conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    tx.execute(
+        r#"
+        INSERT INTO project_archives
+        (id, project_id, original_path, archive_path, format, size_bytes, original_size_bytes, archived_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+        "#,
+        params![
+            &archive.id,
+            &archive.project_id,
+            &archive.original_path,
+            &archive.archive_path,
+            archive.format.as_str(),
+            archive.size_bytes as i64,
+            archive.original_size_bytes as i64,
+            archive.archived_at.to_rfc3339(),
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+    tx.execute(
+        "UPDATE projects SET is_archived = 1, updated_at = ?1 WHERE id = ?2",
+        params![Utc::now().to_rfc3339(), &archive.project_id],
+    )
+    .map_err(|e| e.to_string())?;
+    tx.commit().map_err(|e| e.to_string())
+}
+
+/// Remove the archive row and show the project again in one transaction.
+fn clear_archive(db: &Database, archive: &ProjectArchive) -> Result<(), String> {
+    let mut conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    tx.execute(
+        "UPDATE projects SET is_archived = 0, updated_at = ?1 WHERE id = ?2",
+        params![Utc::now().to_rfc3339(), &archive.project_id],
+    )
+    .map_err(|e| e.to_string())?;
+    tx.execute(
+        "DELETE FROM project_archives WHERE id = ?1",
+        params![&archive.id],
+    )
+    .map_err(|e| e.to_string())?;
+    tx.commit().map_err(|e| e.to_string())
+}
+
+/// Restore an archived project to its original location.
+///
+/// Returns the restored project path.
+pub fn unarchive_project(db: &Database, project_id: &str) -> Result<String, String> {
+    let archive = get_project_archive(db, project_id)?
+        .ok_or_else(|| format!("Project is not archived: {}", project_id))?;
+
+    let original = Path::new(&archive.original_path);
+    if original.exists() {
+        return Err(format!(
+            "Cannot restore: folder already exists at {}",
+            archive.original_path
+        ));
+    }
+    let parent = original
+        .parent()
+        .ok_or_else(|| format!("Invalid project path: {}", archive.original_path))?;
+
+    extract_archive(Path::new(&archive.archive_path), parent, archive.format)?;
+
+    if let Err(e) = clear_archive(db, &archive) {
+        // The archive is still recorded, so drop the extracted copy
+        let _ = std::fs::remove_dir_all(original);
+        return Err(e);
+    }
+
+    if let Err(e) = std::fs::remove_file(&archive.archive_path) {
+        tracing::warn!("Restored project but failed to remove archive: {}", e);
+    }
+
+    Ok(archive.original_path)
+}
+
+const ARCHIVE_SELECT: &str = r#"
+    SELECT a.id, a.project_id, p.name, p.scope_id, a.original_path, a.archive_path,
+           a.format, a.size_bytes, a.original_size_bytes, a.archived_at
+    FROM project_archives a
+    JOIN projects p ON p.id = a.project_id
+"#;
+
+fn parse_archive_row(row: &rusqlite::Row) -> rusqlite::Result<ProjectArchive> {
+    Ok(ProjectArchive {
+        id: row.get(0)?,
+        project_id: row.get(1)?,
+        project_name: row.get(2)?,
+        scope_id: row.get(3)?,
+        original_path: row.get(4)?,
+        archive_path: row.get(5)?,
+        format: ArchiveFormat::parse(&row.get::<_, String>(6)?).unwrap_or(ArchiveFormat::TarZst),
+        size_bytes: row.get::<_, i64>(7)? as u64,
+        original_size_bytes: row.get::<_, i64>(8)? as u64,
+        archived_at: row
+            .get::<_, String>(9)?
+            .parse()
+            .unwrap_or_else(|_| Utc::now()),
+    })
+}
+
+/// Get the archive record for a project, if archived.
+pub fn get_project_archive(db: &Database, project_id: &str) -> Result<Option<ProjectArchive>, String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    conn.query_row(
+        &format!("{} WHERE a.project_id = ?1", ARCHIVE_SELECT),
+        params![project_id],
+        parse_archive_row,
+    )
+    .optional()
+    .map_err(|e| e.to_string())
+}
+
+/// Archive files of a project, or of every project in a scope.
+///
+/// Archive rows cascade away when their project is deleted, so read the
+/// paths before deleting and pass them to [`remove_archive_files`] after.
+pub fn archive_files(
+    conn: &Connection,
+    project_id: Option<&str>,
+    scope_id: Option<&str>,
+) -> Result<Vec<String>, String> {
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT a.archive_path
+            FROM project_archives a
+            JOIN projects p ON p.id = a.project_id
+            WHERE (?1 IS NULL OR p.id = ?1) AND (?2 IS NULL OR p.scope_id = ?2)
+            "#,
+        )
+        .map_err(|e| e.to_string())?;
+    let paths = stmt
+        .query_map(params![project_id, scope_id], |row| row.get(0))
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(|e| e.to_string())?;
+    Ok(paths)
+}
+
+/// Delete archive files whose projects were deleted.
+pub fn remove_archive_files(paths: &[String]) {
+    for path in paths {
+        if let Err(e) = std::fs::remove_file(path) {
+            tracing::warn!("Failed to remove archive {}: {}", path, e);
+        }
+    }
+}
+
+/// List archived projects, optionally for a single scope.
+pub fn get_archives(db: &Database, scope_id: Option<&str>) -> Result<Vec<ProjectArchive>, String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let sql = format!(
+        "{} WHERE (?1 IS NULL OR p.scope_id = ?1) ORDER BY a.archived_at DESC",
+        ARCHIVE_SELECT
+    );
+    let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
+
+    let archives = stmt
+        .query_map(params![scope_id], parse_archive_row)
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    Ok(archives)
+}
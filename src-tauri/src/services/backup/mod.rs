@@ -0,0 +1,66 @@
+//! Database export, import and scheduled backups.
+//!
+//! Exports are either a versioned JSON snapshot of the organization tables
+//! (scopes, projects, tags, groups, commands, templates, settings) or a full
+//! SQLite copy. Scheduled backups are SQLite copies written to the
+//! `backup_location` folder every `backup_interval_hours`, keeping the newest
+//! `backup_retention_count`.
+
+pub mod models;
+pub mod service;
+mod snapshot;
+mod state;
+
+pub use models::{BackupFormat, BackupInfo, BackupSummary};
+pub use service::start_backup_service;
+pub use state::BackupServiceState;
+
+use crate::db::Database;
+use crate::utils::paths::expand_tilde;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager, State};
+
+// =========================================================================
+// Tauri Commands
+// =========================================================================
+
+/// Export the database to a JSON or SQLite backup file.
+#[tauri::command]
+#[specta::specta]
+pub fn export_database(
+    db: State<Database>,
+    path: String,
+    format: BackupFormat,
+) -> Result<BackupSummary, String> {
+    service::export_database(&db, &PathBuf::from(expand_tilde(&path)), format)
+}
+
+/// Replace all organization data with the contents of a backup file.
+///
+/// The current database is saved to the backup folder before importing.
+#[tauri::command]
+#[specta::specta]
+pub async fn import_database(app: AppHandle, path: String) -> Result<BackupSummary, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let path = PathBuf::from(expand_tilde(&path));
+        let summary = service::import_database(&app.state::<Database>(), &path)?;
+        service::reload_after_import(&app, &path);
+        Ok(summary)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Write an automatic-style backup immediately.
+#[tauri::command]
+#[specta::specta]
+pub fn create_backup_now(db: State<Database>) -> Result<BackupInfo, String> {
+    service::create_backup(&db)
+}
+
+/// List backups in the backup folder, newest first.
+#[tauri::command]
+#[specta::specta]
+pub fn list_backups(db: State<Database>) -> Result<Vec<BackupInfo>, String> {
+    Ok(service::list_backups(&service::get_backup_dir(&db)?))
+}
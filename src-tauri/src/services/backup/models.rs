@@ -0,0 +1,80 @@
+//! Backup models.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Version of the JSON backup layout (bump when the layout itself changes).
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// File format for exported backups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum BackupFormat {
+    /// Versioned, human-readable JSON of the organization tables
+    Json,
+    /// A consistent copy of the whole SQLite database
+    Sqlite,
+}
+
+impl BackupFormat {
+    /// Detect the format from a file's first bytes.
+    pub fn detect(header: &[u8]) -> Self {
+        if header.starts_with(b"SQLite format 3\0") {
+            BackupFormat::Sqlite
+        } else {
+            BackupFormat::Json
+        }
+    }
+}
+
+/// A JSON database backup.
+///
+/// Rows are stored as column -> value maps so backups stay readable and can
+/// be restored into newer schema versions (unknown columns are ignored,
+/// missing columns use their defaults).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseBackup {
+    pub format_version: u32,
+    /// `schema_migrations` version of the exporting database
+    pub schema_version: i32,
+    pub app_version: String,
+    pub created_at: DateTime<Utc>,
+    pub tables: BTreeMap<String, Vec<serde_json::Map<String, serde_json::Value>>>,
+}
+
+/// Row counts per table for an export or import.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupSummary {
+    pub path: String,
+    pub format: Option<BackupFormat>,
+    pub table_counts: BTreeMap<String, u32>,
+}
+
+/// A backup file in the automatic backup folder.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupInfo {
+    pub path: String,
+    pub file_name: String,
+    pub size_bytes: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(
+            BackupFormat::detect(b"SQLite format 3\0\x10\x00"),
+            BackupFormat::Sqlite
+        );
+        assert_eq!(BackupFormat::detect(b"{\"formatVersion\":1"), BackupFormat::Json);
+    }
+}
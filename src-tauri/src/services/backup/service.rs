@@ -0,0 +1,339 @@
+//! Export, import and scheduled backups.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tauri::{AppHandle, Manager};
+use tokio::time::interval;
+
+use crate::db::Database;
+use crate::events::{AppEvent, EventBus};
+use crate::services::activity::ProjectActivityCache;
+use crate::services::health::ProjectHealthCache;
+
+use super::models::{BackupFormat, BackupInfo, BackupSummary, DatabaseBackup};
+use super::snapshot::{
+    copy_sqlite, export_snapshot, import_snapshot, read_sqlite_snapshot, strip_secrets,
+    table_counts,
+};
+use super::BackupServiceState;
+
+/// Setting key for hours between automatic backups (0 = disabled).
+pub const BACKUP_INTERVAL_KEY: &str = "backup_interval_hours";
+
+/// Setting key for how many automatic backups to keep.
+pub const BACKUP_RETENTION_KEY: &str = "backup_retention_count";
+
+/// Setting key for the automatic backup folder (empty = app data `backups` folder).
+pub const BACKUP_LOCATION_KEY: &str = "backup_location";
+
+/// File name prefix of automatic backups.
+const BACKUP_FILE_PREFIX: &str = "panager-";
+
+/// Resolve the folder automatic backups are written to.
+pub fn get_backup_dir(db: &Database) -> Result<PathBuf, String> {
+    let configured = db
+        .get_setting(BACKUP_LOCATION_KEY)
+        .map_err(|e| e.to_string())?
+        .and_then(|v| v.as_str().map(String::from))
+        .filter(|s| !s.trim().is_empty());
+
+    match configured {
+        Some(dir) => Ok(PathBuf::from(crate::utils::paths::expand_tilde(&dir))),
+        None => crate::utils::paths::app_data_dir()
+            .map(|dir| dir.join("backups"))
+            .ok_or_else(|| "Failed to determine app data directory".to_string()),
+    }
+}
+
+fn get_setting_i64(db: &Database, key: &str, default: i64) -> i64 {
+    db.get_setting(key)
+        .ok()
+        .flatten()
+        .and_then(|v| v.as_i64())
+        .unwrap_or(default)
+}
+
+/// Export the database to `path` in the given format.
+pub fn export_database(
+    db: &Database,
+    path: &Path,
+    format: BackupFormat,
+) -> Result<BackupSummary, String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    // Only take the snapshot under the lock; writing the file happens after
+    let backup = match format {
+        BackupFormat::Json => {
+            let backup = {
+                let conn = db.conn.lock().map_err(|e| e.to_string())?;
+                export_snapshot(&conn)?
+            };
+            let json = serde_json::to_string_pretty(&backup).map_err(|e| e.to_string())?;
+            fs::write(path, json).map_err(|e| format!("Failed to write backup: {}", e))?;
+            backup
+        }
+        BackupFormat::Sqlite => {
+            let staging = staging_path(path);
+            {
+                let conn = db.conn.lock().map_err(|e| e.to_string())?;
+                copy_sqlite(&conn, &staging)?;
+            }
+            strip_secrets(&staging)?;
+            let backup = read_sqlite_snapshot(&staging);
+            if let Err(e) = fs::rename(&staging, path) {
+                let _ = fs::remove_file(&staging);
+                return Err(format!("Failed to write backup: {}", e));
+            }
+            backup?
+        }
+    };
+
+    tracing::info!("Exported database to {}", path.display());
+
+    Ok(BackupSummary {
+        path: path.to_string_lossy().to_string(),
+        format: Some(format),
+        table_counts: table_counts(&backup),
+    })
+}
+
+/// Temporary sibling a SQLite backup is written to before being moved into place.
+fn staging_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}.partial", name))
+}
+
+/// Replace the current data with the contents of a backup file.
+///
+/// The format is detected from the file contents. A safety backup of the
+/// current database is written to the backup folder first.
+pub fn import_database(db: &Database, path: &Path) -> Result<BackupSummary, String> {
+    let bytes = fs::read(path).map_err(|e| format!("Failed to read backup: {}", e))?;
+    let format = BackupFormat::detect(&bytes);
+
+    let backup: DatabaseBackup = match format {
+        BackupFormat::Json => serde_json::from_slice(&bytes)
+            .map_err(|e| format!("Invalid backup file: {}", e))?,
+        BackupFormat::Sqlite => read_sqlite_snapshot(path)?,
+    };
+
+    let safety_path = get_backup_dir(db)?.join(format!(
+        "{}pre-import-{}.db",
+        BACKUP_FILE_PREFIX,
+        Utc::now().format("%Y%m%d-%H%M%S")
+    ));
+    export_database(db, &safety_path, BackupFormat::Sqlite)?;
+
+    let mut conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let counts = import_snapshot(&mut conn, &backup)?;
+
+    drop(conn);
+
+    tracing::info!("Imported database from {}", path.display());
+
+    Ok(BackupSummary {
+        path: path.to_string_lossy().to_string(),
+        format: Some(format),
+        table_counts: counts,
+    })
+}
+
+/// Bring in-memory state in line with freshly imported data.
+///
+/// Settings mirrored in memory (telemetry consent, log levels) are re-read,
+/// computed caches are dropped, and windows are told to reload.
+pub fn reload_after_import(app: &AppHandle, path: &Path) {
+    let db = app.state::<Database>();
+    crate::services::telemetry::reload_consent(&db);
+    crate::app::state::restore_log_overrides(&db);
+
+    if let Some(cache) = app.try_state::<ProjectHealthCache>() {
+        cache.clear();
+    }
+    if let Some(cache) = app.try_state::<ProjectActivityCache>() {
+        cache.clear();
+    }
+
+    if let Some(event_bus) = app.try_state::<EventBus>() {
+        event_bus.emit(AppEvent::DatabaseImported {
+            path: path.to_string_lossy().to_string(),
+        });
+    }
+}
+
+/// Write a new automatic backup and prune old ones.
+pub fn create_backup(db: &Database) -> Result<BackupInfo, String> {
+    let dir = get_backup_dir(db)?;
+    let path = dir.join(format!(
+        "{}{}.db",
+        BACKUP_FILE_PREFIX,
+        Utc::now().format("%Y%m%d-%H%M%S")
+    ));
+    export_database(db, &path, BackupFormat::Sqlite)?;
+
+    let retention = get_setting_i64(db, BACKUP_RETENTION_KEY, 7).max(1) as usize;
+    let removed = prune_backups(&dir, retention)?;
+    if removed > 0 {
+        tracing::debug!("Pruned {} old backups", removed);
+    }
+
+    backup_info(&path).ok_or_else(|| "Backup was not written".to_string())
+}
+
+fn backup_info(path: &Path) -> Option<BackupInfo> {
+    let metadata = fs::metadata(path).ok()?;
+    let created_at: DateTime<Utc> = metadata.modified().ok()?.into();
+    Some(BackupInfo {
+        path: path.to_string_lossy().to_string(),
+        file_name: path.file_name()?.to_string_lossy().to_string(),
+        size_bytes: metadata.len(),
+        created_at,
+    })
+}
+
+/// List backups in a folder, newest first.
+pub fn list_backups(dir: &Path) -> Vec<BackupInfo> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut backups: Vec<BackupInfo> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.starts_with(BACKUP_FILE_PREFIX) && n.ends_with(".db"))
+                .unwrap_or(false)
+        })
+        .filter_map(|p| backup_info(&p))
+        .collect();
+
+    // Timestamped names sort chronologically
+    backups.sort_by(|a, b| b.file_name.cmp(&a.file_name));
+    backups
+}
+
+/// Delete all but the newest `keep` scheduled backups.
+///
+/// Pre-import safety backups are never pruned automatically.
+pub fn prune_backups(dir: &Path, keep: usize) -> Result<usize, String> {
+    let scheduled: Vec<BackupInfo> = list_backups(dir)
+        .into_iter()
+        .filter(|b| !b.file_name.contains("pre-import"))
+        .collect();
+
+    let mut removed = 0;
+    for backup in scheduled.iter().skip(keep) {
+        fs::remove_file(&backup.path).map_err(|e| e.to_string())?;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+/// Whether an automatic backup is due.
+fn backup_due(db: &Database) -> Result<bool, String> {
+    let interval_hours = get_setting_i64(db, BACKUP_INTERVAL_KEY, 24);
+    if interval_hours <= 0 {
+        return Ok(false);
+    }
+
+    let latest = list_backups(&get_backup_dir(db)?)
+        .into_iter()
+        .find(|b| !b.file_name.contains("pre-import"));
+
+    Ok(match latest {
+        Some(backup) => Utc::now() - backup.created_at >= chrono::Duration::hours(interval_hours),
+        None => true,
+    })
+}
+
+/// Start the service that writes scheduled backups.
+pub async fn start_backup_service(app_handle: AppHandle) {
+    let state = app_handle.state::<BackupServiceState>();
+
+    // Check if already running
+    {
+        let mut running = state.running.lock().await;
+        if *running {
+            return;
+        }
+        *running = true;
+    }
+
+    let app = app_handle.clone();
+    let running = state.running.clone();
+
+    tokio::spawn(async move {
+        // Check hourly; the interval setting decides whether a backup is due
+        let mut interval = interval(Duration::from_secs(60 * 60));
+
+        loop {
+            interval.tick().await;
+
+            {
+                let is_running = running.lock().await;
+                if !*is_running {
+                    break;
+                }
+            }
+
+            let handle = app.clone();
+            let result = tauri::async_runtime::spawn_blocking(move || {
+                let db = handle.state::<Database>();
+                if backup_due(&db)? {
+                    create_backup(&db).map(Some)
+                } else {
+                    Ok(None)
+                }
+            })
+            .await;
+
+            match result {
+                Ok(Ok(Some(backup))) => tracing::info!("Wrote scheduled backup {}", backup.path),
+                Ok(Ok(None)) => {}
+                Ok(Err(e)) => tracing::error!("Scheduled backup failed: {}", e),
+                Err(e) => tracing::error!("Scheduled backup task failed: {}", e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_prune_keeps_newest() {
+        let dir = env::temp_dir().join("panager_test_backup_prune");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        for name in [
+            "panager-20240101-000000.db",
+            "panager-20240102-000000.db",
+            "panager-20240103-000000.db",
+            "panager-pre-import-20240101-000000.db",
+            "unrelated.txt",
+        ] {
+            fs::write(dir.join(name), b"x").unwrap();
+        }
+
+        assert_eq!(prune_backups(&dir, 2).unwrap(), 1);
+        assert!(!dir.join("panager-20240101-000000.db").exists());
+        assert!(dir.join("panager-20240103-000000.db").exists());
+        assert!(dir.join("panager-pre-import-20240101-000000.db").exists());
+        assert!(dir.join("unrelated.txt").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
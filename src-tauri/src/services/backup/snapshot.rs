@@ -0,0 +1,343 @@
+//! Export and import of database contents.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chrono::Utc;
+use rusqlite::types::{Value, ValueRef};
+use rusqlite::{Connection, OpenFlags};
+
+use super::models::{DatabaseBackup, BACKUP_FORMAT_VERSION};
+
+/// Tables included in backups, parents before children.
+///
+/// Caches and detected data (git status, diagnostics, editors, terminals)
+/// are left out since they are rebuilt automatically.
+pub const BACKUP_TABLES: &[&str] = &[
+    "scopes",
+    "scope_links",
+    "scope_fetch_policies",
+    "scope_policies",
+    "project_groups",
+    "projects",
+    "project_tags",
+    "project_links",
+    "project_commands",
+    "project_templates",
+    "project_archives",
+    "settings",
+];
+
+/// Current `schema_migrations` version of a database.
+pub fn schema_version(conn: &Connection) -> i32 {
+    conn.query_row(
+        "SELECT COALESCE(MAX(version), 0) FROM schema_migrations",
+        [],
+        |row| row.get(0),
+    )
+    .unwrap_or(0)
+}
+
+/// Column names of a table (empty if the table doesn't exist).
+fn table_columns(conn: &Connection, table: &str) -> rusqlite::Result<Vec<String>> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info(\"{}\")", table))?;
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(columns)
+}
+
+fn to_json(value: ValueRef) -> serde_json::Value {
+    match value {
+        ValueRef::Null => serde_json::Value::Null,
+        ValueRef::Integer(i) => i.into(),
+        ValueRef::Real(f) => serde_json::Number::from_f64(f)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        ValueRef::Text(t) => String::from_utf8_lossy(t).into_owned().into(),
+        ValueRef::Blob(b) => b.iter().map(|byte| serde_json::Value::from(*byte)).collect(),
+    }
+}
+
+fn from_json(value: &serde_json::Value) -> Value {
+    match value {
+        serde_json::Value::Null => Value::Null,
+        serde_json::Value::Bool(b) => Value::Integer(*b as i64),
+        serde_json::Value::Number(n) => n
+            .as_i64()
+            .map(Value::Integer)
+            .or_else(|| n.as_f64().map(Value::Real))
+            .unwrap_or(Value::Null),
+        serde_json::Value::String(s) => Value::Text(s.clone()),
+        serde_json::Value::Array(items) => Value::Blob(
+            items
+                .iter()
+                .filter_map(|v| v.as_u64())
+                .map(|b| b as u8)
+                .collect(),
+        ),
+        serde_json::Value::Object(_) => Value::Text(value.to_string()),
+    }
+}
+
+/// Dump the backup tables of a database.
+pub fn export_snapshot(conn: &Connection) -> Result<DatabaseBackup, String> {
+    let mut tables = BTreeMap::new();
+
+    for table in BACKUP_TABLES {
+        let columns = table_columns(conn, table).map_err(|e| e.to_string())?;
+        if columns.is_empty() {
+            continue;
+        }
+
+        let mut stmt = conn
+            .prepare(&format!("SELECT * FROM \"{}\"", table))
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map([], |row| {
+                let mut map = serde_json::Map::new();
+                for (i, column) in columns.iter().enumerate() {
+                    map.insert(column.clone(), to_json(row.get_ref(i)?));
+                }
+                Ok(map)
+            })
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+
+        tables.insert(table.to_string(), rows);
+    }
+
+    Ok(DatabaseBackup {
+        format_version: BACKUP_FORMAT_VERSION,
+        schema_version: schema_version(conn),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: Utc::now(),
+        tables,
+    })
+}
+
+/// Replace the backup tables with the snapshot contents.
+///
+/// Runs in a single transaction, so a failed import leaves the database
+/// untouched. Returns row counts per restored table.
+pub fn import_snapshot(
+    conn: &mut Connection,
+    backup: &DatabaseBackup,
+) -> Result<BTreeMap<String, u32>, String> {
+    if backup.format_version > BACKUP_FORMAT_VERSION {
+        return Err(format!(
+            "Backup format version {} is newer than supported ({})",
+            backup.format_version, BACKUP_FORMAT_VERSION
+        ));
+    }
+    let current_schema = schema_version(conn);
+    if backup.schema_version > current_schema {
+        return Err(format!(
+            "Backup was created by a newer version of Panager (schema {} > {})",
+            backup.schema_version, current_schema
+        ));
+    }
+
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    let mut counts = BTreeMap::new();
+
+    // Clear children first so foreign keys never dangle
+    for table in BACKUP_TABLES.iter().rev() {
+        if !table_columns(&tx, table).map_err(|e| e.to_string())?.is_empty() {
+            tx.execute(&format!("DELETE FROM \"{}\"", table), [])
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    for table in BACKUP_TABLES {
+        let Some(rows) = backup.tables.get(*table) else {
+            continue;
+        };
+        let columns = table_columns(&tx, table).map_err(|e| e.to_string())?;
+        if columns.is_empty() {
+            continue;
+        }
+
+        for row in rows {
+            // Only restore columns that exist in the current schema
+            let present: Vec<&String> = columns.iter().filter(|c| row.contains_key(*c)).collect();
+            if present.is_empty() {
+                continue;
+            }
+            let sql = format!(
+                "INSERT INTO \"{}\" ({}) VALUES ({})",
+                table,
+                present
+                    .iter()
+                    .map(|c| format!("\"{}\"", c))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                (1..=present.len())
+                    .map(|i| format!("?{}", i))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            let values: Vec<Value> = present.iter().map(|c| from_json(&row[*c])).collect();
+            tx.execute(&sql, rusqlite::params_from_iter(values))
+                .map_err(|e| format!("Failed to restore {}: {}", table, e))?;
+        }
+
+        counts.insert(table.to_string(), rows.len() as u32);
+    }
+
+    tx.commit().map_err(|e| e.to_string())?;
+    Ok(counts)
+}
+
+/// Tables never written to backup files, since they hold credentials.
+const SECRET_TABLES: &[&str] = &["provider_accounts"];
+
+/// Write a consistent copy of the whole database to `path`.
+///
+/// This is the only export step that needs the live connection; run
+/// [`strip_secrets`] on the copy afterwards.
+pub fn copy_sqlite(conn: &Connection, path: &Path) -> Result<(), String> {
+    if path.exists() {
+        std::fs::remove_file(path).map_err(|e| e.to_string())?;
+    }
+    conn.execute("VACUUM INTO ?1", [path.to_string_lossy()])
+        .map_err(|e| format!("Failed to write backup: {}", e))?;
+    Ok(())
+}
+
+/// Empty the credential tables of a database copy.
+///
+/// The copy is vacuumed again so the removed rows don't linger in free
+/// pages, and deleted if this fails so tokens never stay on disk.
+pub fn strip_secrets(path: &Path) -> Result<(), String> {
+    let strip = || -> rusqlite::Result<()> {
+        let copy = Connection::open(path)?;
+        for table in SECRET_TABLES {
+            if !table_columns(&copy, table)?.is_empty() {
+                copy.execute(&format!("DELETE FROM \"{}\"", table), [])?;
+            }
+        }
+        copy.execute_batch("VACUUM;")
+    };
+    if let Err(e) = strip() {
+        let _ = std::fs::remove_file(path);
+        return Err(format!("Failed to write backup: {}", e));
+    }
+    Ok(())
+}
+
+/// Read a snapshot from a SQLite backup file.
+pub fn read_sqlite_snapshot(path: &Path) -> Result<DatabaseBackup, String> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|e| format!("Failed to open backup: {}", e))?;
+    export_snapshot(&conn)
+}
+
+/// Row counts for a summary.
+pub fn table_counts(backup: &DatabaseBackup) -> BTreeMap<String, u32> {
+    backup
+        .tables
+        .iter()
+        .map(|(table, rows)| (table.clone(), rows.len() as u32))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::create_test_database;
+
+    fn seed(conn: &Connection) {
+        conn.execute_batch(
+            r#"
+            INSERT INTO scopes (id, name, created_at, updated_at)
+                VALUES ('s1', 'Work', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z');
+            INSERT INTO projects (id, scope_id, name, path, created_at, updated_at)
+                VALUES ('p1', 's1', 'api', '/work/api', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z');
+            INSERT INTO project_tags (id, project_id, tag) VALUES ('t1', 'p1', 'backend');
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let source = create_test_database();
+        let backup = {
+            let conn = source.conn.lock().unwrap();
+            seed(&conn);
+            export_snapshot(&conn).unwrap()
+        };
+        assert_eq!(backup.tables["projects"].len(), 1);
+        assert_eq!(backup.tables["project_tags"][0]["tag"], "backend");
+
+        // Round-trip through JSON like a real export file
+        let json = serde_json::to_string(&backup).unwrap();
+        let backup: DatabaseBackup = serde_json::from_str(&json).unwrap();
+
+        let target = create_test_database();
+        let mut conn = target.conn.lock().unwrap();
+        conn.execute_batch(
+            "INSERT INTO scopes (id, name, created_at, updated_at)
+                VALUES ('old', 'Old', '2023-01-01T00:00:00Z', '2023-01-01T00:00:00Z');",
+        )
+        .unwrap();
+
+        let counts = import_snapshot(&mut conn, &backup).unwrap();
+        assert_eq!(counts["scopes"], 1);
+
+        let names: Vec<String> = conn
+            .prepare("SELECT name FROM scopes")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(names, vec!["Work".to_string()]);
+
+        let tag: String = conn
+            .query_row("SELECT tag FROM project_tags WHERE project_id = 'p1'", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(tag, "backend");
+    }
+
+    #[test]
+    fn test_sqlite_copy_strips_tokens() {
+        let db = create_test_database();
+        let path = std::env::temp_dir().join("panager_test_backup_tokens.db");
+        {
+            let conn = db.conn.lock().unwrap();
+            conn.execute_batch(
+                r#"
+                INSERT INTO provider_accounts (id, provider, host, username, access_token, created_at)
+                    VALUES ('a1', 'github', 'github.com', 'octocat', 'gho_secret', '2024-01-01T00:00:00Z');
+                "#,
+            )
+            .unwrap();
+            copy_sqlite(&conn, &path).unwrap();
+        }
+        strip_secrets(&path).unwrap();
+
+        let copy = Connection::open(&path).unwrap();
+        let count: i64 = copy
+            .query_row("SELECT COUNT(*) FROM provider_accounts", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
+        drop(copy);
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(!bytes.windows(b"gho_secret".len()).any(|w| w == b"gho_secret"));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_import_rejects_newer_schema() {
+        let db = create_test_database();
+        let mut conn = db.conn.lock().unwrap();
+        let mut backup = export_snapshot(&conn).unwrap();
+        backup.schema_version += 1;
+
+        assert!(import_snapshot(&mut conn, &backup).is_err());
+    }
+}
@@ -0,0 +1,17 @@
+//! State management for the backup service
+
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// State to track if the backup service is running
+pub struct BackupServiceState {
+    pub running: Arc<Mutex<bool>>,
+}
+
+impl Default for BackupServiceState {
+    fn default() -> Self {
+        Self {
+            running: Arc::new(Mutex::new(false)),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Bulk operations on multiple projects.
+//!
+//! Batch commands return a job ID immediately and queue the work for a
+//! background worker. Progress is reported per project on the
+//! "batch-progress" event and a [`BatchSummary`] is emitted on
+//! "batch-completed" when the job finishes.
+
+pub mod models;
+pub mod service;
+pub mod state;
+
+pub use models::{
+    BatchItemError, BatchItemStatus, BatchJob, BatchOperation, BatchProgress, BatchSummary,
+};
+pub use service::start_batch_service;
+pub use state::BatchServiceState;
+
+use crate::git::DirtyWorkPolicy;
+use tauri::State;
+use uuid::Uuid;
+
+/// Validate and queue a batch job, returning its ID.
+async fn queue_job(
+    state: &BatchServiceState,
+    operation: BatchOperation,
+    project_ids: Vec<String>,
+) -> Result<String, String> {
+    if project_ids.is_empty() {
+        return Err("No projects selected".to_string());
+    }
+
+    let job = BatchJob {
+        id: Uuid::new_v4().to_string(),
+        operation,
+        project_ids,
+    };
+    let id = job.id.clone();
+    service::enqueue(state, job).await?;
+    Ok(id)
+}
+
+// =========================================================================
+// Tauri Commands
+// =========================================================================
+
+/// Move multiple projects to another scope.
+#[tauri::command]
+#[specta::specta]
+pub async fn move_projects_to_scope(
+    state: State<'_, BatchServiceState>,
+    project_ids: Vec<String>,
+    new_scope_id: String,
+) -> Result<String, String> {
+    queue_job(&state, BatchOperation::MoveToScope { new_scope_id }, project_ids).await
+}
+
+/// Add a tag to multiple projects.
+#[tauri::command]
+#[specta::specta]
+pub async fn add_tag_to_projects(
+    state: State<'_, BatchServiceState>,
+    project_ids: Vec<String>,
+    tag: String,
+) -> Result<String, String> {
+    if tag.trim().is_empty() {
+        return Err("Tag cannot be empty".to_string());
+    }
+    queue_job(&state, BatchOperation::AddTag { tag }, project_ids).await
+}
+
+/// Delete multiple projects, optionally removing their folders.
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_projects(
+    state: State<'_, BatchServiceState>,
+    project_ids: Vec<String>,
+    delete_folders: bool,
+    dirty_policy: Option<DirtyWorkPolicy>,
+) -> Result<String, String> {
+    let operation = BatchOperation::Delete {
+        delete_folders,
+        dirty_policy: dirty_policy.unwrap_or_default(),
+    };
+    queue_job(&state, operation, project_ids).await
+}
+
+/// Run `git fetch` in multiple projects.
+#[tauri::command]
+#[specta::specta]
+pub async fn git_fetch_projects(
+    state: State<'_, BatchServiceState>,
+    project_ids: Vec<String>,
+) -> Result<String, String> {
+    queue_job(&state, BatchOperation::GitFetch, project_ids).await
+}
@@ -0,0 +1,146 @@
+//! Batch operation models.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::git::DirtyWorkPolicy;
+
+/// An operation applied to every project in a batch.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum BatchOperation {
+    /// Move projects to another scope (database only, folders stay in place)
+    #[serde(rename_all = "camelCase")]
+    MoveToScope { new_scope_id: String },
+    /// Add a tag to projects
+    AddTag { tag: String },
+    /// Remove projects, optionally deleting their folders
+    #[serde(rename_all = "camelCase")]
+    Delete {
+        delete_folders: bool,
+        /// How to handle uncommitted or unpushed work when deleting folders
+        #[serde(default)]
+        dirty_policy: DirtyWorkPolicy,
+    },
+    /// Run `git fetch` in each project
+    GitFetch,
+}
+
+impl BatchOperation {
+    /// Short name used in logs.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BatchOperation::MoveToScope { .. } => "move_to_scope",
+            BatchOperation::AddTag { .. } => "add_tag",
+            BatchOperation::Delete { .. } => "delete",
+            BatchOperation::GitFetch => "git_fetch",
+        }
+    }
+}
+
+/// A queued batch job.
+#[derive(Debug, Clone)]
+pub struct BatchJob {
+    pub id: String,
+    pub operation: BatchOperation,
+    pub project_ids: Vec<String>,
+}
+
+/// Status of a single item within a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum BatchItemStatus {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+/// Emitted on "batch-progress" for every item state change.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchProgress {
+    pub job_id: String,
+    pub operation: BatchOperation,
+    pub project_id: String,
+    /// Zero-based position of the item in the batch
+    pub index: u32,
+    pub total: u32,
+    pub status: BatchItemStatus,
+    pub error: Option<String>,
+}
+
+/// Emitted on "batch-completed" once every item has been processed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchSummary {
+    pub job_id: String,
+    pub total: u32,
+    pub succeeded: u32,
+    pub failed: u32,
+    /// Per-project error messages for failed items
+    pub errors: Vec<BatchItemError>,
+}
+
+/// Error for a single failed batch item.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchItemError {
+    pub project_id: String,
+    pub error: String,
+}
+
+impl BatchSummary {
+    pub fn new(job_id: &str, total: usize) -> Self {
+        Self {
+            job_id: job_id.to_string(),
+            total: total as u32,
+            ..Default::default()
+        }
+    }
+
+    /// Record the outcome of one item.
+    pub fn record(&mut self, project_id: &str, result: &Result<(), String>) {
+        match result {
+            Ok(()) => self.succeeded += 1,
+            Err(e) => {
+                self.failed += 1;
+                self.errors.push(BatchItemError {
+                    project_id: project_id.to_string(),
+                    error: e.clone(),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_record() {
+        let mut summary = BatchSummary::new("job-1", 3);
+        summary.record("a", &Ok(()));
+        summary.record("b", &Err("not a git repository".to_string()));
+        summary.record("c", &Ok(()));
+
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.succeeded, 2);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.errors[0].project_id, "b");
+    }
+
+    #[test]
+    fn test_operation_serialization() {
+        let op = BatchOperation::MoveToScope {
+            new_scope_id: "s2".to_string(),
+        };
+        let json = serde_json::to_value(&op).unwrap();
+        assert_eq!(json["type"], "moveToScope");
+        assert_eq!(json["newScopeId"], "s2");
+        assert_eq!(
+            serde_json::to_value(BatchOperation::GitFetch).unwrap()["type"],
+            "gitFetch"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Background batch queue worker.
+//!
+//! Jobs are processed one at a time in the order they were queued so that,
+//! for example, a bulk move and a bulk delete on the same projects never race.
+
+use std::path::Path;
+
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::mpsc;
+
+use crate::db::Database;
+use crate::events::{AppEvent, EventBus};
+
+use super::models::{BatchItemStatus, BatchJob, BatchOperation, BatchProgress, BatchSummary};
+use super::BatchServiceState;
+
+/// Event channel for per-item progress
+pub const BATCH_PROGRESS_EVENT: &str = "batch-progress";
+
+/// Event channel for job completion
+pub const BATCH_COMPLETED_EVENT: &str = "batch-completed";
+
+/// Start the batch queue worker.
+pub async fn start_batch_service(app_handle: AppHandle) {
+    let state = app_handle.state::<BatchServiceState>();
+
+    // Check if already running
+    {
+        let mut running = state.running.lock().await;
+        if *running {
+            return;
+        }
+        *running = true;
+    }
+
+    let (tx, mut rx) = mpsc::unbounded_channel::<BatchJob>();
+    *state.sender.lock().await = Some(tx);
+
+    let app = app_handle.clone();
+    tokio::spawn(async move {
+        while let Some(job) = rx.recv().await {
+            let app = app.clone();
+            // Items use blocking git/fs calls, keep them off the async workers
+            let result = tokio::task::spawn_blocking(move || run_job(&app, job)).await;
+            if let Err(e) = result {
+                tracing::error!("Batch job panicked: {}", e);
+            }
+        }
+    });
+}
+
+/// Queue a job for the worker.
+pub async fn enqueue(state: &BatchServiceState, job: BatchJob) -> Result<(), String> {
+    let sender = state.sender.lock().await;
+    let sender = sender
+        .as_ref()
+        .ok_or_else(|| "Batch service is not running".to_string())?;
+    sender.send(job).map_err(|e| e.to_string())
+}
+
+/// Process every item of a job, emitting progress as it goes.
+fn run_job(app: &AppHandle, job: BatchJob) {
+    let total = job.project_ids.len();
+    let mut summary = BatchSummary::new(&job.id, total);
+
+    tracing::info!(
+        "Running batch job {} ({}, {} projects)",
+        job.id,
+        job.operation.name(),
+        total
+    );
+
+    for (index, project_id) in job.project_ids.iter().enumerate() {
+        let progress = |status: BatchItemStatus, error: Option<String>| BatchProgress {
+            job_id: job.id.clone(),
+            operation: job.operation.clone(),
+            project_id: project_id.clone(),
+            index: index as u32,
+            total: total as u32,
+            status,
+            error,
+        };
+
+        let _ = app.emit(BATCH_PROGRESS_EVENT, progress(BatchItemStatus::Running, None));
+
+        let result = run_item(app, &job.operation, project_id);
+        let status = if result.is_ok() {
+            BatchItemStatus::Succeeded
+        } else {
+            BatchItemStatus::Failed
+        };
+        let _ = app.emit(
+            BATCH_PROGRESS_EVENT,
+            progress(status, result.as_ref().err().cloned()),
+        );
+
+        summary.record(project_id, &result);
+    }
+
+    tracing::info!(
+        "Batch job {} finished: {} succeeded, {} failed",
+        job.id,
+        summary.succeeded,
+        summary.failed
+    );
+    let _ = app.emit(BATCH_COMPLETED_EVENT, summary);
+}
+
+/// Apply an operation to a single project.
+fn run_item(app: &AppHandle, operation: &BatchOperation, project_id: &str) -> Result<(), String> {
+    let db = app.state::<Database>();
+    let (scope_id, path) = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        conn.query_row(
+            "SELECT scope_id, path FROM projects WHERE id = ?1",
+            [project_id],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+        )
+        .map_err(|e| format!("Project not found: {}", e))?
+    };
+
+    match operation {
+        BatchOperation::MoveToScope { new_scope_id } => {
+            if &scope_id == new_scope_id {
+                return Ok(());
+            }
+            crate::commands::projects::move_project_to_scope(
+                db,
+                project_id.to_string(),
+                new_scope_id.clone(),
+            )?;
+            emit_app_event(
+                app,
+                AppEvent::ProjectMoved {
+                    project_id: project_id.to_string(),
+                    old_scope_id: scope_id,
+                    new_scope_id: new_scope_id.clone(),
+                },
+            );
+        }
+        BatchOperation::AddTag { tag } => {
+            crate::commands::projects::add_project_tag(db, project_id.to_string(), tag.clone())?;
+        }
+        BatchOperation::Delete {
+            delete_folders,
+            dirty_policy,
+        } => {
+            if *delete_folders {
+                crate::commands::projects::delete_project_with_folder(
+                    db,
+                    project_id.to_string(),
+                    Some(*dirty_policy),
+                )?;
+            } else {
+                crate::commands::projects::delete_project(db, project_id.to_string())?;
+            }
+            emit_app_event(
+                app,
+                AppEvent::ProjectRemoved {
+                    project_id: project_id.to_string(),
+                    scope_id,
+                },
+            );
+        }
+        BatchOperation::GitFetch => {
+            if !Path::new(&path).join(".git").exists() {
+                return Err("Not a git repository".to_string());
+            }
+            crate::commands::git::git_fetch(path)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn emit_app_event(app: &AppHandle, event: AppEvent) {
+    if let Some(event_bus) = app.try_state::<EventBus>() {
+        event_bus.emit(event);
+    }
+}
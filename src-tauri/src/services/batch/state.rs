@@ -0,0 +1,15 @@
+//! Batch queue state.
+
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+
+use super::models::BatchJob;
+
+/// State for the batch queue worker.
+#[derive(Default)]
+pub struct BatchServiceState {
+    /// Whether the worker is running
+    pub running: Arc<Mutex<bool>>,
+    /// Sender for queueing jobs (set once the worker starts)
+    pub sender: Arc<Mutex<Option<mpsc::UnboundedSender<BatchJob>>>>,
+}
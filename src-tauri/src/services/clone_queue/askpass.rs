@@ -0,0 +1,191 @@
+//! Credential prompts for queued clones.
+//!
+//! Git (`GIT_ASKPASS`) and ssh (`SSH_ASKPASS`) run an external program to ask
+//! for usernames, passwords and key passphrases. Queued clones point both at
+//! the Panager executable itself with [`ASKPASS_PORT_ENV`] set; in that mode
+//! the process only forwards the prompt to a per-clone loopback listener and
+//! prints the answer (see [`run_client`]).
+//!
+//! The listener surfaces each prompt to the UI as a [`CredentialRequest`] and
+//! waits for `answer_clone_credential`.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+use super::models::{CloneJobStatus, CredentialRequest};
+use super::CloneQueueState;
+
+/// Port of the listener, set for askpass invocations.
+pub const ASKPASS_PORT_ENV: &str = "PANAGER_ASKPASS_PORT";
+
+/// Per-clone secret the client must present.
+pub const ASKPASS_TOKEN_ENV: &str = "PANAGER_ASKPASS_TOKEN";
+
+/// Event channel for credential prompts.
+pub const CREDENTIAL_REQUEST_EVENT: &str = "clone-credential-request";
+
+/// How long a prompt waits for the user before failing the clone.
+const PROMPT_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Whether this process was started by git/ssh as an askpass helper.
+pub fn is_askpass_invocation() -> bool {
+    std::env::var_os(ASKPASS_PORT_ENV).is_some()
+}
+
+/// Forward the prompt to the running app and print the answer.
+///
+/// Returns the process exit code; a non-zero code tells git the prompt was
+/// cancelled.
+pub fn run_client() -> i32 {
+    let port = std::env::var(ASKPASS_PORT_ENV).unwrap_or_default();
+    let token = std::env::var(ASKPASS_TOKEN_ENV).unwrap_or_default();
+    let prompt = std::env::args().skip(1).collect::<Vec<_>>().join(" ");
+
+    let Ok(mut stream) = TcpStream::connect(format!("127.0.0.1:{}", port)) else {
+        return 1;
+    };
+    if writeln!(stream, "{}\n{}", token, prompt.replace('\n', " ")).is_err() {
+        return 1;
+    }
+
+    let mut answer = String::new();
+    if stream.read_to_string(&mut answer).is_err() || answer.is_empty() {
+        return 1;
+    }
+
+    println!("{}", answer);
+    0
+}
+
+/// A loopback listener answering askpass requests for one clone.
+pub struct AskpassServer {
+    pub port: u16,
+    pub token: String,
+    stop: Arc<AtomicBool>,
+}
+
+impl AskpassServer {
+    /// Start listening for prompts of the given job.
+    pub fn start(app: AppHandle, job_id: String) -> std::io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        listener.set_nonblocking(true)?;
+        let port = listener.local_addr()?.port();
+        let token = Uuid::new_v4().to_string();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let server_token = token.clone();
+        let server_stop = stop.clone();
+        thread::spawn(move || {
+            while !server_stop.load(Ordering::Relaxed) {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        if let Err(e) = handle_prompt(&app, &job_id, &server_token, stream) {
+                            tracing::warn!("Credential prompt for clone {} failed: {}", job_id, e);
+                        }
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                        thread::sleep(Duration::from_millis(100));
+                    }
+                    Err(e) => {
+                        tracing::warn!("Askpass listener error: {}", e);
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Self { port, token, stop })
+    }
+
+    /// Environment variables that route git/ssh prompts to this listener.
+    pub fn env(&self) -> Vec<(&'static str, String)> {
+        let exe = std::env::current_exe()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        vec![
+            ("GIT_ASKPASS", exe.clone()),
+            ("SSH_ASKPASS", exe),
+            ("SSH_ASKPASS_REQUIRE", "force".to_string()),
+            ("GIT_TERMINAL_PROMPT", "0".to_string()),
+            (ASKPASS_PORT_ENV, self.port.to_string()),
+            (ASKPASS_TOKEN_ENV, self.token.clone()),
+        ]
+    }
+}
+
+impl Drop for AskpassServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Whether an answer to this prompt should be masked.
+pub fn is_secret_prompt(prompt: &str) -> bool {
+    let prompt = prompt.to_lowercase();
+    ["password", "passphrase", "token", "pin"]
+        .iter()
+        .any(|word| prompt.contains(word))
+}
+
+fn handle_prompt(
+    app: &AppHandle,
+    job_id: &str,
+    token: &str,
+    mut stream: TcpStream,
+) -> Result<(), String> {
+    stream.set_nonblocking(false).map_err(|e| e.to_string())?;
+    let mut reader = BufReader::new(stream.try_clone().map_err(|e| e.to_string())?);
+
+    let mut client_token = String::new();
+    let mut prompt = String::new();
+    reader.read_line(&mut client_token).map_err(|e| e.to_string())?;
+    reader.read_line(&mut prompt).map_err(|e| e.to_string())?;
+    if client_token.trim() != token {
+        return Err("Rejected askpass connection with an invalid token".to_string());
+    }
+
+    let state = app.state::<CloneQueueState>();
+    let request = CredentialRequest {
+        job_id: job_id.to_string(),
+        prompt_id: Uuid::new_v4().to_string(),
+        prompt: prompt.trim().to_string(),
+        is_secret: is_secret_prompt(&prompt),
+    };
+
+    let (tx, rx) = mpsc::channel::<Option<String>>();
+    state.add_prompt(&request, tx);
+    state.set_status(app, job_id, CloneJobStatus::AwaitingCredentials);
+    let _ = app.emit(CREDENTIAL_REQUEST_EVENT, &request);
+
+    // Disconnected (job cancelled) and timeouts both answer with nothing
+    let answer = rx.recv_timeout(PROMPT_TIMEOUT).ok().flatten();
+    state.remove_prompt(&request.prompt_id);
+    state.set_status(app, job_id, CloneJobStatus::Running);
+
+    if let Some(answer) = answer {
+        stream
+            .write_all(answer.as_bytes())
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_secret_prompt() {
+        assert!(is_secret_prompt("Password for 'https://user@github.com': "));
+        assert!(is_secret_prompt("Enter passphrase for key '/home/u/.ssh/id_ed25519': "));
+        assert!(!is_secret_prompt("Username for 'https://github.com': "));
+    }
+}
@@ -0,0 +1,68 @@
+//! Queued repository cloning.
+//!
+//! Clones are queued and run up to [`state::MAX_CONCURRENT_CLONES`] at a
+//! time. Each job reports its stage and overall percentage (parsed from git's
+//! sideband progress) on the `clone-job-progress` event, can be cancelled,
+//! and surfaces git/ssh credential prompts to the UI through an askpass
+//! bridge (see [`askpass`]). Finished clones are registered as projects in
+//! their scope with the scope's git identity applied.
+
+pub mod askpass;
+pub mod models;
+mod progress;
+pub mod service;
+pub mod state;
+
+pub use models::{CloneJob, CloneJobStatus, CloneRequest, CloneStage, CredentialRequest};
+pub use state::CloneQueueState;
+
+use tauri::{AppHandle, State};
+
+// =========================================================================
+// Tauri Commands
+// =========================================================================
+
+/// Queue a clone and return immediately.
+#[tauri::command]
+#[specta::specta]
+pub fn queue_clone(app: AppHandle, request: CloneRequest) -> Result<CloneJob, String> {
+    service::enqueue(&app, request).map(|(job, _)| job)
+}
+
+/// Get all queued, running and finished clones.
+#[tauri::command]
+#[specta::specta]
+pub fn get_clone_jobs(state: State<CloneQueueState>) -> Result<Vec<CloneJob>, String> {
+    Ok(state.list())
+}
+
+/// Cancel a queued or running clone.
+///
+/// Returns false if the job already finished.
+#[tauri::command]
+#[specta::specta]
+pub fn cancel_clone(
+    app: AppHandle,
+    state: State<CloneQueueState>,
+    job_id: String,
+) -> Result<bool, String> {
+    Ok(state.cancel(&app, &job_id))
+}
+
+/// Answer a credential prompt (None dismisses it and fails the clone).
+#[tauri::command]
+#[specta::specta]
+pub fn answer_clone_credential(
+    state: State<CloneQueueState>,
+    prompt_id: String,
+    answer: Option<String>,
+) -> Result<(), String> {
+    state.answer_prompt(&prompt_id, answer)
+}
+
+/// Remove finished jobs from the list.
+#[tauri::command]
+#[specta::specta]
+pub fn clear_finished_clones(state: State<CloneQueueState>) -> Result<u32, String> {
+    Ok(state.clear_finished() as u32)
+}
@@ -0,0 +1,106 @@
+//! Clone queue models.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::db::models::CloneOptions;
+
+/// Lifecycle of a queued clone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum CloneJobStatus {
+    Queued,
+    Running,
+    /// Waiting for the user to answer a credential prompt
+    AwaitingCredentials,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl CloneJobStatus {
+    /// Whether the job has finished (successfully or not).
+    pub fn is_finished(self) -> bool {
+        matches!(
+            self,
+            CloneJobStatus::Completed | CloneJobStatus::Failed | CloneJobStatus::Cancelled
+        )
+    }
+}
+
+/// Phase of a running clone, as reported by git.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum CloneStage {
+    /// Remote is counting/compressing objects
+    Preparing,
+    Receiving,
+    Resolving,
+    CheckingOut,
+    /// Registering the project and applying the scope identity
+    Registering,
+}
+
+impl CloneStage {
+    /// Human-readable stage name.
+    pub fn label(self) -> &'static str {
+        match self {
+            CloneStage::Preparing => "Preparing",
+            CloneStage::Receiving => "Receiving objects",
+            CloneStage::Resolving => "Resolving deltas",
+            CloneStage::CheckingOut => "Checking out files",
+            CloneStage::Registering => "Registering project",
+        }
+    }
+}
+
+/// Request to queue a clone.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CloneRequest {
+    pub scope_id: String,
+    pub url: String,
+    /// Folder name inside the scope's default folder
+    pub folder_name: String,
+    pub options: CloneOptions,
+}
+
+/// A queued, running or finished clone.
+///
+/// Emitted on every change via the `clone-job-progress` event.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CloneJob {
+    pub id: String,
+    pub scope_id: String,
+    pub url: String,
+    pub folder_name: String,
+    pub target_path: String,
+    pub status: CloneJobStatus,
+    pub stage: Option<CloneStage>,
+    /// Overall progress across all stages (0-100)
+    pub percent: u8,
+    /// Latest git progress line
+    pub message: Option<String>,
+    pub error: Option<String>,
+    /// Set once the clone is registered as a project
+    pub project_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// A credential prompt from git or ssh, surfaced to the UI.
+///
+/// Emitted via the `clone-credential-request` event and answered with
+/// `answer_clone_credential`.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialRequest {
+    pub job_id: String,
+    pub prompt_id: String,
+    /// Prompt text from git/ssh (e.g. "Password for 'https://github.com':")
+    pub prompt: String,
+    /// Whether the answer should be masked (password, passphrase, token)
+    pub is_secret: bool,
+}
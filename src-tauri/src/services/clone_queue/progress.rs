@@ -0,0 +1,108 @@
+//! Parsing of `git clone --progress` output.
+//!
+//! Git writes progress to stderr, rewriting the current line with `\r`, e.g.
+//! `Receiving objects:  45% (450/1000), 1.2 MiB | 2.0 MiB/s`. Each stage is
+//! mapped to a slice of the overall 0-100 range.
+
+use crate::utils::regex::GIT_PROGRESS_PERCENT_REGEX;
+
+use super::models::CloneStage;
+
+/// Split a chunk of stderr into complete lines on `\r` or `\n`.
+///
+/// Returns the lines and leaves any incomplete trailing text in `pending`.
+pub fn split_progress_lines(pending: &mut String, chunk: &str) -> Vec<String> {
+    pending.push_str(chunk);
+
+    let mut lines = Vec::new();
+    while let Some(pos) = pending.find(['\r', '\n']) {
+        let line = pending[..pos].trim().to_string();
+        pending.drain(..=pos);
+        if !line.is_empty() {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+/// Parse the stage and stage percentage from a progress line.
+pub fn parse_progress_line(line: &str) -> Option<(CloneStage, u8)> {
+    let stage = if line.contains("Counting objects")
+        || line.contains("Compressing objects")
+        || line.contains("Enumerating objects")
+    {
+        CloneStage::Preparing
+    } else if line.contains("Receiving objects") {
+        CloneStage::Receiving
+    } else if line.contains("Resolving deltas") {
+        CloneStage::Resolving
+    } else if line.contains("Updating files") || line.contains("Checking out files") {
+        CloneStage::CheckingOut
+    } else {
+        return None;
+    };
+
+    let percent = GIT_PROGRESS_PERCENT_REGEX
+        .captures(line)
+        .and_then(|caps| caps.get(1))
+        .and_then(|m| m.as_str().parse::<u8>().ok())
+        .unwrap_or(0)
+        .min(100);
+
+    Some((stage, percent))
+}
+
+/// Map a stage percentage to overall clone progress.
+pub fn overall_percent(stage: CloneStage, percent: u8) -> u8 {
+    let (start, span) = match stage {
+        CloneStage::Preparing => (0.0, 5.0),
+        CloneStage::Receiving => (5.0, 75.0),
+        CloneStage::Resolving => (80.0, 15.0),
+        CloneStage::CheckingOut => (95.0, 4.0),
+        CloneStage::Registering => (99.0, 1.0),
+    };
+    (start + span * f64::from(percent) / 100.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_progress_lines() {
+        let mut pending = String::new();
+        let lines = split_progress_lines(&mut pending, "Receiving objects:  10% (1/10)\rReceiving obj");
+        assert_eq!(lines, vec!["Receiving objects:  10% (1/10)"]);
+        assert_eq!(pending, "Receiving obj");
+
+        let lines = split_progress_lines(&mut pending, "ects:  20% (2/10)\r\n");
+        assert_eq!(lines, vec!["Receiving objects:  20% (2/10)"]);
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn test_parse_progress_line() {
+        assert_eq!(
+            parse_progress_line("remote: Compressing objects:  50% (5/10)"),
+            Some((CloneStage::Preparing, 50))
+        );
+        assert_eq!(
+            parse_progress_line("Receiving objects:  45% (450/1000), 1.2 MiB | 2.0 MiB/s"),
+            Some((CloneStage::Receiving, 45))
+        );
+        assert_eq!(
+            parse_progress_line("Resolving deltas: 100% (20/20), done."),
+            Some((CloneStage::Resolving, 100))
+        );
+        assert_eq!(parse_progress_line("Cloning into 'repo'..."), None);
+    }
+
+    #[test]
+    fn test_overall_percent_is_monotonic_across_stages() {
+        assert_eq!(overall_percent(CloneStage::Preparing, 0), 0);
+        assert_eq!(overall_percent(CloneStage::Receiving, 100), 80);
+        assert_eq!(overall_percent(CloneStage::Resolving, 0), 80);
+        assert!(overall_percent(CloneStage::CheckingOut, 100) < 100);
+        assert_eq!(overall_percent(CloneStage::Registering, 100), 100);
+    }
+}
@@ -0,0 +1,361 @@
+//! Queued clone runner.
+
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use chrono::Utc;
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+use crate::db::models::{CloneProgress, CloneResult};
+use crate::db::repository::get_scope_git_config;
+use crate::db::Database;
+use crate::events::{AppEvent, EventBus};
+use crate::git::url::{build_ssh_url_with_alias, parse_git_url};
+
+use super::askpass::AskpassServer;
+use super::models::{CloneJob, CloneJobStatus, CloneRequest, CloneStage};
+use super::progress::{overall_percent, parse_progress_line, split_progress_lines};
+use super::state::JobControl;
+use super::CloneQueueState;
+
+/// Legacy per-line progress channel used by `clone_repository`.
+pub const CLONE_PROGRESS_EVENT: &str = "clone-progress";
+
+/// Validate a request and queue it.
+///
+/// Returns the queued job and a handle resolving to the final result.
+pub fn enqueue(
+    app: &AppHandle,
+    request: CloneRequest,
+) -> Result<(CloneJob, JoinHandle<CloneResult>), String> {
+    let default_folder: String = {
+        let db = app.state::<Database>();
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        let folder: Option<String> = conn
+            .query_row(
+                "SELECT default_folder FROM scopes WHERE id = ?1",
+                [&request.scope_id],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Scope not found: {}", e))?;
+        folder.ok_or("Scope has no default folder configured")?
+    };
+
+    let target_path = Path::new(&default_folder).join(&request.folder_name);
+    let target_path_str = target_path.to_string_lossy().to_string();
+
+    let state = app.state::<CloneQueueState>();
+    let already_queued = state
+        .list()
+        .iter()
+        .any(|job| job.target_path == target_path_str && !job.status.is_finished());
+    if target_path.exists() || already_queued {
+        return Err(format!("Folder already exists: {}", target_path_str));
+    }
+
+    let job = CloneJob {
+        id: Uuid::new_v4().to_string(),
+        scope_id: request.scope_id.clone(),
+        url: request.url.clone(),
+        folder_name: request.folder_name.clone(),
+        target_path: target_path_str,
+        status: CloneJobStatus::Queued,
+        stage: None,
+        percent: 0,
+        message: None,
+        error: None,
+        project_id: None,
+        created_at: Utc::now(),
+        finished_at: None,
+    };
+    let control = state.insert(job.clone());
+    let _ = app.emit(super::state::CLONE_JOB_EVENT, &job);
+
+    let slots = state.slots.clone();
+    let handle = app.clone();
+    let job_id = job.id.clone();
+    let task = tauri::async_runtime::spawn(async move {
+        // Wait for a free slot; the permit is released when the clone ends
+        let _permit = slots.acquire_owned().await;
+
+        let app = handle.clone();
+        tauri::async_runtime::spawn_blocking(move || run_job(&app, &job_id, request, control))
+            .await
+            .unwrap_or_else(|e| failure(e.to_string()))
+    });
+
+    Ok((job, task))
+}
+
+fn failure(error: String) -> CloneResult {
+    CloneResult {
+        success: false,
+        project_id: None,
+        project_path: None,
+        error: Some(error),
+    }
+}
+
+fn emit_line(app: &AppHandle, line: &str, is_error: bool, status: Option<&str>) {
+    let _ = app.emit(
+        CLONE_PROGRESS_EVENT,
+        CloneProgress {
+            line: line.to_string(),
+            is_error,
+            status: status.map(String::from),
+        },
+    );
+}
+
+/// Run one clone to completion.
+fn run_job(
+    app: &AppHandle,
+    job_id: &str,
+    request: CloneRequest,
+    control: Arc<JobControl>,
+) -> CloneResult {
+    let state = app.state::<CloneQueueState>();
+    let Some(job) = state.get(job_id) else {
+        return failure("Clone job not found".to_string());
+    };
+
+    // Cancelled while still queued: nothing was written to disk
+    let mut created = false;
+    let result = if control.cancelled.load(Ordering::Relaxed) {
+        Err("Clone cancelled".to_string())
+    } else {
+        state.set_status(app, job_id, CloneJobStatus::Running);
+        claim_target(Path::new(&job.target_path)).and_then(|()| {
+            created = true;
+            clone(app, &job, &request, &control).and_then(|()| register(app, &job))
+        })
+    };
+
+    match result {
+        Ok(project_id) => {
+            state.update(app, job_id, |job| {
+                job.status = CloneJobStatus::Completed;
+                job.percent = 100;
+                job.project_id = Some(project_id.clone());
+            });
+            emit_line(app, "Done!", false, Some("Complete"));
+
+            CloneResult {
+                success: true,
+                project_id: Some(project_id),
+                project_path: Some(job.target_path),
+                error: None,
+            }
+        }
+        Err(error) => {
+            // Clean up a partial clone, but never a folder this job didn't create
+            if created {
+                let _ = std::fs::remove_dir_all(&job.target_path);
+            }
+
+            let cancelled = control.cancelled.load(Ordering::Relaxed);
+            state.update(app, job_id, |job| {
+                job.status = if cancelled {
+                    CloneJobStatus::Cancelled
+                } else {
+                    CloneJobStatus::Failed
+                };
+                job.error = Some(error.clone());
+            });
+            emit_line(app, &error, true, None);
+
+            failure(error)
+        }
+    }
+}
+
+/// Create the empty clone target.
+///
+/// The folder may have appeared while the job was queued, so this fails
+/// rather than letting `git clone` fail and the cleanup remove it.
+fn claim_target(path: &Path) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {}", e))?;
+    }
+    std::fs::create_dir(path).map_err(|e| match e.kind() {
+        std::io::ErrorKind::AlreadyExists => format!("Folder already exists: {}", path.display()),
+        _ => format!("Failed to create folder: {}", e),
+    })
+}
+
+/// Run `git clone`, streaming progress into the job.
+fn clone(
+    app: &AppHandle,
+    job: &CloneJob,
+    request: &CloneRequest,
+    control: &JobControl,
+) -> Result<(), String> {
+    let state = app.state::<CloneQueueState>();
+
+    // Rewrite the URL with the SSH alias if requested
+    let url = match request.options.use_ssh_alias {
+        Some(ref alias) => {
+            let known_aliases: Vec<String> = crate::ssh::config::read_ssh_aliases()
+                .map(|aliases| aliases.into_iter().map(|a| a.host).collect())
+                .unwrap_or_default();
+            match parse_git_url(&request.url, known_aliases) {
+                Ok(parsed) => build_ssh_url_with_alias(&parsed, alias),
+                Err(_) => request.url.clone(),
+            }
+        }
+        None => request.url.clone(),
+    };
+
+    let mut args = vec!["clone".to_string(), "--progress".to_string()];
+    if request.options.shallow {
+        args.extend(["--depth".to_string(), "1".to_string()]);
+    }
+    if let Some(ref branch) = request.options.branch {
+        args.extend(["--branch".to_string(), branch.clone()]);
+    }
+    args.push(url);
+    args.push(job.target_path.clone());
+
+    emit_line(
+        app,
+        &format!("Cloning {} into {}", job.folder_name, job.target_path),
+        false,
+        Some("Initializing..."),
+    );
+
+    let askpass = AskpassServer::start(app.clone(), job.id.clone())
+        .map_err(|e| format!("Failed to start credential listener: {}", e))?;
+
+    let mut child = Command::new("git")
+        .args(&args)
+        .envs(askpass.env())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to start git clone: {}", e))?;
+
+    let Some(mut stderr) = child.stderr.take() else {
+        let _ = child.kill();
+        let _ = child.wait();
+        return Err("Failed to capture git output".to_string());
+    };
+    {
+        // The slot only holds the child, so a poisoned lock is still usable;
+        // dropping the child here would leave a zombie process
+        let mut slot = control.child.lock().unwrap_or_else(|e| e.into_inner());
+        // Cancelled between the queue check and the spawn
+        if control.cancelled.load(Ordering::Relaxed) {
+            let _ = child.kill();
+        }
+        *slot = Some(child);
+    }
+
+    // Progress lines are separated by \r, so read raw chunks
+    let mut pending = String::new();
+    let mut buf = [0u8; 4096];
+    let mut last_error_line: Option<String> = None;
+    loop {
+        let n = match stderr.read(&mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => n,
+        };
+
+        for line in split_progress_lines(&mut pending, &String::from_utf8_lossy(&buf[..n])) {
+            if line.starts_with("fatal:") || line.starts_with("error:") {
+                last_error_line = Some(line.clone());
+            }
+
+            let progress = parse_progress_line(&line);
+            emit_line(
+                app,
+                &line,
+                false,
+                progress
+                    .map(|(stage, pct)| format!("{} ({}%)", stage.label(), pct))
+                    .as_deref(),
+            );
+
+            state.update(app, &job.id, |job| {
+                if let Some((stage, pct)) = progress {
+                    job.stage = Some(stage);
+                    job.percent = job.percent.max(overall_percent(stage, pct));
+                }
+                job.message = Some(line.clone());
+            });
+        }
+    }
+
+    let child = control.child.lock().unwrap_or_else(|e| e.into_inner()).take();
+    let status = match child {
+        Some(mut child) => child.wait().map_err(|e| e.to_string())?,
+        None => return Err("git clone process was lost".to_string()),
+    };
+    drop(askpass);
+
+    if control.cancelled.load(Ordering::Relaxed) {
+        return Err("Clone cancelled".to_string());
+    }
+    if !status.success() {
+        return Err(last_error_line.unwrap_or_else(|| "Git clone failed".to_string()));
+    }
+    Ok(())
+}
+
+/// Register the clone as a project and apply the scope's git identity.
+fn register(app: &AppHandle, job: &CloneJob) -> Result<String, String> {
+    let state = app.state::<CloneQueueState>();
+    state.update(app, &job.id, |job| {
+        job.stage = Some(CloneStage::Registering);
+        job.percent = job.percent.max(overall_percent(CloneStage::Registering, 0));
+    });
+    emit_line(app, "Clone completed successfully", false, Some("Registering project..."));
+
+    let db = app.state::<Database>();
+    let project_id = Uuid::new_v4().to_string();
+    let now = Utc::now();
+
+    let git_config = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        get_scope_git_config(&conn, &job.scope_id).map_err(|e| e.to_string())?
+    };
+
+    // Configure the clone before registering it, so a failure leaves no project row
+    if let Some(git_config) = git_config {
+        emit_line(app, "Setting up git identity...", false, Some("Configuring git identity..."));
+        crate::commands::git::apply_git_config_to_project(&job.target_path, &git_config)?;
+    }
+
+    {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        conn.execute(
+            r#"
+            INSERT INTO projects (id, scope_id, name, path, is_temp, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, 0, ?5, ?6)
+            "#,
+            (
+                &project_id,
+                &job.scope_id,
+                &job.folder_name,
+                &job.target_path,
+                now.to_rfc3339(),
+                now.to_rfc3339(),
+            ),
+        )
+        .map_err(|e| format!("Failed to create project: {}", e))?;
+    }
+
+    if let Some(event_bus) = app.try_state::<EventBus>() {
+        event_bus.emit(AppEvent::ProjectAdded {
+            project_id: project_id.clone(),
+            scope_id: job.scope_id.clone(),
+        });
+    }
+
+    Ok(project_id)
+}
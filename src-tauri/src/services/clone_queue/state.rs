@@ -0,0 +1,189 @@
+//! Clone queue state.
+
+use std::collections::HashMap;
+use std::process::Child;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+
+use chrono::Utc;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::Semaphore;
+
+use super::models::{CloneJob, CloneJobStatus, CredentialRequest};
+
+/// Number of clones allowed to run at the same time.
+pub const MAX_CONCURRENT_CLONES: usize = 3;
+
+/// Event channel for job updates.
+pub const CLONE_JOB_EVENT: &str = "clone-job-progress";
+
+/// Handles used to cancel a job.
+#[derive(Default)]
+pub struct JobControl {
+    pub cancelled: AtomicBool,
+    /// The running `git clone` process, once started
+    pub child: Mutex<Option<Child>>,
+}
+
+struct PendingPrompt {
+    job_id: String,
+    answer: mpsc::Sender<Option<String>>,
+}
+
+/// State for queued clones.
+pub struct CloneQueueState {
+    /// Limits concurrently running clones
+    pub slots: Arc<Semaphore>,
+    jobs: Mutex<HashMap<String, CloneJob>>,
+    controls: Mutex<HashMap<String, Arc<JobControl>>>,
+    prompts: Mutex<HashMap<String, PendingPrompt>>,
+}
+
+impl Default for CloneQueueState {
+    fn default() -> Self {
+        Self {
+            slots: Arc::new(Semaphore::new(MAX_CONCURRENT_CLONES)),
+            jobs: Mutex::new(HashMap::new()),
+            controls: Mutex::new(HashMap::new()),
+            prompts: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl CloneQueueState {
+    /// Register a new job and return its control handle.
+    pub fn insert(&self, job: CloneJob) -> Arc<JobControl> {
+        let control = Arc::new(JobControl::default());
+        if let Ok(mut controls) = self.controls.lock() {
+            controls.insert(job.id.clone(), control.clone());
+        }
+        if let Ok(mut jobs) = self.jobs.lock() {
+            jobs.insert(job.id.clone(), job);
+        }
+        control
+    }
+
+    /// Get a snapshot of a job.
+    pub fn get(&self, job_id: &str) -> Option<CloneJob> {
+        self.jobs.lock().ok()?.get(job_id).cloned()
+    }
+
+    /// All jobs, oldest first.
+    pub fn list(&self) -> Vec<CloneJob> {
+        let mut jobs: Vec<CloneJob> = self
+            .jobs
+            .lock()
+            .map(|jobs| jobs.values().cloned().collect())
+            .unwrap_or_default();
+        jobs.sort_by_key(|job| job.created_at);
+        jobs
+    }
+
+    /// Apply a change to a job and emit the updated job.
+    pub fn update(&self, app: &AppHandle, job_id: &str, change: impl FnOnce(&mut CloneJob)) {
+        let updated = self.jobs.lock().ok().and_then(|mut jobs| {
+            let job = jobs.get_mut(job_id)?;
+            change(job);
+            if job.status.is_finished() && job.finished_at.is_none() {
+                job.finished_at = Some(Utc::now());
+            }
+            Some(job.clone())
+        });
+
+        if let Some(job) = updated {
+            let _ = app.emit(CLONE_JOB_EVENT, &job);
+        }
+    }
+
+    /// Set a job's status (ignored once the job has finished).
+    pub fn set_status(&self, app: &AppHandle, job_id: &str, status: CloneJobStatus) {
+        self.update(app, job_id, |job| {
+            if !job.status.is_finished() {
+                job.status = status;
+            }
+        });
+    }
+
+    /// Request cancellation: kills the running process and drops pending prompts.
+    ///
+    /// A queued job is marked cancelled right away instead of when its slot
+    /// comes up. Returns false if the job doesn't exist or already finished.
+    pub fn cancel(&self, app: &AppHandle, job_id: &str) -> bool {
+        let Some(job) = self.get(job_id).filter(|job| !job.status.is_finished()) else {
+            return false;
+        };
+
+        if let Some(control) = self.control(job_id) {
+            control.cancelled.store(true, Ordering::Relaxed);
+            let mut child = control.child.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(child) = child.as_mut() {
+                let _ = child.kill();
+            }
+        }
+
+        if let Ok(mut prompts) = self.prompts.lock() {
+            prompts.retain(|_, prompt| prompt.job_id != job_id);
+        }
+
+        if job.status == CloneJobStatus::Queued {
+            self.update(app, job_id, |job| {
+                job.status = CloneJobStatus::Cancelled;
+                job.error = Some("Clone cancelled".to_string());
+            });
+        }
+        true
+    }
+
+    /// Remove finished jobs, returning how many were removed.
+    pub fn clear_finished(&self) -> usize {
+        let finished: Vec<String> = self
+            .list()
+            .into_iter()
+            .filter(|job| job.status.is_finished())
+            .map(|job| job.id)
+            .collect();
+
+        if let Ok(mut jobs) = self.jobs.lock() {
+            jobs.retain(|id, _| !finished.contains(id));
+        }
+        if let Ok(mut controls) = self.controls.lock() {
+            controls.retain(|id, _| !finished.contains(id));
+        }
+        finished.len()
+    }
+
+    fn control(&self, job_id: &str) -> Option<Arc<JobControl>> {
+        self.controls.lock().ok()?.get(job_id).cloned()
+    }
+
+    /// Track a prompt waiting for the user.
+    pub fn add_prompt(&self, request: &CredentialRequest, answer: mpsc::Sender<Option<String>>) {
+        if let Ok(mut prompts) = self.prompts.lock() {
+            prompts.insert(
+                request.prompt_id.clone(),
+                PendingPrompt {
+                    job_id: request.job_id.clone(),
+                    answer,
+                },
+            );
+        }
+    }
+
+    /// Stop tracking a prompt.
+    pub fn remove_prompt(&self, prompt_id: &str) {
+        if let Ok(mut prompts) = self.prompts.lock() {
+            prompts.remove(prompt_id);
+        }
+    }
+
+    /// Deliver the user's answer (None = the user dismissed the prompt).
+    pub fn answer_prompt(&self, prompt_id: &str, answer: Option<String>) -> Result<(), String> {
+        let prompt = self
+            .prompts
+            .lock()
+            .map_err(|e| e.to_string())?
+            .remove(prompt_id)
+            .ok_or_else(|| format!("No pending credential prompt: {}", prompt_id))?;
+        prompt.answer.send(answer).map_err(|e| e.to_string())
+    }
+}
@@ -22,6 +22,7 @@
 //! - `repo/*` - Repository health and state
 //! - `project/*` - Project structure and organization
 //! - `security/*` - Security-related checks
+//! - `policy/*` - Per-scope conventions (layout, naming, branch, remote host)
 
 pub mod models;
 pub mod repository;
@@ -32,8 +33,8 @@ pub mod state;
 
 // Re-export commonly used types
 pub use models::{
-    DiagnosticFix, DiagnosticIssue, DisabledRule, RuleGroup, RuleMetadata, ScanState, Severity,
-    ScopeDiagnosticsSummary,
+    DiagnosticFix, DiagnosticIssue, DisabledRule, RuleGroup, RuleMetadata, ScanState, ScopePolicy,
+    Severity, ScopeDiagnosticsSummary,
 };
 pub use repository::DiagnosticsRepository;
 pub use rules::DiagnosticRule;
@@ -43,7 +44,9 @@ pub use state::DiagnosticsServiceState;
 
 use crate::db::Database;
 use crate::events::{AppEvent, EventBus};
-use tauri::State;
+use crate::git::DirtyWorkPolicy;
+use std::path::Path;
+use tauri::{AppHandle, Manager, State};
 
 // =========================================================================
 // Tauri Commands
@@ -179,6 +182,36 @@ pub fn get_diagnostic_rule_metadata() -> Vec<RuleMetadata> {
     scanner.get_rule_metadata()
 }
 
+/// Get the convention policy of a scope (all fields unset if none).
+#[tauri::command]
+#[specta::specta]
+pub fn get_scope_policy(db: State<Database>, scope_id: String) -> Result<ScopePolicy, String> {
+    DiagnosticsRepository::get_scope_policy(&db, &scope_id)
+}
+
+/// Set the convention policy of a scope.
+///
+/// The scope is rescanned in the background; `DiagnosticsUpdated` is emitted
+/// when the new issues are stored.
+#[tauri::command]
+#[specta::specta]
+pub fn set_scope_policy(app: AppHandle, policy: ScopePolicy) -> Result<ScopePolicy, String> {
+    let policy = rules::policy::normalize_policy(policy)?;
+    DiagnosticsRepository::set_scope_policy(&app.state::<Database>(), &policy)?;
+
+    let scope_id = policy.scope_id.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let scanner = DiagnosticsScanner::new();
+        if let Err(e) = scanner.scan_scope(&app.state::<Database>(), &scope_id) {
+            tracing::warn!("Failed to rescan scope {} after policy change: {}", scope_id, e);
+            return;
+        }
+        app.state::<EventBus>().emit(AppEvent::DiagnosticsUpdated { scope_id });
+    });
+
+    Ok(policy)
+}
+
 /// Fix a diagnostic issue using the specified fix type.
 #[tauri::command]
 #[specta::specta]
@@ -319,6 +352,74 @@ pub fn fix_diagnostic_issue(
             }
         }
 
+        // Move project into the scope's folder layout
+        ("policy/folder-layout", "move_to_layout") => {
+            if let (Some(project_id), Some(expected)) = (&issue.project_id, &issue.expected_value) {
+                relocate_for_policy(&db, &event_bus, project_id, Path::new(expected), &fix)?;
+            }
+        }
+
+        // Rename project folder to match the naming pattern
+        ("policy/repo-naming", "rename_folder") => {
+            if let Some(project_id) = &issue.project_id {
+                let name = fix
+                    .params
+                    .as_ref()
+                    .and_then(|p| p.get("name"))
+                    .and_then(|v| v.as_str())
+                    .map(str::trim)
+                    .filter(|n| !n.is_empty())
+                    .ok_or_else(|| "A new folder name is required".to_string())?;
+                if name.contains(['/', '\\']) || name == "." || name == ".." {
+                    return Err(format!("Invalid folder name: {}", name));
+                }
+
+                let policy = DiagnosticsRepository::get_scope_policy(&db, &issue.scope_id)?;
+                if let Some(pattern) = &policy.repo_name_pattern {
+                    let regex = regex::Regex::new(pattern).map_err(|e| e.to_string())?;
+                    if !regex.is_match(name) {
+                        return Err(format!("'{}' doesn't match the naming pattern {}", name, pattern));
+                    }
+                }
+
+                let project_path = get_project_path(&db, project_id)?;
+                let new_path = Path::new(&project_path)
+                    .parent()
+                    .ok_or("Invalid project path")?
+                    .join(name);
+                relocate_for_policy(&db, &event_bus, project_id, &new_path, &fix)?;
+            }
+        }
+
+        // Rename the local branch (only for repositories without a remote default)
+        ("policy/default-branch", "rename_branch") => {
+            let from_remote = issue
+                .metadata
+                .as_ref()
+                .and_then(|m| m.get("source"))
+                .and_then(|v| v.as_str())
+                == Some("remote");
+            if from_remote {
+                return Err("The default branch is set on the remote; change it there".to_string());
+            }
+
+            if let (Some(project_id), Some(expected), Some(actual)) =
+                (&issue.project_id, &issue.expected_value, &issue.actual_value)
+            {
+                let project_path = get_project_path(&db, project_id)?;
+
+                let output = std::process::Command::new("git")
+                    .args(["branch", "-m", actual, expected])
+                    .current_dir(&project_path)
+                    .output()
+                    .map_err(|e| e.to_string())?;
+
+                if !output.status.success() {
+                    return Err(String::from_utf8_lossy(&output.stderr).to_string());
+                }
+            }
+        }
+
         _ => {
             return Err(format!(
                 "No automatic fix available for rule '{}' with fix type '{}'",
@@ -349,6 +450,36 @@ fn get_project_path(db: &Database, project_id: &str) -> Result<String, String> {
     .map_err(|e| e.to_string())
 }
 
+/// Helper to move a project for a policy fix and announce the new path.
+///
+/// `params.dirtyPolicy` decides what happens to uncommitted or unpushed
+/// work (refuse by default).
+fn relocate_for_policy(
+    db: &Database,
+    event_bus: &EventBus,
+    project_id: &str,
+    new_path: &Path,
+    fix: &DiagnosticFix,
+) -> Result<(), String> {
+    let dirty_policy: DirtyWorkPolicy = fix
+        .params
+        .as_ref()
+        .and_then(|p| p.get("dirtyPolicy"))
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
+
+    let (scope_id, old_path, new_path) =
+        rules::policy::relocate_project(db, project_id, new_path, dirty_policy)?;
+
+    event_bus.emit(AppEvent::ProjectPathChanged {
+        project_id: project_id.to_string(),
+        scope_id,
+        old_path,
+        new_path,
+    });
+    Ok(())
+}
+
 /// Helper to apply git config fix
 fn apply_git_config_fix(db: &Database, issue: &DiagnosticIssue, fix: &DiagnosticFix) -> Result<(), String> {
     if let (Some(project_id), Some(expected)) = (&issue.project_id, &issue.expected_value) {
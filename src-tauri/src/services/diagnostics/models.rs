@@ -56,6 +56,8 @@ pub enum RuleGroup {
     Project,
     /// Security-related rules (security/*)
     Security,
+    /// Per-scope convention rules (policy/*)
+    Policy,
 }
 
 impl RuleGroup {
@@ -65,6 +67,7 @@ impl RuleGroup {
             RuleGroup::Repo => "repo",
             RuleGroup::Project => "project",
             RuleGroup::Security => "security",
+            RuleGroup::Policy => "policy",
         }
     }
 
@@ -74,6 +77,7 @@ impl RuleGroup {
             RuleGroup::Repo => "Repository Health",
             RuleGroup::Project => "Project Structure",
             RuleGroup::Security => "Security",
+            RuleGroup::Policy => "Scope Policies",
         }
     }
 
@@ -83,6 +87,7 @@ impl RuleGroup {
             RuleGroup::Repo => "Branch state, conflicts, and sync status",
             RuleGroup::Project => "File structure and organization",
             RuleGroup::Security => "Secrets, credentials, and access",
+            RuleGroup::Policy => "Folder layout, naming, branch, and remote conventions",
         }
     }
 
@@ -92,6 +97,7 @@ impl RuleGroup {
             RuleGroup::Repo => "Activity",
             RuleGroup::Project => "Folder",
             RuleGroup::Security => "Shield",
+            RuleGroup::Policy => "ClipboardCheck",
         }
     }
 
@@ -102,6 +108,7 @@ impl RuleGroup {
             "repo" => Some(RuleGroup::Repo),
             "project" => Some(RuleGroup::Project),
             "security" => Some(RuleGroup::Security),
+            "policy" => Some(RuleGroup::Policy),
             _ => None,
         }
     }
@@ -243,6 +250,24 @@ pub struct DiagnosticFix {
     pub params: Option<serde_json::Value>,
 }
 
+/// Conventions enforced for a scope's projects by the `policy/*` rules.
+///
+/// Unset fields are not checked.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ScopePolicy {
+    pub scope_id: String,
+    /// Project location relative to the scope's default folder, with
+    /// `{owner}`, `{repo}` and `{name}` placeholders (e.g. "{owner}/{repo}")
+    pub folder_layout: Option<String>,
+    /// Regex project folder names must match (e.g. "^[a-z0-9-]+$")
+    pub repo_name_pattern: Option<String>,
+    /// Expected default branch (e.g. "main")
+    pub default_branch: Option<String>,
+    /// Host the `origin` remote must point to (e.g. "github.com")
+    pub required_remote_host: Option<String>,
+}
+
 /// Scan state for a scope.
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
@@ -273,6 +298,10 @@ mod tests {
             RuleGroup::from_rule_id("security/secrets-in-files"),
             Some(RuleGroup::Security)
         );
+        assert_eq!(
+            RuleGroup::from_rule_id("policy/folder-layout"),
+            Some(RuleGroup::Policy)
+        );
         assert_eq!(RuleGroup::from_rule_id("invalid"), None);
     }
 
@@ -1,13 +1,14 @@
 //! Database repository for diagnostics.
 //!
-//! Handles all CRUD operations for diagnostic issues, disabled rules, and scan state.
+//! Handles all CRUD operations for diagnostic issues, disabled rules, scan state,
+//! and scope policies.
 
 use crate::db::Database;
 use chrono::Utc;
 use rusqlite::{params, OptionalExtension, Row};
 
 use super::models::{
-    DiagnosticIssue, DisabledRule, ScanState, ScopeDiagnosticsSummary, Severity,
+    DiagnosticIssue, DisabledRule, ScanState, ScopeDiagnosticsSummary, ScopePolicy, Severity,
 };
 
 /// Repository for diagnostics database operations.
@@ -325,6 +326,28 @@ impl DiagnosticsRepository {
         Ok(summary)
     }
 
+    /// Get non-dismissed (error, warning, info) counts for a project.
+    pub fn get_project_counts(db: &Database, project_id: &str) -> Result<(i32, i32, i32), String> {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+
+        conn.query_row(
+            "SELECT
+                SUM(CASE WHEN severity = 'error' THEN 1 ELSE 0 END),
+                SUM(CASE WHEN severity = 'warning' THEN 1 ELSE 0 END),
+                SUM(CASE WHEN severity = 'info' THEN 1 ELSE 0 END)
+             FROM diagnostics WHERE project_id = ?1 AND dismissed = 0",
+            params![project_id],
+            |row| {
+                Ok((
+                    row.get::<_, Option<i32>>(0)?.unwrap_or(0),
+                    row.get::<_, Option<i32>>(1)?.unwrap_or(0),
+                    row.get::<_, Option<i32>>(2)?.unwrap_or(0),
+                ))
+            },
+        )
+        .map_err(|e| e.to_string())
+    }
+
     // =========================================================================
     // Disabled Rules
     // =========================================================================
@@ -468,4 +491,64 @@ impl DiagnosticsRepository {
 
         Ok(state)
     }
+
+    // =========================================================================
+    // Scope Policies
+    // =========================================================================
+
+    /// Get the policy for a scope (all fields unset when none is stored).
+    pub fn get_scope_policy(db: &Database, scope_id: &str) -> Result<ScopePolicy, String> {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+
+        let policy = conn
+            .query_row(
+                "SELECT folder_layout, repo_name_pattern, default_branch, required_remote_host
+                 FROM scope_policies WHERE scope_id = ?1",
+                params![scope_id],
+                |row| {
+                    Ok(ScopePolicy {
+                        scope_id: scope_id.to_string(),
+                        folder_layout: row.get(0)?,
+                        repo_name_pattern: row.get(1)?,
+                        default_branch: row.get(2)?,
+                        required_remote_host: row.get(3)?,
+                    })
+                },
+            )
+            .optional()
+            .map_err(|e| e.to_string())?;
+
+        Ok(policy.unwrap_or_else(|| ScopePolicy {
+            scope_id: scope_id.to_string(),
+            ..Default::default()
+        }))
+    }
+
+    /// Store the policy for a scope.
+    pub fn set_scope_policy(db: &Database, policy: &ScopePolicy) -> Result<(), String> {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+
+        conn.execute(
+            "INSERT INTO scope_policies
+                (scope_id, folder_layout, repo_name_pattern, default_branch, required_remote_host, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(scope_id) DO UPDATE SET
+                folder_layout = excluded.folder_layout,
+                repo_name_pattern = excluded.repo_name_pattern,
+                default_branch = excluded.default_branch,
+                required_remote_host = excluded.required_remote_host,
+                updated_at = excluded.updated_at",
+            params![
+                policy.scope_id,
+                policy.folder_layout,
+                policy.repo_name_pattern,
+                policy.default_branch,
+                policy.required_remote_host,
+                Utc::now().to_rfc3339(),
+            ],
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
 }
@@ -8,6 +8,7 @@ use crate::db::models::{ProjectWithStatus, Scope};
 use super::models::{DiagnosticIssue, RuleGroup, RuleMetadata, Severity};
 
 pub mod git;
+pub mod policy;
 pub mod project;
 pub mod repo;
 pub mod security;
@@ -67,6 +68,11 @@ impl RuleRegistry {
             Box::new(security::EnvFileTrackedRule),
             Box::new(security::InsecureRemoteRule),
             Box::new(security::NodeModulesCommittedRule),
+            // Policy rules
+            Box::new(policy::FolderLayoutRule),
+            Box::new(policy::RepoNamingRule),
+            Box::new(policy::DefaultBranchRule),
+            Box::new(policy::RemoteHostRule),
         ];
 
         Self { rules }
@@ -0,0 +1,178 @@
+//! Default branch rule.
+//!
+//! Checks if the repository's default branch has the scope's expected name.
+//! The remote's default (`refs/remotes/*/HEAD`) is used when known; otherwise
+//! the repository must have a local branch with that name. The checked-out
+//! branch is never taken as the default, since it is usually a feature branch.
+
+use crate::db::models::{ProjectWithStatus, Scope};
+use crate::db::Database;
+use crate::services::diagnostics::models::{DiagnosticIssue, RuleMetadata, Severity};
+use crate::services::diagnostics::repository::DiagnosticsRepository;
+use crate::services::diagnostics::rules::{rule_metadata, DiagnosticRule};
+use git2::{BranchType, Repository};
+
+pub struct DefaultBranchRule;
+
+/// The branch a remote's HEAD points to, preferring `origin`.
+fn remote_default_branch(repo: &Repository) -> Option<String> {
+    let remotes = repo.remotes().ok()?;
+    let mut names: Vec<&str> = remotes.iter().flatten().collect();
+    names.sort_by_key(|name| *name != "origin");
+
+    names.into_iter().find_map(|remote| {
+        let head = repo
+            .find_reference(&format!("refs/remotes/{}/HEAD", remote))
+            .ok()?;
+        let target = head.symbolic_target()?;
+        target
+            .strip_prefix(&format!("refs/remotes/{}/", remote))
+            .map(String::from)
+    })
+}
+
+/// Best guess at the default branch of a repository without a remote default.
+///
+/// Uses `init.defaultBranch` when that branch exists, then a lone local
+/// branch, then `main`/`master`. Returns None when it can't tell.
+fn local_default_branch(repo: &Repository) -> Option<String> {
+    let exists = |name: &str| repo.find_branch(name, BranchType::Local).is_ok();
+
+    if let Some(configured) = repo
+        .config()
+        .ok()
+        .and_then(|config| config.get_string("init.defaultBranch").ok())
+        .filter(|name| exists(name))
+    {
+        return Some(configured);
+    }
+
+    let branches: Vec<String> = repo
+        .branches(Some(BranchType::Local))
+        .ok()?
+        .filter_map(|b| b.ok())
+        .filter_map(|(branch, _)| branch.name().ok().flatten().map(String::from))
+        .collect();
+    if let [only] = branches.as_slice() {
+        return Some(only.clone());
+    }
+
+    ["main", "master"]
+        .into_iter()
+        .find(|name| exists(name))
+        .map(String::from)
+}
+
+impl DiagnosticRule for DefaultBranchRule {
+    fn metadata(&self) -> RuleMetadata {
+        rule_metadata(
+            "policy/default-branch",
+            "Default Branch",
+            "Repository's default branch doesn't have the scope's expected name",
+            true,
+            Severity::Warning,
+            None,
+            false,
+        )
+    }
+
+    fn check(
+        &self,
+        db: &Database,
+        scope: &Scope,
+        projects: &[ProjectWithStatus],
+    ) -> Result<Vec<DiagnosticIssue>, String> {
+        let mut issues = Vec::new();
+
+        let expected = match DiagnosticsRepository::get_scope_policy(db, &scope.id)?.default_branch {
+            Some(branch) => branch,
+            None => return Ok(issues),
+        };
+
+        for project in projects {
+            // Skip temp projects
+            if project.project.is_temp {
+                continue;
+            }
+
+            let Ok(repo) = Repository::open(&project.project.path) else {
+                continue;
+            };
+
+            let (actual, source) = match remote_default_branch(&repo) {
+                Some(branch) => (branch, "remote"),
+                None => {
+                    if repo.find_branch(&expected, BranchType::Local).is_ok() {
+                        continue;
+                    }
+                    let Some(branch) = local_default_branch(&repo) else {
+                        continue;
+                    };
+                    (branch, "local")
+                }
+            };
+
+            if actual != expected {
+                issues.push(
+                    DiagnosticIssue::new(
+                        scope.id.clone(),
+                        Some(project.project.id.clone()),
+                        "policy/default-branch".to_string(),
+                        Severity::Warning,
+                        "Default Branch".to_string(),
+                        format!(
+                            "Project '{}' uses '{}' as its default branch instead of '{}'",
+                            project.project.name, actual, expected
+                        ),
+                    )
+                    .with_values(Some(expected.clone()), Some(actual))
+                    .with_metadata(serde_json::json!({ "source": source })),
+                );
+            }
+        }
+
+        Ok(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    fn commit_on(repo: &Repository, branch: &str) {
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(
+            Some(&format!("refs/heads/{}", branch)),
+            &sig,
+            &sig,
+            "commit",
+            &tree,
+            &parents,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_local_default_ignores_checked_out_branch() {
+        let dir = env::temp_dir().join("panager_test_policy_default_branch");
+        let _ = fs::remove_dir_all(&dir);
+        let repo = Repository::init(&dir).unwrap();
+        repo.config().unwrap().set_str("init.defaultBranch", "master").unwrap();
+
+        commit_on(&repo, "master");
+        repo.set_head("refs/heads/master").unwrap();
+        commit_on(&repo, "feature");
+        repo.set_head("refs/heads/feature").unwrap();
+
+        assert_eq!(local_default_branch(&repo), Some("master".to_string()));
+        assert_eq!(remote_default_branch(&repo), None);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,85 @@
+//! Folder layout rule.
+//!
+//! Checks if project is located where the scope's folder layout says,
+//! e.g. `{default_folder}/{owner}/{repo}`.
+
+use super::{folder_name, origin_parts, render_layout, same_path};
+use crate::db::models::{ProjectWithStatus, Scope};
+use crate::db::Database;
+use crate::services::diagnostics::models::{DiagnosticIssue, RuleMetadata, Severity};
+use crate::services::diagnostics::repository::DiagnosticsRepository;
+use crate::services::diagnostics::rules::{rule_metadata, DiagnosticRule};
+use std::path::Path;
+
+pub struct FolderLayoutRule;
+
+impl DiagnosticRule for FolderLayoutRule {
+    fn metadata(&self) -> RuleMetadata {
+        rule_metadata(
+            "policy/folder-layout",
+            "Folder Layout",
+            "Project is not located according to the scope's folder layout",
+            true,
+            Severity::Warning,
+            None,
+            false,
+        )
+    }
+
+    fn check(
+        &self,
+        db: &Database,
+        scope: &Scope,
+        projects: &[ProjectWithStatus],
+    ) -> Result<Vec<DiagnosticIssue>, String> {
+        let mut issues = Vec::new();
+
+        let default_folder = match &scope.default_folder {
+            Some(folder) if !folder.is_empty() => folder,
+            _ => return Ok(issues),
+        };
+        let layout = match DiagnosticsRepository::get_scope_policy(db, &scope.id)?.folder_layout {
+            Some(layout) => layout,
+            None => return Ok(issues),
+        };
+
+        for project in projects {
+            // Skip temp projects
+            if project.project.is_temp {
+                continue;
+            }
+
+            let Some(name) = folder_name(&project.project.path) else {
+                continue;
+            };
+            let origin = origin_parts(project);
+            let remote = origin.as_ref().map(|(_, owner, repo)| (owner.as_str(), repo.as_str()));
+
+            // Projects without a remote can't be placed by owner/repo
+            let Some(relative) = render_layout(&layout, remote, name) else {
+                continue;
+            };
+            let expected = Path::new(default_folder).join(relative);
+            let expected = expected.to_string_lossy().to_string();
+
+            if !same_path(&expected, &project.project.path) {
+                issues.push(
+                    DiagnosticIssue::new(
+                        scope.id.clone(),
+                        Some(project.project.id.clone()),
+                        "policy/folder-layout".to_string(),
+                        Severity::Warning,
+                        "Folder Layout".to_string(),
+                        format!(
+                            "Project '{}' is not located according to the folder layout '{}'",
+                            project.project.name, layout
+                        ),
+                    )
+                    .with_values(Some(expected), Some(project.project.path.clone())),
+                );
+            }
+        }
+
+        Ok(issues)
+    }
+}
@@ -0,0 +1,249 @@
+//! Scope policy diagnostic rules.
+//!
+//! Rules in this group check projects against the conventions configured
+//! for their scope (see [`ScopePolicy`]):
+//! - Folder layout relative to the scope's default folder
+//! - Repository folder naming pattern
+//! - Default branch name
+//! - Required remote host
+//!
+//! Each rule does nothing until the matching policy field is set.
+
+mod default_branch;
+mod folder_layout;
+mod remote_host;
+mod repo_naming;
+
+pub use default_branch::DefaultBranchRule;
+pub use folder_layout::FolderLayoutRule;
+pub use remote_host::RemoteHostRule;
+pub use repo_naming::RepoNamingRule;
+
+use crate::db::models::ProjectWithStatus;
+use crate::db::Database;
+use crate::git::{guard_folder_operation, DirtyWorkPolicy, FolderOperation};
+use crate::services::diagnostics::models::ScopePolicy;
+use crate::services::providers::service::{origin_url, remote_parts};
+use crate::utils::regex::LAYOUT_PLACEHOLDER_REGEX;
+use chrono::Utc;
+use regex::Regex;
+use std::path::{Component, Path};
+
+/// Placeholders allowed in a folder layout.
+const LAYOUT_PLACEHOLDERS: [&str; 3] = ["{owner}", "{repo}", "{name}"];
+
+/// Trim a policy and check that its fields are usable.
+///
+/// Blank fields are cleared so they aren't checked.
+pub fn normalize_policy(policy: ScopePolicy) -> Result<ScopePolicy, String> {
+    fn clean(value: Option<String>) -> Option<String> {
+        value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty())
+    }
+
+    let policy = ScopePolicy {
+        scope_id: policy.scope_id,
+        folder_layout: clean(policy.folder_layout).map(|l| l.trim_matches('/').to_string()),
+        repo_name_pattern: clean(policy.repo_name_pattern),
+        default_branch: clean(policy.default_branch),
+        required_remote_host: clean(policy.required_remote_host).map(|h| h.to_lowercase()),
+    };
+
+    if let Some(layout) = &policy.folder_layout {
+        let path = Path::new(layout);
+        if path
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_)))
+        {
+            return Err(format!("Folder layout must be a relative path: {}", layout));
+        }
+        let unknown = LAYOUT_PLACEHOLDER_REGEX
+            .find_iter(layout)
+            .find(|m| !LAYOUT_PLACEHOLDERS.contains(&m.as_str()));
+        if let Some(unknown) = unknown {
+            return Err(format!(
+                "Unknown placeholder {} in folder layout (use {{owner}}, {{repo}} or {{name}})",
+                unknown.as_str()
+            ));
+        }
+    }
+
+    if let Some(pattern) = &policy.repo_name_pattern {
+        Regex::new(pattern).map_err(|e| format!("Invalid naming pattern: {}", e))?;
+    }
+
+    if let Some(branch) = &policy.default_branch {
+        if !git2::Reference::is_valid_name(&format!("refs/heads/{}", branch)) {
+            return Err(format!("Invalid branch name: {}", branch));
+        }
+    }
+
+    if let Some(host) = &policy.required_remote_host {
+        if host.contains("://") || host.contains('/') || host.contains('@') {
+            return Err(format!("Remote host must be a bare host name: {}", host));
+        }
+    }
+
+    Ok(policy)
+}
+
+/// Fill a folder layout's placeholders.
+///
+/// Returns None when the layout uses `{owner}`/`{repo}` and the project has
+/// no recognizable remote.
+pub fn render_layout(layout: &str, remote: Option<(&str, &str)>, name: &str) -> Option<String> {
+    let mut rendered = layout.replace("{name}", name);
+    if rendered.contains("{owner}") || rendered.contains("{repo}") {
+        let (owner, repo) = remote?;
+        rendered = rendered.replace("{owner}", owner).replace("{repo}", repo);
+    }
+    Some(rendered)
+}
+
+/// Host and (owner, repo) of a project's `origin` remote.
+///
+/// Nested GitLab groups stay in the owner ("group/sub", "repo"). The host is
+/// lowercased; owner and repo keep their casing.
+pub(super) fn origin_parts(project: &ProjectWithStatus) -> Option<(String, String, String)> {
+    let cached = project.git_status.as_ref().and_then(|s| s.remote_url.clone());
+    let url = origin_url(cached, &project.project.path)?;
+    let (host, path) = remote_parts(&url)?;
+    let (owner, repo) = path.rsplit_once('/')?;
+    Some((host, owner.to_string(), repo.to_string()))
+}
+
+/// Whether two paths name the same location.
+///
+/// Case is ignored only where the default file systems are case-insensitive.
+pub(super) fn same_path(a: &str, b: &str) -> bool {
+    if cfg!(any(target_os = "macos", target_os = "windows")) {
+        a.to_lowercase() == b.to_lowercase()
+    } else {
+        a == b
+    }
+}
+
+/// The folder name of a project.
+pub(super) fn folder_name(path: &str) -> Option<&str> {
+    Path::new(path).file_name().and_then(|n| n.to_str())
+}
+
+/// Move a project folder to `new_path` and update its stored path.
+///
+/// Returns (scope_id, old_path, new_path). Uncommitted or unpushed work is
+/// handled according to `dirty_policy`.
+pub fn relocate_project(
+    db: &Database,
+    project_id: &str,
+    new_path: &Path,
+    dirty_policy: DirtyWorkPolicy,
+) -> Result<(String, String, String), String> {
+    let (old_path, scope_id): (String, String) = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        conn.query_row(
+            "SELECT path, scope_id FROM projects WHERE id = ?1",
+            [project_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .map_err(|e| e.to_string())?
+    };
+    let new_path_str = new_path.to_str().ok_or("Invalid new path")?.to_string();
+
+    if new_path.exists() {
+        return Err(format!("Destination already exists: {}", new_path_str));
+    }
+
+    guard_folder_operation(Path::new(&old_path), FolderOperation::Move, dirty_policy)?;
+
+    if let Some(parent) = new_path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create destination folder: {}", e))?;
+    }
+    std::fs::rename(&old_path, new_path).map_err(|e| format!("Failed to move folder: {}", e))?;
+
+    let updated = db.conn.lock().map_err(|e| e.to_string()).and_then(|conn| {
+        conn.execute(
+            "UPDATE projects SET path = ?1, updated_at = ?2 WHERE id = ?3",
+            (&new_path_str, Utc::now().to_rfc3339(), project_id),
+        )
+        .map_err(|e| e.to_string())
+    });
+
+    // Keep the folder where the database says it is
+    if let Err(e) = updated {
+        return match std::fs::rename(new_path, &old_path) {
+            Ok(()) => Err(format!("Failed to update project path: {}", e)),
+            Err(rollback) => Err(format!(
+                "Failed to update project path ({}); the folder is now at {} and could not be moved back: {}",
+                e, new_path_str, rollback
+            )),
+        };
+    }
+
+    Ok((scope_id, old_path, new_path_str))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_path() {
+        assert!(same_path("/code/acme/app", "/code/acme/app"));
+        assert!(!same_path("/code/acme/app", "/code/acme/api"));
+        assert_eq!(
+            same_path("/code/Acme/App", "/code/acme/app"),
+            cfg!(any(target_os = "macos", target_os = "windows"))
+        );
+    }
+
+    #[test]
+    fn test_render_layout() {
+        let remote = Some(("acme/platform", "api"));
+        assert_eq!(
+            render_layout("{owner}/{repo}", remote, "api-local"),
+            Some("acme/platform/api".to_string())
+        );
+        assert_eq!(
+            render_layout("clients/{name}", None, "site"),
+            Some("clients/site".to_string())
+        );
+        assert_eq!(render_layout("{owner}/{name}", None, "site"), None);
+    }
+
+    #[test]
+    fn test_normalize_policy() {
+        let policy = normalize_policy(ScopePolicy {
+            scope_id: "s1".to_string(),
+            folder_layout: Some("/{owner}/{repo}/".to_string()),
+            repo_name_pattern: Some("  ".to_string()),
+            default_branch: Some("main".to_string()),
+            required_remote_host: Some("GitHub.com".to_string()),
+        })
+        .unwrap();
+        assert_eq!(policy.folder_layout.as_deref(), Some("{owner}/{repo}"));
+        assert_eq!(policy.repo_name_pattern, None);
+        assert_eq!(policy.required_remote_host.as_deref(), Some("github.com"));
+
+        let invalid = |policy: ScopePolicy| normalize_policy(policy).is_err();
+        assert!(invalid(ScopePolicy {
+            folder_layout: Some("{org}/{repo}".to_string()),
+            ..Default::default()
+        }));
+        assert!(invalid(ScopePolicy {
+            folder_layout: Some("../{repo}".to_string()),
+            ..Default::default()
+        }));
+        assert!(invalid(ScopePolicy {
+            repo_name_pattern: Some("[a-z".to_string()),
+            ..Default::default()
+        }));
+        assert!(invalid(ScopePolicy {
+            default_branch: Some("bad..name".to_string()),
+            ..Default::default()
+        }));
+        assert!(invalid(ScopePolicy {
+            required_remote_host: Some("https://github.com".to_string()),
+            ..Default::default()
+        }));
+    }
+}
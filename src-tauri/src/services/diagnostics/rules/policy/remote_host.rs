@@ -0,0 +1,75 @@
+//! Remote host rule.
+//!
+//! Checks if the `origin` remote points to the scope's required host.
+//! SSH config aliases are resolved to their real host name.
+
+use super::origin_parts;
+use crate::db::models::{ProjectWithStatus, Scope};
+use crate::db::Database;
+use crate::services::diagnostics::models::{DiagnosticIssue, RuleMetadata, Severity};
+use crate::services::diagnostics::repository::DiagnosticsRepository;
+use crate::services::diagnostics::rules::{rule_metadata, DiagnosticRule};
+use crate::services::providers::service::ssh_alias_hosts;
+
+pub struct RemoteHostRule;
+
+impl DiagnosticRule for RemoteHostRule {
+    fn metadata(&self) -> RuleMetadata {
+        rule_metadata(
+            "policy/remote-host",
+            "Remote Host",
+            "Origin remote doesn't point to the scope's required host",
+            true,
+            Severity::Warning,
+            None,
+            false,
+        )
+    }
+
+    fn check(
+        &self,
+        db: &Database,
+        scope: &Scope,
+        projects: &[ProjectWithStatus],
+    ) -> Result<Vec<DiagnosticIssue>, String> {
+        let mut issues = Vec::new();
+
+        let required = match DiagnosticsRepository::get_scope_policy(db, &scope.id)?.required_remote_host {
+            Some(host) => host.to_lowercase(),
+            None => return Ok(issues),
+        };
+        let aliases = ssh_alias_hosts();
+
+        for project in projects {
+            // Skip temp projects
+            if project.project.is_temp {
+                continue;
+            }
+
+            // Projects without an origin are not checked
+            let Some((host, _, _)) = origin_parts(project) else {
+                continue;
+            };
+            let resolved = aliases.get(&host).unwrap_or(&host);
+
+            if *resolved != required {
+                issues.push(
+                    DiagnosticIssue::new(
+                        scope.id.clone(),
+                        Some(project.project.id.clone()),
+                        "policy/remote-host".to_string(),
+                        Severity::Warning,
+                        "Remote Host".to_string(),
+                        format!(
+                            "Project '{}' has its origin on {} instead of {}",
+                            project.project.name, resolved, required
+                        ),
+                    )
+                    .with_values(Some(required.clone()), Some(resolved.clone())),
+                );
+            }
+        }
+
+        Ok(issues)
+    }
+}
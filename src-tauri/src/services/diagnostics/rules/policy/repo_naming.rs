@@ -0,0 +1,72 @@
+//! Repository naming rule.
+//!
+//! Checks if project folder names match the scope's naming pattern.
+
+use super::folder_name;
+use crate::db::models::{ProjectWithStatus, Scope};
+use crate::db::Database;
+use crate::services::diagnostics::models::{DiagnosticIssue, RuleMetadata, Severity};
+use crate::services::diagnostics::repository::DiagnosticsRepository;
+use crate::services::diagnostics::rules::{rule_metadata, DiagnosticRule};
+use regex::Regex;
+
+pub struct RepoNamingRule;
+
+impl DiagnosticRule for RepoNamingRule {
+    fn metadata(&self) -> RuleMetadata {
+        rule_metadata(
+            "policy/repo-naming",
+            "Repository Naming",
+            "Project folder name doesn't match the scope's naming pattern",
+            true,
+            Severity::Warning,
+            None,
+            false,
+        )
+    }
+
+    fn check(
+        &self,
+        db: &Database,
+        scope: &Scope,
+        projects: &[ProjectWithStatus],
+    ) -> Result<Vec<DiagnosticIssue>, String> {
+        let mut issues = Vec::new();
+
+        let pattern = match DiagnosticsRepository::get_scope_policy(db, &scope.id)?.repo_name_pattern {
+            Some(pattern) => pattern,
+            None => return Ok(issues),
+        };
+        let regex = Regex::new(&pattern).map_err(|e| format!("Invalid naming pattern: {}", e))?;
+
+        for project in projects {
+            // Skip temp projects
+            if project.project.is_temp {
+                continue;
+            }
+
+            let Some(name) = folder_name(&project.project.path) else {
+                continue;
+            };
+
+            if !regex.is_match(name) {
+                issues.push(
+                    DiagnosticIssue::new(
+                        scope.id.clone(),
+                        Some(project.project.id.clone()),
+                        "policy/repo-naming".to_string(),
+                        Severity::Warning,
+                        "Repository Naming".to_string(),
+                        format!(
+                            "Folder '{}' of project '{}' doesn't match the naming pattern",
+                            name, project.project.name
+                        ),
+                    )
+                    .with_values(Some(pattern.clone()), Some(name.to_string())),
+                );
+            }
+        }
+
+        Ok(issues)
+    }
+}
@@ -105,6 +105,7 @@ async fn run_scan(
 
         let total_issues: usize = results.iter().map(|r| r.issues_found).sum();
         let total_duration: u64 = results.iter().map(|r| r.duration_ms).sum();
+        crate::services::telemetry::record_metric("diagnostics_scan", total_duration);
         tracing::debug!(
             "Diagnostics scan complete: {} scopes, {} total issues, {}ms",
             results.len(),
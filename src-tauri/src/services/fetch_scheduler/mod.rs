@@ -0,0 +1,74 @@
+//! Background git fetch scheduler.
+//!
+//! Periodically runs `git fetch` for the projects of each scope according to
+//! the scope's [`ScopeFetchPolicy`] (opt-in; interval, and whether to skip on
+//! battery power or metered connections), then refreshes the cached git
+//! status so ahead/behind counts stay current. Projects whose counts change
+//! emit `ProjectGitStatusChanged`. Fetches never prompt for credentials and
+//! are killed after a timeout.
+
+pub mod models;
+mod platform;
+pub mod service;
+mod state;
+
+pub use models::{PlatformConditions, ScopeFetchPolicy, ScopeFetchResult};
+pub use service::start_fetch_scheduler;
+pub use state::FetchSchedulerState;
+
+use crate::db::Database;
+use models::MIN_FETCH_INTERVAL_MINUTES;
+use tauri::{AppHandle, Manager, State};
+
+// =========================================================================
+// Tauri Commands
+// =========================================================================
+
+/// Get the background fetch policy for a scope.
+#[tauri::command]
+#[specta::specta]
+pub fn get_scope_fetch_policy(
+    db: State<Database>,
+    scope_id: String,
+) -> Result<ScopeFetchPolicy, String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    service::get_policy(&conn, &scope_id)
+}
+
+/// Set the background fetch policy for a scope.
+#[tauri::command]
+#[specta::specta]
+pub fn set_scope_fetch_policy(db: State<Database>, policy: ScopeFetchPolicy) -> Result<(), String> {
+    if policy.interval_minutes < MIN_FETCH_INTERVAL_MINUTES {
+        return Err(format!(
+            "Fetch interval must be at least {} minutes",
+            MIN_FETCH_INTERVAL_MINUTES
+        ));
+    }
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    service::set_policy(&conn, &policy)
+}
+
+/// Get the current power and network conditions used by fetch policies.
+#[tauri::command]
+#[specta::specta]
+pub fn get_fetch_conditions() -> Result<PlatformConditions, String> {
+    Ok(platform::current_conditions())
+}
+
+/// Fetch all projects in a scope now, ignoring interval and conditions.
+#[tauri::command]
+#[specta::specta]
+pub async fn fetch_scope_now(app: AppHandle, scope_id: String) -> Result<ScopeFetchResult, String> {
+    let handle = app.clone();
+    let id = scope_id.clone();
+    let result = tauri::async_runtime::spawn_blocking(move || service::fetch_scope(&handle, &id))
+        .await
+        .map_err(|e| e.to_string())??;
+
+    if let Ok(mut last_run) = app.state::<FetchSchedulerState>().last_run.lock() {
+        last_run.insert(scope_id, chrono::Utc::now());
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,131 @@
+//! Background fetch models.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Default minutes between background fetches of a scope.
+pub const DEFAULT_FETCH_INTERVAL_MINUTES: u32 = 30;
+
+/// Shortest allowed interval, to avoid hammering remotes.
+pub const MIN_FETCH_INTERVAL_MINUTES: u32 = 5;
+
+/// Background fetch policy for a scope.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ScopeFetchPolicy {
+    pub scope_id: String,
+    pub enabled: bool,
+    pub interval_minutes: u32,
+    /// Skip fetching while running on battery power
+    pub skip_on_battery: bool,
+    /// Skip fetching on metered network connections
+    pub skip_on_metered: bool,
+}
+
+impl ScopeFetchPolicy {
+    /// Policy used for scopes without an explicit one.
+    ///
+    /// Background fetching is opt-in per scope.
+    pub fn default_for(scope_id: &str) -> Self {
+        Self {
+            scope_id: scope_id.to_string(),
+            enabled: false,
+            interval_minutes: DEFAULT_FETCH_INTERVAL_MINUTES,
+            skip_on_battery: true,
+            skip_on_metered: true,
+        }
+    }
+
+    /// Whether a fetch is due given the last run time.
+    pub fn is_due(&self, last_run: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        let interval = self.interval_minutes.max(MIN_FETCH_INTERVAL_MINUTES) as i64;
+        match last_run {
+            Some(last) => now - last >= Duration::minutes(interval),
+            None => true,
+        }
+    }
+
+    /// Reason to skip under the current platform conditions, if any.
+    pub fn skip_reason(&self, conditions: &PlatformConditions) -> Option<&'static str> {
+        if self.skip_on_battery && conditions.on_battery {
+            Some("on battery power")
+        } else if self.skip_on_metered && conditions.metered_network {
+            Some("on a metered connection")
+        } else {
+            None
+        }
+    }
+}
+
+/// Power and network state reported by the platform.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PlatformConditions {
+    pub on_battery: bool,
+    pub metered_network: bool,
+}
+
+/// Result of fetching one scope.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ScopeFetchResult {
+    pub scope_id: String,
+    pub fetched: u32,
+    pub failed: u32,
+    /// Projects whose ahead/behind counts changed
+    pub changed: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_due() {
+        let now = Utc::now();
+        let policy = ScopeFetchPolicy {
+            enabled: true,
+            ..ScopeFetchPolicy::default_for("s1")
+        };
+
+        assert!(!ScopeFetchPolicy::default_for("s1").is_due(None, now));
+        assert!(policy.is_due(None, now));
+        assert!(!policy.is_due(Some(now - Duration::minutes(10)), now));
+        assert!(policy.is_due(Some(now - Duration::minutes(30)), now));
+
+        let disabled = ScopeFetchPolicy {
+            enabled: false,
+            ..policy.clone()
+        };
+        assert!(!disabled.is_due(None, now));
+
+        // Intervals below the minimum are clamped
+        let aggressive = ScopeFetchPolicy {
+            interval_minutes: 1,
+            ..policy
+        };
+        assert!(!aggressive.is_due(Some(now - Duration::minutes(2)), now));
+    }
+
+    #[test]
+    fn test_skip_reason() {
+        let policy = ScopeFetchPolicy::default_for("s1");
+        let battery = PlatformConditions {
+            on_battery: true,
+            metered_network: false,
+        };
+
+        assert_eq!(policy.skip_reason(&battery), Some("on battery power"));
+        assert_eq!(policy.skip_reason(&PlatformConditions::default()), None);
+
+        let allow_battery = ScopeFetchPolicy {
+            skip_on_battery: false,
+            ..policy
+        };
+        assert_eq!(allow_battery.skip_reason(&battery), None);
+    }
+}
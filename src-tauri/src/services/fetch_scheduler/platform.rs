@@ -0,0 +1,70 @@
+//! Platform hooks for power and network conditions.
+//!
+//! Detection is best effort: when the platform can't be queried the
+//! conditions default to "not on battery, not metered" so fetching proceeds.
+
+use super::models::PlatformConditions;
+
+/// Query current power and network conditions.
+pub fn current_conditions() -> PlatformConditions {
+    PlatformConditions {
+        on_battery: on_battery(),
+        metered_network: metered_network(),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn on_battery() -> bool {
+    let Ok(entries) = std::fs::read_dir("/sys/class/power_supply") else {
+        return false;
+    };
+
+    let mut has_mains = false;
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        let kind = std::fs::read_to_string(path.join("type")).unwrap_or_default();
+        if kind.trim() == "Mains" {
+            has_mains = true;
+            let online = std::fs::read_to_string(path.join("online")).unwrap_or_default();
+            if online.trim() == "1" {
+                return false;
+            }
+        }
+    }
+
+    // Desktops without a mains supply entry are never on battery
+    has_mains
+}
+
+#[cfg(target_os = "macos")]
+fn on_battery() -> bool {
+    std::process::Command::new("pmset")
+        .args(["-g", "batt"])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).contains("'Battery Power'"))
+        .unwrap_or(false)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn on_battery() -> bool {
+    false
+}
+
+#[cfg(target_os = "linux")]
+fn metered_network() -> bool {
+    // NetworkManager reports "yes" / "yes (guessed)" for metered devices
+    std::process::Command::new("nmcli")
+        .args(["-t", "-g", "GENERAL.METERED", "device", "show"])
+        .output()
+        .map(|o| {
+            String::from_utf8_lossy(&o.stdout)
+                .lines()
+                .any(|line| line.trim().starts_with("yes"))
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn metered_network() -> bool {
+    false
+}
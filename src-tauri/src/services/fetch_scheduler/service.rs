@@ -0,0 +1,397 @@
+//! Background fetch scheduler implementation.
+
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use tauri::{AppHandle, Manager};
+
+use crate::db::Database;
+use crate::events::{AppEvent, EventBus};
+
+use super::models::{PlatformConditions, ScopeFetchPolicy, ScopeFetchResult};
+use super::platform::current_conditions;
+use super::FetchSchedulerState;
+
+/// How often the scheduler checks for due scopes.
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Longest a single background `git fetch` may run before it is killed.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// A project to fetch, with its ahead/behind counts before the fetch.
+struct FetchTarget {
+    project_id: String,
+    path: String,
+    previous: Option<(i32, i32)>,
+}
+
+/// Get the fetch policy for a scope (defaults when none is stored).
+pub fn get_policy(conn: &Connection, scope_id: &str) -> Result<ScopeFetchPolicy, String> {
+    let policy = conn
+        .query_row(
+            r#"
+            SELECT enabled, interval_minutes, skip_on_battery, skip_on_metered
+            FROM scope_fetch_policies
+            WHERE scope_id = ?1
+            "#,
+            [scope_id],
+            |row| {
+                Ok(ScopeFetchPolicy {
+                    scope_id: scope_id.to_string(),
+                    enabled: row.get(0)?,
+                    interval_minutes: row.get(1)?,
+                    skip_on_battery: row.get(2)?,
+                    skip_on_metered: row.get(3)?,
+                })
+            },
+        )
+        .optional()
+        .map_err(|e| e.to_string())?;
+
+    Ok(policy.unwrap_or_else(|| ScopeFetchPolicy::default_for(scope_id)))
+}
+
+/// Store the fetch policy for a scope.
+pub fn set_policy(conn: &Connection, policy: &ScopeFetchPolicy) -> Result<(), String> {
+    conn.execute(
+        r#"
+        INSERT INTO scope_fetch_policies
+            (scope_id, enabled, interval_minutes, skip_on_battery, skip_on_metered, updated_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        ON CONFLICT(scope_id) DO UPDATE SET
+            enabled = excluded.enabled,
+            interval_minutes = excluded.interval_minutes,
+            skip_on_battery = excluded.skip_on_battery,
+            skip_on_metered = excluded.skip_on_metered,
+            updated_at = excluded.updated_at
+        "#,
+        params![
+            policy.scope_id,
+            policy.enabled,
+            policy.interval_minutes,
+            policy.skip_on_battery,
+            policy.skip_on_metered,
+            Utc::now().to_rfc3339(),
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Fetch every git project in a scope and refresh its cached status.
+///
+/// Emits `ProjectGitStatusChanged` for projects whose ahead/behind counts
+/// changed.
+pub fn fetch_scope(app: &AppHandle, scope_id: &str) -> Result<ScopeFetchResult, String> {
+    let db = app.state::<Database>();
+
+    let projects: Vec<FetchTarget> = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT p.id, p.path, g.ahead, g.behind
+                FROM projects p
+                LEFT JOIN git_status_cache g ON p.id = g.project_id
+                WHERE p.scope_id = ?1 AND p.is_archived = 0
+                "#,
+            )
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map([scope_id], |row| {
+                let ahead: Option<i32> = row.get(2)?;
+                let behind: Option<i32> = row.get(3)?;
+                Ok(FetchTarget {
+                    project_id: row.get(0)?,
+                    path: row.get(1)?,
+                    previous: ahead.zip(behind),
+                })
+            })
+            .map_err(|e| e.to_string())?
+            .filter_map(|r| r.ok())
+            .collect();
+        rows
+    };
+
+    let mut result = ScopeFetchResult {
+        scope_id: scope_id.to_string(),
+        ..Default::default()
+    };
+
+    for FetchTarget {
+        project_id,
+        path,
+        previous,
+    } in projects
+    {
+        if !Path::new(&path).join(".git").exists() {
+            continue;
+        }
+
+        if let Err(e) = fetch_noninteractive(Path::new(&path), FETCH_TIMEOUT) {
+            tracing::debug!("Background fetch failed for {}: {}", path, e);
+            result.failed += 1;
+            continue;
+        }
+        result.fetched += 1;
+
+        let status = match crate::commands::git::refresh_git_status(
+            app.state::<Database>(),
+            project_id.clone(),
+            path.clone(),
+        ) {
+            Ok(status) => status,
+            Err(e) => {
+                tracing::warn!("Failed to refresh git status for {}: {}", path, e);
+                continue;
+            }
+        };
+        if previous != Some((status.ahead, status.behind)) {
+            result.changed += 1;
+            if let Some(event_bus) = app.try_state::<EventBus>() {
+                event_bus.emit(AppEvent::ProjectGitStatusChanged {
+                    project_id,
+                    scope_id: scope_id.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Run `git fetch` without any credential prompts, killing it after `timeout`.
+///
+/// Terminal and askpass prompts are disabled and ssh runs in batch mode, so
+/// remotes that need interactive authentication fail instead of hanging.
+fn fetch_noninteractive(path: &Path, timeout: Duration) -> Result<(), String> {
+    let ssh_command = batch_ssh_command(path, std::env::var("GIT_SSH_COMMAND").ok());
+
+    let mut child = Command::new("git")
+        .args(["fetch", "--quiet"])
+        .current_dir(path)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .env("GIT_ASKPASS", "")
+        .env("SSH_ASKPASS", "")
+        .env("GIT_SSH_COMMAND", ssh_command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    // Drain stderr on a thread so a chatty remote can't fill the pipe
+    let stderr = child.stderr.take().map(|mut stderr| {
+        std::thread::spawn(move || {
+            let mut output = String::new();
+            let _ = stderr.read_to_string(&mut output);
+            output
+        })
+    });
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(format!("git fetch timed out after {}s", timeout.as_secs()));
+        }
+        std::thread::sleep(Duration::from_millis(200));
+    };
+
+    if status.success() {
+        Ok(())
+    } else {
+        let stderr = stderr
+            .and_then(|handle| handle.join().ok())
+            .unwrap_or_default();
+        Err(stderr.trim().to_string())
+    }
+}
+
+/// Fetch all scopes whose policy is due under the given conditions.
+fn run_due_scopes(app: &AppHandle, conditions: PlatformConditions) -> Result<(), String> {
+    let db = app.state::<Database>();
+    let state = app.state::<FetchSchedulerState>();
+
+    let policies: Vec<ScopeFetchPolicy> = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        let mut stmt = conn
+            .prepare("SELECT id FROM scopes")
+            .map_err(|e| e.to_string())?;
+        let scope_ids: Vec<String> = stmt
+            .query_map([], |row| row.get(0))
+            .map_err(|e| e.to_string())?
+            .filter_map(|r| r.ok())
+            .collect();
+        scope_ids
+            .iter()
+            .map(|id| get_policy(&conn, id))
+            .collect::<Result<_, _>>()?
+    };
+
+    let now = Utc::now();
+    for policy in policies {
+        let last_run = state
+            .last_run
+            .lock()
+            .map_err(|e| e.to_string())?
+            .get(&policy.scope_id)
+            .copied();
+        if !policy.is_due(last_run, now) {
+            continue;
+        }
+        if let Some(reason) = policy.skip_reason(&conditions) {
+            tracing::debug!("Skipping background fetch for scope {}: {}", policy.scope_id, reason);
+            continue;
+        }
+
+        // Record the attempt first so a failing scope isn't retried every minute
+        state
+            .last_run
+            .lock()
+            .map_err(|e| e.to_string())?
+            .insert(policy.scope_id.clone(), now);
+
+        match fetch_scope(app, &policy.scope_id) {
+            Ok(result) => tracing::debug!(
+                "Background fetch for scope {}: {} fetched, {} failed, {} changed",
+                policy.scope_id,
+                result.fetched,
+                result.failed,
+                result.changed
+            ),
+            Err(e) => tracing::warn!("Background fetch for scope {} failed: {}", policy.scope_id, e),
+        }
+    }
+
+    Ok(())
+}
+
+/// Start the scheduler that periodically fetches projects per scope policy.
+pub async fn start_fetch_scheduler(app_handle: AppHandle) {
+    let state = app_handle.state::<FetchSchedulerState>();
+
+    // Check if already running
+    {
+        let mut running = state.running.lock().await;
+        if *running {
+            return;
+        }
+        *running = true;
+    }
+
+    let app = app_handle.clone();
+    let running = state.running.clone();
+
+    tokio::spawn(async move {
+        // Wait a full interval so launch isn't slowed by fetches
+        let mut interval =
+            tokio::time::interval_at(tokio::time::Instant::now() + CHECK_INTERVAL, CHECK_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            {
+                let is_running = running.lock().await;
+                if !*is_running {
+                    break;
+                }
+            }
+
+            let handle = app.clone();
+            // Fetching runs git subprocesses, keep it off the async workers
+            let result = tauri::async_runtime::spawn_blocking(move || {
+                run_due_scopes(&handle, current_conditions())
+            })
+            .await;
+
+            match result {
+                Ok(Err(e)) => tracing::error!("Background fetch error: {}", e),
+                Err(e) => tracing::error!("Background fetch task failed: {}", e),
+                Ok(Ok(())) => {}
+            }
+        }
+    });
+}
+
+/// SSH command for a fetch that must never prompt.
+///
+/// Follows git's own precedence (`GIT_SSH_COMMAND`, then `core.sshCommand`,
+/// then plain `ssh`) so custom keys and proxies keep working, and appends
+/// `BatchMode` to whichever command wins.
+fn batch_ssh_command(repo_path: &Path, env_command: Option<String>) -> String {
+    let ssh_command = env_command
+        .filter(|command| !command.trim().is_empty())
+        .or_else(|| {
+            git2::Repository::open(repo_path)
+                .and_then(|repo| repo.config())
+                .and_then(|config| config.get_string("core.sshCommand"))
+                .ok()
+                .filter(|command| !command.trim().is_empty())
+        })
+        .unwrap_or_else(|| "ssh".to_string());
+
+    format!("{} -o BatchMode=yes", ssh_command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::create_test_database;
+    use std::{env, fs};
+
+    #[test]
+    fn test_policy_roundtrip() {
+        let db = create_test_database();
+        let conn = db.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO scopes (id, name, created_at, updated_at) VALUES ('s1', 'Work', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z')",
+            [],
+        )
+        .unwrap();
+
+        assert_eq!(get_policy(&conn, "s1").unwrap(), ScopeFetchPolicy::default_for("s1"));
+
+        let policy = ScopeFetchPolicy {
+            enabled: true,
+            interval_minutes: 120,
+            ..ScopeFetchPolicy::default_for("s1")
+        };
+        set_policy(&conn, &policy).unwrap();
+        assert_eq!(get_policy(&conn, "s1").unwrap(), policy);
+    }
+
+    #[test]
+    fn test_batch_ssh_command_honors_core_ssh_command() {
+        let dir = env::temp_dir().join("panager_test_fetch_ssh_command");
+        let _ = fs::remove_dir_all(&dir);
+        let repo = git2::Repository::init(&dir).unwrap();
+
+        assert!(batch_ssh_command(&dir, None).ends_with(" -o BatchMode=yes"));
+
+        repo.config()
+            .unwrap()
+            .set_str("core.sshCommand", "ssh -i ~/.ssh/work_key")
+            .unwrap();
+        assert_eq!(
+            batch_ssh_command(&dir, None),
+            "ssh -i ~/.ssh/work_key -o BatchMode=yes"
+        );
+
+        // The environment variable takes precedence, as it does for git
+        assert_eq!(
+            batch_ssh_command(&dir, Some("ssh -p 2222".to_string())),
+            "ssh -p 2222 -o BatchMode=yes"
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
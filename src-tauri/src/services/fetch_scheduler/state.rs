@@ -0,0 +1,16 @@
+//! State management for the background fetch scheduler
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::Mutex;
+
+/// State for the background fetch scheduler
+#[derive(Default)]
+pub struct FetchSchedulerState {
+    /// Whether the scheduler is running
+    pub running: Arc<Mutex<bool>>,
+    /// Last time each scope was fetched
+    pub last_run: Arc<std::sync::Mutex<HashMap<String, DateTime<Utc>>>>,
+}
@@ -1,5 +1,6 @@
 //! Background folder scanner service implementation
 
+use crate::commands::projects::spawn_language_backfill;
 use crate::db::Database;
 use chrono::Utc;
 use std::collections::HashSet;
@@ -136,6 +137,10 @@ fn scan_and_add_repos(app: &AppHandle, scope_id: &str, folder: &str) -> Result<V
         }
     }
 
+    if !added.is_empty() {
+        spawn_language_backfill(app.clone());
+    }
+
     Ok(added)
 }
 
@@ -0,0 +1,117 @@
+//! Project health dashboard.
+//!
+//! This module aggregates per-project signals into a single [`ProjectHealth`]:
+//! - Git status (branch, uncommitted changes, unpushed/behind counts)
+//! - Last commit age
+//! - Diagnostics counts
+//! - Disk size
+//!
+//! Results are cached for [`state::HEALTH_CACHE_TTL`] so the dashboard can
+//! refresh without recomputing every card. Computation runs on a blocking
+//! thread so large projects never stall IPC.
+
+pub mod models;
+pub mod service;
+pub mod state;
+
+pub use models::{HealthDiagnostics, HealthStatus, ProjectHealth};
+pub use service::compute_project_health;
+pub use state::ProjectHealthCache;
+
+use crate::db::repository::{fetch_projects_with_status, find_project_by_id};
+use crate::db::Database;
+use tauri::{AppHandle, Manager, State};
+
+/// Get cached health or compute and cache it.
+fn get_or_compute(
+    db: &Database,
+    cache: &ProjectHealthCache,
+    project: &crate::db::models::Project,
+    force_refresh: bool,
+) -> Result<ProjectHealth, String> {
+    if !force_refresh {
+        if let Some(health) = cache.get(project) {
+            return Ok(health);
+        }
+    }
+
+    let health = compute_project_health(db, project)?;
+    cache.insert(project, health.clone());
+    Ok(health)
+}
+
+// =========================================================================
+// Tauri Commands
+// =========================================================================
+
+/// Get aggregated health for a project.
+///
+/// Cached results are returned unless `force_refresh` is true.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_project_health(
+    app: AppHandle,
+    project_id: String,
+    force_refresh: Option<bool>,
+) -> Result<ProjectHealth, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let db = app.state::<Database>();
+        let project = {
+            let conn = db.conn.lock().map_err(|e| e.to_string())?;
+            find_project_by_id(&conn, &project_id)
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| format!("Project not found: {}", project_id))?
+        };
+
+        get_or_compute(
+            &db,
+            &app.state::<ProjectHealthCache>(),
+            &project,
+            force_refresh.unwrap_or(false),
+        )
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Get aggregated health for every project in a scope (or all projects).
+#[tauri::command]
+#[specta::specta]
+pub async fn get_projects_health(
+    app: AppHandle,
+    scope_id: Option<String>,
+    force_refresh: Option<bool>,
+) -> Result<Vec<ProjectHealth>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let db = app.state::<Database>();
+        let cache = app.state::<ProjectHealthCache>();
+        let projects = {
+            let conn = db.conn.lock().map_err(|e| e.to_string())?;
+            fetch_projects_with_status(&conn, scope_id.as_deref()).map_err(|e| e.to_string())?
+        };
+
+        let force_refresh = force_refresh.unwrap_or(false);
+        projects
+            .iter()
+            .map(|p| get_or_compute(&db, &cache, &p.project, force_refresh))
+            .collect()
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Drop cached health so the next request recomputes it.
+///
+/// Clears a single project when `project_id` is given, otherwise everything.
+#[tauri::command]
+#[specta::specta]
+pub fn invalidate_project_health(
+    cache: State<ProjectHealthCache>,
+    project_id: Option<String>,
+) -> Result<(), String> {
+    match project_id {
+        Some(id) => cache.invalidate(&id),
+        None => cache.clear(),
+    }
+    Ok(())
+}
@@ -0,0 +1,140 @@
+//! Project health models.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Days without a commit before a project is considered stale.
+pub const STALE_COMMIT_DAYS: i64 = 90;
+
+/// Overall health rating for a project card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /// Clean, in sync, no diagnostics
+    Healthy,
+    /// Something needs attention (uncommitted work, unpushed commits, warnings, stale)
+    Warning,
+    /// Folder missing or error-level diagnostics
+    Critical,
+}
+
+/// Non-dismissed diagnostics counts for a single project.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthDiagnostics {
+    pub error_count: i32,
+    pub warning_count: i32,
+    pub info_count: i32,
+}
+
+/// Aggregated health information for a project.
+///
+/// Combines git status, last commit age, diagnostics and disk usage so the
+/// dashboard can render a card from a single call.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectHealth {
+    pub project_id: String,
+    pub scope_id: String,
+    /// Whether the project folder exists on disk
+    pub exists: bool,
+    /// Whether the folder is a git repository
+    pub is_git_repo: bool,
+    pub branch: Option<String>,
+    pub has_uncommitted: bool,
+    pub has_untracked: bool,
+    /// Commits on the current branch not yet pushed to origin
+    pub unpushed_count: i32,
+    /// Commits on origin not yet pulled
+    pub behind_count: i32,
+    pub has_remote: bool,
+    pub last_commit_at: Option<DateTime<Utc>>,
+    /// Whole days since the last commit
+    pub last_commit_age_days: Option<i64>,
+    pub diagnostics: HealthDiagnostics,
+    /// Estimated size of the project's own files, excluding .git, dependency
+    /// and build folders (None if missing or too large to measure quickly)
+    pub disk_size_bytes: Option<u64>,
+    pub status: HealthStatus,
+    pub computed_at: DateTime<Utc>,
+}
+
+impl ProjectHealth {
+    /// Derive the overall status from the collected signals.
+    pub fn evaluate_status(&self) -> HealthStatus {
+        if !self.exists || self.diagnostics.error_count > 0 {
+            return HealthStatus::Critical;
+        }
+
+        let stale = self
+            .last_commit_age_days
+            .map(|days| days >= STALE_COMMIT_DAYS)
+            .unwrap_or(false);
+
+        if self.has_uncommitted
+            || self.unpushed_count > 0
+            || self.behind_count > 0
+            || self.diagnostics.warning_count > 0
+            || stale
+        {
+            return HealthStatus::Warning;
+        }
+
+        HealthStatus::Healthy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn healthy() -> ProjectHealth {
+        ProjectHealth {
+            project_id: "p1".to_string(),
+            scope_id: "s1".to_string(),
+            exists: true,
+            is_git_repo: true,
+            branch: Some("main".to_string()),
+            has_uncommitted: false,
+            has_untracked: false,
+            unpushed_count: 0,
+            behind_count: 0,
+            has_remote: true,
+            last_commit_at: Some(Utc::now()),
+            last_commit_age_days: Some(1),
+            diagnostics: HealthDiagnostics::default(),
+            disk_size_bytes: Some(1024),
+            status: HealthStatus::Healthy,
+            computed_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_evaluate_status_healthy() {
+        assert_eq!(healthy().evaluate_status(), HealthStatus::Healthy);
+    }
+
+    #[test]
+    fn test_evaluate_status_warning() {
+        let mut health = healthy();
+        health.unpushed_count = 2;
+        assert_eq!(health.evaluate_status(), HealthStatus::Warning);
+
+        let mut health = healthy();
+        health.last_commit_age_days = Some(STALE_COMMIT_DAYS);
+        assert_eq!(health.evaluate_status(), HealthStatus::Warning);
+    }
+
+    #[test]
+    fn test_evaluate_status_critical() {
+        let mut health = healthy();
+        health.exists = false;
+        assert_eq!(health.evaluate_status(), HealthStatus::Critical);
+
+        let mut health = healthy();
+        health.has_uncommitted = true;
+        health.diagnostics.error_count = 1;
+        assert_eq!(health.evaluate_status(), HealthStatus::Critical);
+    }
+}
@@ -0,0 +1,80 @@
+//! Project health computation.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use git2::Repository;
+
+use crate::commands::git::get_git_status;
+use crate::db::models::Project;
+use crate::db::Database;
+use crate::services::diagnostics::DiagnosticsRepository;
+use crate::utils::fs::estimate_dir_size;
+
+use super::models::{HealthDiagnostics, HealthStatus, ProjectHealth};
+
+/// Most entries visited when estimating a project's disk size.
+const DISK_SIZE_MAX_ENTRIES: usize = 50_000;
+
+/// Compute the health of a single project.
+///
+/// Git and filesystem failures are not errors: the corresponding fields are
+/// left empty so one broken repository doesn't fail the whole dashboard.
+pub fn compute_project_health(db: &Database, project: &Project) -> Result<ProjectHealth, String> {
+    let path = Path::new(&project.path);
+    let exists = path.exists();
+    let now = Utc::now();
+
+    let git_status = if exists {
+        get_git_status(project.path.clone()).ok()
+    } else {
+        None
+    };
+    let last_commit_at = if git_status.is_some() {
+        get_last_commit_time(path)
+    } else {
+        None
+    };
+
+    let diagnostics = DiagnosticsRepository::get_project_counts(db, &project.id)?;
+
+    let mut health = ProjectHealth {
+        project_id: project.id.clone(),
+        scope_id: project.scope_id.clone(),
+        exists,
+        is_git_repo: git_status.is_some(),
+        branch: git_status.as_ref().and_then(|s| s.branch.clone()),
+        has_uncommitted: git_status.as_ref().map(|s| s.has_uncommitted).unwrap_or(false),
+        has_untracked: git_status.as_ref().map(|s| s.has_untracked).unwrap_or(false),
+        unpushed_count: git_status.as_ref().map(|s| s.ahead).unwrap_or(0),
+        behind_count: git_status.as_ref().map(|s| s.behind).unwrap_or(0),
+        has_remote: git_status
+            .as_ref()
+            .map(|s| s.remote_url.is_some())
+            .unwrap_or(false),
+        last_commit_at,
+        last_commit_age_days: last_commit_at.map(|at| (now - at).num_days().max(0)),
+        diagnostics: HealthDiagnostics {
+            error_count: diagnostics.0,
+            warning_count: diagnostics.1,
+            info_count: diagnostics.2,
+        },
+        disk_size_bytes: if exists {
+            estimate_dir_size(path, DISK_SIZE_MAX_ENTRIES)
+        } else {
+            None
+        },
+        status: HealthStatus::Healthy,
+        computed_at: now,
+    };
+    health.status = health.evaluate_status();
+
+    Ok(health)
+}
+
+/// Time of the commit HEAD points to.
+fn get_last_commit_time(path: &Path) -> Option<DateTime<Utc>> {
+    let repo = Repository::open(path).ok()?;
+    let commit = repo.head().ok()?.peel_to_commit().ok()?;
+    DateTime::from_timestamp(commit.time().seconds(), 0)
+}
@@ -0,0 +1,78 @@
+//! Project health cache.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::models::ProjectHealth;
+use crate::db::models::Project;
+
+/// How long a computed health entry is reused before recomputing.
+pub const HEALTH_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// A cached health entry and the project location it was computed for.
+struct CacheEntry {
+    computed: Instant,
+    path: String,
+    health: ProjectHealth,
+}
+
+/// In-memory cache of computed project health, keyed by project ID.
+///
+/// Computing health walks the project folder and opens the repository, so
+/// dashboard refreshes reuse recent results instead of recomputing per card.
+/// Entries are dropped by the health event handler when a project's git
+/// status, path, scope or diagnostics change, and are never returned for a
+/// project that has since moved.
+#[derive(Default)]
+pub struct ProjectHealthCache {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl ProjectHealthCache {
+    /// Get a cached entry if it is younger than the TTL and still matches
+    /// the project's path and scope.
+    pub fn get(&self, project: &Project) -> Option<ProjectHealth> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .get(&project.id)
+            .filter(|entry| entry.computed.elapsed() < HEALTH_CACHE_TTL)
+            .filter(|entry| entry.path == project.path && entry.health.scope_id == project.scope_id)
+            .map(|entry| entry.health.clone())
+    }
+
+    /// Store a freshly computed entry.
+    pub fn insert(&self, project: &Project, health: ProjectHealth) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(
+                project.id.clone(),
+                CacheEntry {
+                    computed: Instant::now(),
+                    path: project.path.clone(),
+                    health,
+                },
+            );
+        }
+    }
+
+    /// Drop cached entries for every project in a scope.
+    pub fn invalidate_scope(&self, scope_id: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|_, entry| entry.health.scope_id != scope_id);
+        }
+    }
+
+    /// Drop the cached entry for a project.
+    pub fn invalidate(&self, project_id: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(project_id);
+        }
+    }
+
+    /// Drop all cached entries.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
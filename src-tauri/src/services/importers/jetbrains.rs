@@ -0,0 +1,96 @@
+//! JetBrains IDE recent projects (`options/recentProjects.xml`).
+//!
+//! Every installed product/version has its own config folder, e.g.
+//! `JetBrains/IntelliJIdea2024.3` or `JetBrains/WebStorm2024.2`, all of
+//! which are scanned.
+
+use std::path::{Path, PathBuf};
+
+use crate::utils::regex::{JETBRAINS_RECENT_ENTRY_REGEX, JETBRAINS_RECENT_OPTION_REGEX};
+
+/// Read recent project folders from all installed JetBrains IDEs.
+pub fn read_recent_folders() -> Vec<PathBuf> {
+    let Some(config_dir) = directories::BaseDirs::new().map(|d| d.config_dir().to_path_buf()) else {
+        return Vec::new();
+    };
+    let home = home::home_dir().unwrap_or_default();
+
+    let Ok(products) = std::fs::read_dir(config_dir.join("JetBrains")) else {
+        return Vec::new();
+    };
+
+    products
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path().join("options").join("recentProjects.xml"))
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .flat_map(|xml| parse_recent_projects(&xml, &home))
+        .collect()
+}
+
+/// Extract project paths from `recentProjects.xml`.
+///
+/// Newer IDEs list projects as `<entry key="...">` in the `additionalInfo`
+/// map; older ones use `<option value="..."/>` in `recentPaths`.
+pub fn parse_recent_projects(xml: &str, home: &Path) -> Vec<PathBuf> {
+    let home = home.to_string_lossy();
+
+    JETBRAINS_RECENT_ENTRY_REGEX
+        .captures_iter(xml)
+        .chain(JETBRAINS_RECENT_OPTION_REGEX.captures_iter(xml))
+        .filter_map(|caps| caps.get(1))
+        .map(|m| unescape_xml(m.as_str()).replace("$USER_HOME$", &home))
+        .filter(|p| looks_like_path(p))
+        .map(PathBuf::from)
+        .collect()
+}
+
+fn looks_like_path(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    value.starts_with('/') || (bytes.len() > 2 && bytes[1] == b':' && bytes[0].is_ascii_alphabetic())
+}
+
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_recent_projects() {
+        let xml = r#"<application>
+  <component name="RecentProjectsManager">
+    <option name="additionalInfo">
+      <map>
+        <entry key="$USER_HOME$/IdeaProjects/api">
+          <value><RecentProjectMetaInfo frameTitle="api" /></value>
+        </entry>
+        <entry key="/opt/src/R&amp;D" />
+      </map>
+    </option>
+    <option name="lastProjectLocation" value="$USER_HOME$/IdeaProjects" />
+    <option name="recentPaths">
+      <list>
+        <option value="C:/work/legacy" />
+      </list>
+    </option>
+  </component>
+</application>"#;
+
+        let paths = parse_recent_projects(xml, Path::new("/home/dev"));
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("/home/dev/IdeaProjects/api"),
+                PathBuf::from("/opt/src/R&D"),
+                PathBuf::from("C:/work/legacy"),
+            ]
+        );
+    }
+}
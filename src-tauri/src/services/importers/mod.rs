@@ -0,0 +1,230 @@
+//! Import projects from other editors' recent lists.
+//!
+//! Supported sources:
+//! - VS Code and Cursor (`state.vscdb` / `storage.json`)
+//! - JetBrains IDEs (`recentProjects.xml`)
+//! - Sublime Text (`Session.sublime_session`)
+//!
+//! Candidates are deduplicated across sources and against paths that are
+//! already registered, and a scope is suggested from scope default folders.
+
+pub mod jetbrains;
+pub mod models;
+pub mod sublime;
+pub mod vscode;
+
+pub use models::{ExternalProjectImport, ImportCandidate, ImportResult, ImportSource};
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use rusqlite::Connection;
+use tauri::State;
+use uuid::Uuid;
+
+use crate::db::Database;
+use crate::events::{AppEvent, EventBus};
+
+/// Read recent folders from one source.
+fn read_source(source: ImportSource) -> Vec<PathBuf> {
+    match source {
+        ImportSource::VsCode => vscode::read_recent_folders("Code"),
+        ImportSource::Cursor => vscode::read_recent_folders("Cursor"),
+        ImportSource::JetBrains => jetbrains::read_recent_folders(),
+        ImportSource::Sublime => sublime::read_recent_folders(),
+    }
+}
+
+/// Paths of all registered projects (including archived ones).
+fn existing_project_paths(conn: &Connection) -> Result<Vec<String>, String> {
+    let mut stmt = conn
+        .prepare("SELECT path FROM projects")
+        .map_err(|e| e.to_string())?;
+    let paths = stmt
+        .query_map([], |row| row.get::<_, String>(0))
+        .map_err(|e| e.to_string())?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(paths)
+}
+
+/// Duplicate-detection keys for registered paths.
+///
+/// Touches the filesystem, so call it without holding the database lock.
+fn path_keys(paths: &[String]) -> HashSet<String> {
+    paths.iter().map(|p| normalize(p)).collect()
+}
+
+/// Scope IDs and their default folders, longest folder first so the most
+/// specific scope wins when folders are nested.
+fn scope_folders(conn: &Connection) -> Result<Vec<(String, PathBuf)>, String> {
+    let mut stmt = conn
+        .prepare("SELECT id, default_folder FROM scopes WHERE default_folder IS NOT NULL")
+        .map_err(|e| e.to_string())?;
+    let mut folders: Vec<(String, PathBuf)> = stmt
+        .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+        .map_err(|e| e.to_string())?
+        .filter_map(|r| r.ok())
+        .map(|(id, folder)| (id, PathBuf::from(crate::utils::paths::expand_tilde(&folder))))
+        .collect();
+    folders.sort_by_key(|(_, folder)| std::cmp::Reverse(folder.as_os_str().len()));
+    Ok(folders)
+}
+
+/// Normalize a path for duplicate detection.
+///
+/// Existing folders are canonicalized so symlinks, `..` segments and `~`
+/// resolve to the same key; missing ones fall back to trimming slashes.
+fn normalize(path: &str) -> String {
+    let expanded = crate::utils::paths::expand_tilde(path);
+    std::fs::canonicalize(&expanded)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| crate::utils::paths::normalize_trailing_slash(&expanded).to_string())
+}
+
+fn folder_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string())
+}
+
+// =========================================================================
+// Tauri Commands
+// =========================================================================
+
+/// List folders from other editors' recent lists that could be imported.
+///
+/// Only folders that still exist are returned. Pass `sources` to limit the
+/// scan; by default every supported source is read.
+#[tauri::command]
+#[specta::specta]
+pub fn get_external_project_candidates(
+    db: State<Database>,
+    sources: Option<Vec<ImportSource>>,
+) -> Result<Vec<ImportCandidate>, String> {
+    let sources = sources.unwrap_or_else(|| {
+        vec![
+            ImportSource::VsCode,
+            ImportSource::Cursor,
+            ImportSource::JetBrains,
+            ImportSource::Sublime,
+        ]
+    });
+
+    // Merge by path, keeping every source that lists it
+    let mut found: BTreeMap<String, (PathBuf, Vec<ImportSource>)> = BTreeMap::new();
+    for source in sources {
+        for path in read_source(source) {
+            if !path.is_dir() {
+                continue;
+            }
+            let key = normalize(&path.to_string_lossy());
+            let entry = found.entry(key).or_insert_with(|| (path, Vec::new()));
+            if !entry.1.contains(&source) {
+                entry.1.push(source);
+            }
+        }
+    }
+
+    let (existing, scopes) = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        (existing_project_paths(&conn)?, scope_folders(&conn)?)
+    };
+    let existing = path_keys(&existing);
+
+    Ok(found
+        .into_iter()
+        .map(|(key, (path, sources))| ImportCandidate {
+            name: folder_name(&path),
+            suggested_scope_id: scopes
+                .iter()
+                .find(|(_, folder)| path.starts_with(folder))
+                .map(|(id, _)| id.clone()),
+            already_added: existing.contains(&key),
+            path: path.to_string_lossy().to_string(),
+            sources,
+        })
+        .collect())
+}
+
+/// Register selected external folders as projects.
+///
+/// Paths that are already registered or no longer exist are skipped. The
+/// remaining projects are inserted in one transaction, so a failure leaves
+/// nothing half-imported.
+#[tauri::command]
+#[specta::specta]
+pub fn import_external_projects(
+    db: State<Database>,
+    event_bus: State<EventBus>,
+    imports: Vec<ExternalProjectImport>,
+) -> Result<ImportResult, String> {
+    let mut result = ImportResult::default();
+
+    let existing = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        existing_project_paths(&conn)?
+    };
+    let mut existing = path_keys(&existing);
+
+    // Filesystem checks happen before the database lock is taken
+    let mut pending = Vec::new();
+    for import in imports {
+        let key = normalize(&import.path);
+        if existing.contains(&key) {
+            result.skipped_existing.push(import.path);
+            continue;
+        }
+        if !Path::new(&import.path).is_dir() {
+            result.skipped_missing.push(import.path);
+            continue;
+        }
+        existing.insert(key);
+        pending.push(import);
+    }
+
+    let mut added: Vec<(String, String)> = Vec::new();
+    {
+        let mut conn = db.conn.lock().map_err(|e| e.to_string())?;
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+        let now = Utc::now().to_rfc3339();
+
+        for import in pending {
+            let id = Uuid::new_v4().to_string();
+            let name = import
+                .name
+                .unwrap_or_else(|| folder_name(Path::new(&import.path)));
+            tx.execute(
+                r#"
+                INSERT INTO projects (id, scope_id, name, path, is_temp, created_at, updated_at)
+                VALUES (?1, ?2, ?3, ?4, 0, ?5, ?6)
+                "#,
+                (&id, &import.scope_id, &name, &import.path, &now, &now),
+            )
+            .map_err(|e| format!("Failed to import {}: {}", import.path, e))?;
+
+            added.push((id, import.scope_id));
+        }
+
+        tx.commit().map_err(|e| e.to_string())?;
+    }
+
+    result.imported = added.iter().map(|(id, _)| id.clone()).collect();
+
+    for (project_id, scope_id) in added {
+        event_bus.emit(AppEvent::ProjectAdded {
+            project_id,
+            scope_id,
+        });
+    }
+
+    tracing::info!(
+        "Imported {} external projects ({} already registered, {} missing)",
+        result.imported.len(),
+        result.skipped_existing.len(),
+        result.skipped_missing.len()
+    );
+
+    Ok(result)
+}
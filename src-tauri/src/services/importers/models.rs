@@ -0,0 +1,61 @@
+//! External project import models.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Application a project was discovered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportSource {
+    VsCode,
+    Cursor,
+    JetBrains,
+    Sublime,
+}
+
+impl ImportSource {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ImportSource::VsCode => "VS Code",
+            ImportSource::Cursor => "Cursor",
+            ImportSource::JetBrains => "JetBrains",
+            ImportSource::Sublime => "Sublime Text",
+        }
+    }
+}
+
+/// A folder found in another application's recent projects.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportCandidate {
+    pub path: String,
+    pub name: String,
+    /// Every application that lists this folder
+    pub sources: Vec<ImportSource>,
+    /// Scope whose default folder contains this path, if any
+    pub suggested_scope_id: Option<String>,
+    /// Whether a project with this path is already registered
+    pub already_added: bool,
+}
+
+/// A candidate the user chose to import into a scope.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalProjectImport {
+    pub path: String,
+    pub scope_id: String,
+    /// Project name (defaults to the folder name)
+    pub name: Option<String>,
+}
+
+/// Outcome of an import run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportResult {
+    /// IDs of newly created projects
+    pub imported: Vec<String>,
+    /// Paths skipped because a project with that path already exists
+    pub skipped_existing: Vec<String>,
+    /// Paths skipped because the folder no longer exists
+    pub skipped_missing: Vec<String>,
+}
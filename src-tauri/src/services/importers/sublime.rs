@@ -0,0 +1,81 @@
+//! Sublime Text session folders (`Local/Session.sublime_session`).
+
+use std::path::PathBuf;
+
+/// Read folders from the Sublime Text session file.
+pub fn read_recent_folders() -> Vec<PathBuf> {
+    let Some(config_dir) = directories::BaseDirs::new().map(|d| d.config_dir().to_path_buf()) else {
+        return Vec::new();
+    };
+
+    // macOS/Windows use "Sublime Text", Linux uses "sublime-text"
+    ["Sublime Text", "sublime-text", "Sublime Text 3", "sublime-text-3"]
+        .iter()
+        .map(|dir| config_dir.join(dir).join("Local").join("Session.sublime_session"))
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .flat_map(|json| parse_session(&json))
+        .collect()
+}
+
+/// Extract folder paths from a session file.
+///
+/// Uses `folder_history` plus the folders of every open window.
+pub fn parse_session(json: &str) -> Vec<PathBuf> {
+    let Ok(session) = serde_json::from_str::<serde_json::Value>(json) else {
+        return Vec::new();
+    };
+
+    let history = session
+        .get("folder_history")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.as_str());
+
+    let windows = session
+        .get("windows")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|w| w.get("folders").and_then(|f| f.as_array()))
+        .flatten()
+        .filter_map(|f| f.get("path").and_then(|p| p.as_str()));
+
+    history.chain(windows).map(normalize_path).collect()
+}
+
+/// Sublime writes Windows paths as `/C/Users/...`.
+fn normalize_path(path: &str) -> PathBuf {
+    let bytes = path.as_bytes();
+    if cfg!(windows) && bytes.len() > 2 && bytes[0] == b'/' && bytes[2] == b'/' {
+        return PathBuf::from(format!("{}:{}", &path[1..2], &path[2..]));
+    }
+    PathBuf::from(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_session() {
+        let json = r#"{
+            "folder_history": ["/Users/dev/site", "/Users/dev/tools"],
+            "windows": [
+                { "folders": [{ "path": "/Users/dev/site" }, { "path": "/Users/dev/blog" }] },
+                { "buffers": [] }
+            ]
+        }"#;
+
+        let folders = parse_session(json);
+        assert_eq!(
+            folders,
+            vec![
+                PathBuf::from("/Users/dev/site"),
+                PathBuf::from("/Users/dev/tools"),
+                PathBuf::from("/Users/dev/site"),
+                PathBuf::from("/Users/dev/blog"),
+            ]
+        );
+    }
+}
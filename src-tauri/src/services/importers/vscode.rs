@@ -0,0 +1,136 @@
+//! VS Code (and Cursor) recently opened folders.
+//!
+//! Current versions keep the list in `User/globalStorage/state.vscdb`
+//! (SQLite, key `history.recentlyOpenedPathsList`); older versions used
+//! `User/globalStorage/storage.json` (key `openedPathsList`).
+
+use std::path::{Path, PathBuf};
+
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+
+const RECENT_KEY: &str = "history.recentlyOpenedPathsList";
+
+/// Read recent folders for a VS Code-family editor.
+///
+/// `app_dir_name` is the folder under the config dir, e.g. `Code` or `Cursor`.
+pub fn read_recent_folders(app_dir_name: &str) -> Vec<PathBuf> {
+    let Some(config_dir) = directories::BaseDirs::new().map(|d| d.config_dir().to_path_buf()) else {
+        return Vec::new();
+    };
+    let storage = config_dir.join(app_dir_name).join("User").join("globalStorage");
+
+    let json = read_state_db(&storage.join("state.vscdb")).or_else(|| {
+        std::fs::read_to_string(storage.join("storage.json"))
+            .ok()
+            .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+            .and_then(|v| v.get("openedPathsList").map(|v| v.to_string()))
+    });
+
+    json.map(|j| parse_recent_folders(&j)).unwrap_or_default()
+}
+
+/// Read the recent list JSON from `state.vscdb` without locking it for the editor.
+fn read_state_db(path: &Path) -> Option<String> {
+    if !path.exists() {
+        return None;
+    }
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY).ok()?;
+    conn.query_row(
+        "SELECT value FROM ItemTable WHERE key = ?1",
+        [RECENT_KEY],
+        |row| row.get::<_, String>(0),
+    )
+    .optional()
+    .ok()
+    .flatten()
+}
+
+/// Parse `{"entries": [{"folderUri": "file:///..."}, ...]}` into local folder paths.
+///
+/// Remote (`vscode-remote://`) and workspace-file entries are ignored.
+pub fn parse_recent_folders(json: &str) -> Vec<PathBuf> {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(json) else {
+        return Vec::new();
+    };
+
+    value
+        .get("entries")
+        .and_then(|e| e.as_array())
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|entry| entry.get("folderUri").and_then(|u| u.as_str()))
+                .filter_map(file_uri_to_path)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Convert a `file://` URI into a local path.
+fn file_uri_to_path(uri: &str) -> Option<PathBuf> {
+    let path = percent_decode(uri.strip_prefix("file://")?);
+
+    // Windows URIs look like file:///c%3A/Users/...
+    let bytes = path.as_bytes();
+    if bytes.len() > 3 && bytes[0] == b'/' && bytes[2] == b':' {
+        return Some(PathBuf::from(&path[1..]));
+    }
+    Some(PathBuf::from(path))
+}
+
+/// Decode `%XX` escapes in a URI path.
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = (hex_value(bytes[i + 1]), hex_value(bytes[i + 2]));
+            if let (Some(high), Some(low)) = hex {
+                out.push(high << 4 | low);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&out).to_string()
+}
+
+fn hex_value(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|d| d as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_recent_folders() {
+        let json = r#"{"entries":[
+            {"folderUri":"file:///Users/dev/work/my%20app"},
+            {"workspace":{"id":"1","configPath":"file:///Users/dev/a.code-workspace"}},
+            {"folderUri":"vscode-remote://ssh-remote%2Bbox/home/dev/api"},
+            {"fileUri":"file:///Users/dev/notes.md"},
+            {"folderUri":"file:///c%3A/Users/dev/win"}
+        ]}"#;
+
+        let folders = parse_recent_folders(json);
+        assert_eq!(
+            folders,
+            vec![
+                PathBuf::from("/Users/dev/work/my app"),
+                PathBuf::from("c:/Users/dev/win"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_json() {
+        assert!(parse_recent_folders("not json").is_empty());
+        assert!(parse_recent_folders("{}").is_empty());
+    }
+}
@@ -3,6 +3,16 @@
 //! This module contains background services that run independently
 //! of user interactions.
 
+pub mod activity;
+pub mod archive;
+pub mod backup;
+pub mod batch;
 pub mod cleanup;
+pub mod clone_queue;
 pub mod diagnostics;
+pub mod fetch_scheduler;
 pub mod folder_scanner;
+pub mod health;
+pub mod importers;
+pub mod providers;
+pub mod telemetry;
@@ -0,0 +1,290 @@
+//! GitHub (and GitHub Enterprise Server) API.
+
+use serde::Deserialize;
+
+use super::models::{CiState, Pipeline, PullRequestSummary, RemoteRepository};
+use super::service::{self, MAX_PAGES, PER_PAGE};
+
+/// OAuth scopes requested at sign-in (private repos and org membership).
+pub const SCOPES: &str = "repo read:org";
+
+pub fn device_code_url(host: &str) -> String {
+    format!("https://{}/login/device/code", host)
+}
+
+pub fn token_url(host: &str) -> String {
+    format!("https://{}/login/oauth/access_token", host)
+}
+
+fn api_base(host: &str) -> String {
+    if host == "github.com" {
+        "https://api.github.com".to_string()
+    } else {
+        format!("https://{}/api/v3", host)
+    }
+}
+
+#[derive(Deserialize)]
+struct User {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct Owner {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct Repo {
+    name: String,
+    full_name: String,
+    owner: Owner,
+    description: Option<String>,
+    clone_url: String,
+    ssh_url: String,
+    default_branch: Option<String>,
+    private: bool,
+    fork: bool,
+    #[serde(default)]
+    archived: bool,
+    updated_at: Option<String>,
+}
+
+impl From<Repo> for RemoteRepository {
+    fn from(repo: Repo) -> Self {
+        RemoteRepository {
+            owner: repo.owner.login,
+            name: repo.name,
+            full_name: repo.full_name,
+            description: repo.description,
+            https_url: repo.clone_url,
+            ssh_url: repo.ssh_url,
+            default_branch: repo.default_branch,
+            is_private: repo.private,
+            is_fork: repo.fork,
+            is_archived: repo.archived,
+            updated_at: repo.updated_at,
+            local_project_id: None,
+            local_path: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct BranchRef {
+    #[serde(rename = "ref")]
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct PullRequest {
+    number: u32,
+    title: String,
+    user: Option<Owner>,
+    html_url: String,
+    #[serde(default)]
+    draft: bool,
+    head: BranchRef,
+    base: BranchRef,
+    updated_at: Option<String>,
+}
+
+impl From<PullRequest> for PullRequestSummary {
+    fn from(pr: PullRequest) -> Self {
+        PullRequestSummary {
+            number: pr.number,
+            title: pr.title,
+            author: pr.user.map(|u| u.login),
+            url: pr.html_url,
+            is_draft: pr.draft,
+            source_branch: Some(pr.head.name),
+            target_branch: Some(pr.base.name),
+            updated_at: pr.updated_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct WorkflowRuns {
+    workflow_runs: Vec<WorkflowRun>,
+}
+
+#[derive(Deserialize)]
+struct WorkflowRun {
+    status: Option<String>,
+    conclusion: Option<String>,
+    html_url: Option<String>,
+    head_branch: Option<String>,
+    updated_at: Option<String>,
+}
+
+/// Map an Actions run's status/conclusion to a [`CiState`].
+fn ci_state(status: Option<&str>, conclusion: Option<&str>) -> Option<CiState> {
+    match status? {
+        "completed" => match conclusion? {
+            "success" => Some(CiState::Success),
+            "failure" | "timed_out" | "startup_failure" => Some(CiState::Failed),
+            "cancelled" => Some(CiState::Cancelled),
+            "skipped" | "neutral" | "stale" => Some(CiState::Skipped),
+            "action_required" => Some(CiState::Pending),
+            _ => None,
+        },
+        "in_progress" => Some(CiState::Running),
+        _ => Some(CiState::Pending),
+    }
+}
+
+async fn get<T: serde::de::DeserializeOwned>(
+    client: &reqwest::Client,
+    url: &str,
+    token: &str,
+) -> Result<T, String> {
+    let response = client
+        .get(url)
+        .bearer_auth(token)
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(service::request_error("GitHub", &response));
+    }
+    response.json().await.map_err(|e| e.to_string())
+}
+
+/// Login of the signed-in user.
+pub async fn current_user(client: &reqwest::Client, host: &str, token: &str) -> Result<String, String> {
+    let user: User = get(client, &format!("{}/user", api_base(host)), token).await?;
+    Ok(user.login)
+}
+
+/// Repositories the user owns, collaborates on or can access through an
+/// organization, most recently updated first.
+pub async fn list_repositories(
+    client: &reqwest::Client,
+    host: &str,
+    token: &str,
+) -> Result<Vec<RemoteRepository>, String> {
+    let mut repos = Vec::new();
+    for page in 1..=MAX_PAGES {
+        let url = format!(
+            "{}/user/repos?affiliation=owner,collaborator,organization_member&sort=updated&per_page={}&page={}",
+            api_base(host),
+            PER_PAGE,
+            page
+        );
+        let batch: Vec<Repo> = get(client, &url, token).await?;
+        let done = batch.len() < PER_PAGE as usize;
+        repos.extend(batch.into_iter().map(RemoteRepository::from));
+        if done {
+            break;
+        }
+    }
+    Ok(repos)
+}
+
+/// Organizations the user belongs to.
+pub async fn list_owners(client: &reqwest::Client, host: &str, token: &str) -> Result<Vec<String>, String> {
+    let orgs: Vec<Owner> = get(
+        client,
+        &format!("{}/user/orgs?per_page={}", api_base(host), PER_PAGE),
+        token,
+    )
+    .await?;
+    Ok(orgs.into_iter().map(|org| org.login).collect())
+}
+
+/// Open pull requests of a repository ("owner/repo").
+pub async fn list_open_pull_requests(
+    client: &reqwest::Client,
+    host: &str,
+    token: &str,
+    repo: &str,
+) -> Result<Vec<PullRequestSummary>, String> {
+    let mut prs = Vec::new();
+    for page in 1..=MAX_PAGES {
+        let url = format!(
+            "{}/repos/{}/pulls?state=open&per_page={}&page={}",
+            api_base(host),
+            repo,
+            PER_PAGE,
+            page
+        );
+        let batch: Vec<PullRequest> = get(client, &url, token).await?;
+        let done = batch.len() < PER_PAGE as usize;
+        prs.extend(batch.into_iter().map(PullRequestSummary::from));
+        if done {
+            break;
+        }
+    }
+    Ok(prs)
+}
+
+/// Latest GitHub Actions run, optionally limited to a branch.
+pub async fn latest_pipeline(
+    client: &reqwest::Client,
+    host: &str,
+    token: &str,
+    repo: &str,
+    branch: Option<&str>,
+) -> Result<Option<Pipeline>, String> {
+    let mut params = vec![("per_page", "1")];
+    if let Some(branch) = branch {
+        params.push(("branch", branch));
+    }
+    let url = reqwest::Url::parse_with_params(
+        &format!("{}/repos/{}/actions/runs", api_base(host), repo),
+        &params,
+    )
+    .map_err(|e| e.to_string())?;
+
+    let runs: WorkflowRuns = get(client, url.as_str(), token).await?;
+    Ok(runs.workflow_runs.into_iter().next().map(|run| Pipeline {
+        state: ci_state(run.status.as_deref(), run.conclusion.as_deref()),
+        branch: run.head_branch,
+        url: run.html_url,
+        updated_at: run.updated_at,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_base() {
+        assert_eq!(api_base("github.com"), "https://api.github.com");
+        assert_eq!(api_base("git.example.com"), "https://git.example.com/api/v3");
+    }
+
+    #[test]
+    fn test_ci_state() {
+        assert_eq!(ci_state(Some("completed"), Some("success")), Some(CiState::Success));
+        assert_eq!(ci_state(Some("completed"), Some("timed_out")), Some(CiState::Failed));
+        assert_eq!(ci_state(Some("in_progress"), None), Some(CiState::Running));
+        assert_eq!(ci_state(Some("queued"), None), Some(CiState::Pending));
+        assert_eq!(ci_state(None, None), None);
+    }
+
+    #[test]
+    fn test_repo_conversion() {
+        let json = r#"{
+            "name": "app",
+            "full_name": "acme/app",
+            "owner": { "login": "acme" },
+            "description": null,
+            "clone_url": "https://github.com/acme/app.git",
+            "ssh_url": "git@github.com:acme/app.git",
+            "default_branch": "main",
+            "private": true,
+            "fork": false,
+            "updated_at": "2024-05-01T10:00:00Z"
+        }"#;
+        let repo: RemoteRepository = serde_json::from_str::<Repo>(json).unwrap().into();
+
+        assert_eq!(repo.owner, "acme");
+        assert_eq!(repo.full_name, "acme/app");
+        assert!(repo.is_private);
+        assert!(!repo.is_archived);
+    }
+}
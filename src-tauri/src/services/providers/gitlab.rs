@@ -0,0 +1,278 @@
+//! GitLab (gitlab.com and self-managed) API.
+
+use serde::Deserialize;
+
+use super::models::{CiState, Pipeline, PullRequestSummary, RemoteRepository};
+use super::service::{self, MAX_PAGES, PER_PAGE};
+
+/// OAuth scopes requested at sign-in.
+pub const SCOPES: &str = "read_api read_repository";
+
+pub fn device_code_url(host: &str) -> String {
+    format!("https://{}/oauth/authorize_device", host)
+}
+
+pub fn token_url(host: &str) -> String {
+    format!("https://{}/oauth/token", host)
+}
+
+fn api_base(host: &str) -> String {
+    format!("https://{}/api/v4", host)
+}
+
+#[derive(Deserialize)]
+struct User {
+    username: String,
+}
+
+#[derive(Deserialize)]
+struct Namespace {
+    full_path: String,
+}
+
+#[derive(Deserialize)]
+struct Group {
+    full_path: String,
+}
+
+#[derive(Deserialize)]
+struct Project {
+    path: String,
+    path_with_namespace: String,
+    namespace: Namespace,
+    description: Option<String>,
+    http_url_to_repo: String,
+    ssh_url_to_repo: String,
+    default_branch: Option<String>,
+    visibility: Option<String>,
+    /// Only present for forks
+    forked_from_project: Option<serde_json::Value>,
+    #[serde(default)]
+    archived: bool,
+    last_activity_at: Option<String>,
+}
+
+impl From<Project> for RemoteRepository {
+    fn from(project: Project) -> Self {
+        RemoteRepository {
+            owner: project.namespace.full_path,
+            name: project.path,
+            full_name: project.path_with_namespace,
+            description: project.description.filter(|d| !d.is_empty()),
+            https_url: project.http_url_to_repo,
+            ssh_url: project.ssh_url_to_repo,
+            default_branch: project.default_branch,
+            // Internal projects are not public either
+            is_private: project.visibility.as_deref() != Some("public"),
+            is_fork: project.forked_from_project.is_some_and(|p| !p.is_null()),
+            is_archived: project.archived,
+            updated_at: project.last_activity_at,
+            local_project_id: None,
+            local_path: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct MergeRequest {
+    iid: u32,
+    title: String,
+    author: Option<User>,
+    web_url: String,
+    #[serde(default)]
+    draft: bool,
+    source_branch: Option<String>,
+    target_branch: Option<String>,
+    updated_at: Option<String>,
+}
+
+impl From<MergeRequest> for PullRequestSummary {
+    fn from(mr: MergeRequest) -> Self {
+        PullRequestSummary {
+            number: mr.iid,
+            title: mr.title,
+            author: mr.author.map(|u| u.username),
+            url: mr.web_url,
+            is_draft: mr.draft,
+            source_branch: mr.source_branch,
+            target_branch: mr.target_branch,
+            updated_at: mr.updated_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct PipelineRun {
+    status: String,
+    #[serde(rename = "ref")]
+    branch: Option<String>,
+    web_url: Option<String>,
+    updated_at: Option<String>,
+}
+
+/// Map a pipeline status to a [`CiState`].
+fn ci_state(status: &str) -> Option<CiState> {
+    match status {
+        "success" => Some(CiState::Success),
+        "failed" => Some(CiState::Failed),
+        "running" => Some(CiState::Running),
+        "created" | "waiting_for_resource" | "preparing" | "pending" | "scheduled" | "manual" => {
+            Some(CiState::Pending)
+        }
+        "canceled" => Some(CiState::Cancelled),
+        "skipped" => Some(CiState::Skipped),
+        _ => None,
+    }
+}
+
+/// Project ID for API paths ("group/sub/repo" -> "group%2Fsub%2Frepo").
+fn project_id(repo: &str) -> String {
+    repo.replace('/', "%2F")
+}
+
+async fn get<T: serde::de::DeserializeOwned>(
+    client: &reqwest::Client,
+    url: &str,
+    token: &str,
+) -> Result<T, String> {
+    let response = client
+        .get(url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(service::request_error("GitLab", &response));
+    }
+    response.json().await.map_err(|e| e.to_string())
+}
+
+/// Username of the signed-in user.
+pub async fn current_user(client: &reqwest::Client, host: &str, token: &str) -> Result<String, String> {
+    let user: User = get(client, &format!("{}/user", api_base(host)), token).await?;
+    Ok(user.username)
+}
+
+/// Projects the user is a member of, most recently active first.
+pub async fn list_repositories(
+    client: &reqwest::Client,
+    host: &str,
+    token: &str,
+) -> Result<Vec<RemoteRepository>, String> {
+    let mut repos = Vec::new();
+    for page in 1..=MAX_PAGES {
+        let url = format!(
+            "{}/projects?membership=true&order_by=last_activity_at&per_page={}&page={}",
+            api_base(host),
+            PER_PAGE,
+            page
+        );
+        let batch: Vec<Project> = get(client, &url, token).await?;
+        let done = batch.len() < PER_PAGE as usize;
+        repos.extend(batch.into_iter().map(RemoteRepository::from));
+        if done {
+            break;
+        }
+    }
+    Ok(repos)
+}
+
+/// Groups (including subgroups) the user is a member of.
+pub async fn list_owners(client: &reqwest::Client, host: &str, token: &str) -> Result<Vec<String>, String> {
+    let groups: Vec<Group> = get(
+        client,
+        &format!("{}/groups?min_access_level=10&per_page={}", api_base(host), PER_PAGE),
+        token,
+    )
+    .await?;
+    Ok(groups.into_iter().map(|group| group.full_path).collect())
+}
+
+/// Open merge requests of a project ("group/repo").
+pub async fn list_open_pull_requests(
+    client: &reqwest::Client,
+    host: &str,
+    token: &str,
+    repo: &str,
+) -> Result<Vec<PullRequestSummary>, String> {
+    let mut mrs = Vec::new();
+    for page in 1..=MAX_PAGES {
+        let url = format!(
+            "{}/projects/{}/merge_requests?state=opened&per_page={}&page={}",
+            api_base(host),
+            project_id(repo),
+            PER_PAGE,
+            page
+        );
+        let batch: Vec<MergeRequest> = get(client, &url, token).await?;
+        let done = batch.len() < PER_PAGE as usize;
+        mrs.extend(batch.into_iter().map(PullRequestSummary::from));
+        if done {
+            break;
+        }
+    }
+    Ok(mrs)
+}
+
+/// Latest pipeline, optionally limited to a branch.
+pub async fn latest_pipeline(
+    client: &reqwest::Client,
+    host: &str,
+    token: &str,
+    repo: &str,
+    branch: Option<&str>,
+) -> Result<Option<Pipeline>, String> {
+    let mut params = vec![("per_page", "1")];
+    if let Some(branch) = branch {
+        params.push(("ref", branch));
+    }
+    let url = reqwest::Url::parse_with_params(
+        &format!("{}/projects/{}/pipelines", api_base(host), project_id(repo)),
+        &params,
+    )
+    .map_err(|e| e.to_string())?;
+
+    let runs: Vec<PipelineRun> = get(client, url.as_str(), token).await?;
+    Ok(runs.into_iter().next().map(|run| Pipeline {
+        state: ci_state(&run.status),
+        branch: run.branch,
+        url: run.web_url,
+        updated_at: run.updated_at,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ci_state_and_project_id() {
+        assert_eq!(ci_state("failed"), Some(CiState::Failed));
+        assert_eq!(ci_state("manual"), Some(CiState::Pending));
+        assert_eq!(ci_state("canceled"), Some(CiState::Cancelled));
+        assert_eq!(project_id("acme/platform/api"), "acme%2Fplatform%2Fapi");
+    }
+
+    #[test]
+    fn test_project_conversion() {
+        let json = r#"{
+            "path": "api",
+            "path_with_namespace": "acme/platform/api",
+            "namespace": { "full_path": "acme/platform" },
+            "description": "",
+            "http_url_to_repo": "https://gitlab.com/acme/platform/api.git",
+            "ssh_url_to_repo": "git@gitlab.com:acme/platform/api.git",
+            "default_branch": "main",
+            "visibility": "internal",
+            "forked_from_project": { "id": 1 },
+            "last_activity_at": "2024-05-01T10:00:00Z"
+        }"#;
+        let repo: RemoteRepository = serde_json::from_str::<Project>(json).unwrap().into();
+
+        assert_eq!(repo.owner, "acme/platform");
+        assert_eq!(repo.name, "api");
+        assert_eq!(repo.description, None);
+        assert!(repo.is_private);
+        assert!(repo.is_fork);
+    }
+}
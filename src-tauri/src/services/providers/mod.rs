@@ -0,0 +1,239 @@
+//! GitHub and GitLab account integration.
+//!
+//! Accounts are added with the OAuth device flow: `start_provider_auth`
+//! returns a code for the user to enter on the provider's site and
+//! `complete_provider_auth` waits for approval and stores the token. The
+//! OAuth application's client ID is read from the `github_oauth_client_id` /
+//! `gitlab_oauth_client_id` settings, so self-hosted instances work with
+//! their own application.
+//!
+//! Remote repositories can then be listed (filtered by owner), annotated
+//! with the project that already tracks them, and cloned into a scope
+//! through the clone queue.
+//!
+//! For projects whose `origin` belongs to a signed-in account, the latest
+//! CI run and open pull requests are refreshed in the background and cached
+//! in the database for list badges (see [`status`]).
+
+pub mod github;
+pub mod gitlab;
+pub mod models;
+pub mod service;
+mod state;
+pub mod status;
+
+pub use models::{
+    CiState, CloneRemoteRequest, CloneRemoteResult, DeviceAuthorization, ProjectCiStatus,
+    ProviderAccount, ProviderKind, PullRequestSummary, RemoteRepository, RepositoryFilter,
+};
+pub use state::ProviderStatusState;
+pub use status::start_provider_status_service;
+
+use tauri::{AppHandle, State};
+
+use crate::db::models::CloneOptions;
+use crate::db::Database;
+use crate::services::clone_queue::{self, CloneRequest};
+
+/// Look up an account's token without holding the lock across awaits.
+fn account_token(db: &Database, account_id: &str) -> Result<(ProviderAccount, String), String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    service::get_account(&conn, account_id)
+}
+
+// =========================================================================
+// Tauri Commands
+// =========================================================================
+
+/// Start signing in to a provider with the device flow.
+///
+/// `host` defaults to github.com / gitlab.com.
+#[tauri::command]
+#[specta::specta]
+pub async fn start_provider_auth(
+    db: State<'_, Database>,
+    provider: ProviderKind,
+    host: Option<String>,
+) -> Result<DeviceAuthorization, String> {
+    let host = service::normalize_host(host.as_deref(), provider);
+    let client_id = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        service::client_id(&conn, provider)?
+    };
+
+    let client = service::http_client()?;
+    service::start_device_flow(&client, provider, &host, &client_id).await
+}
+
+/// Wait for the user to approve a device-flow sign-in and save the account.
+#[tauri::command]
+#[specta::specta]
+pub async fn complete_provider_auth(
+    db: State<'_, Database>,
+    authorization: DeviceAuthorization,
+) -> Result<ProviderAccount, String> {
+    let client_id = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        service::client_id(&conn, authorization.provider)?
+    };
+
+    let client = service::http_client()?;
+    let token = service::poll_device_flow(&client, &authorization, &client_id).await?;
+    let username =
+        service::current_user(&client, authorization.provider, &authorization.host, &token).await?;
+
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let account = service::save_account(
+        &conn,
+        authorization.provider,
+        &authorization.host,
+        &username,
+        &token,
+    )?;
+    tracing::info!(
+        "Signed in to {} on {} as {}",
+        authorization.provider.display_name(),
+        account.host,
+        account.username
+    );
+    Ok(account)
+}
+
+/// Get all signed-in provider accounts.
+#[tauri::command]
+#[specta::specta]
+pub fn get_provider_accounts(db: State<Database>) -> Result<Vec<ProviderAccount>, String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    service::list_accounts(&conn)
+}
+
+/// Sign out of a provider account, deleting its stored token.
+#[tauri::command]
+#[specta::specta]
+pub fn remove_provider_account(db: State<Database>, account_id: String) -> Result<(), String> {
+    {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        service::remove_account(&conn, &account_id)?;
+    }
+    // Drop CI badges and pull requests that were fetched with this account
+    status::prune_statuses(&db)
+}
+
+/// List the organizations (GitHub) or groups (GitLab) of an account.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_provider_owners(
+    db: State<'_, Database>,
+    account_id: String,
+) -> Result<Vec<String>, String> {
+    let (account, token) = account_token(&db, &account_id)?;
+    let client = service::http_client()?;
+    service::list_owners(&client, account.provider, &account.host, &token).await
+}
+
+/// List an account's remote repositories, marking those already cloned.
+///
+/// Forks and archived repositories are excluded unless the filter asks
+/// for them.
+#[tauri::command]
+#[specta::specta]
+pub async fn list_remote_repositories(
+    db: State<'_, Database>,
+    account_id: String,
+    filter: Option<RepositoryFilter>,
+) -> Result<Vec<RemoteRepository>, String> {
+    let (account, token) = account_token(&db, &account_id)?;
+    let filter = filter.unwrap_or_default();
+
+    let client = service::http_client()?;
+    let mut repos: Vec<RemoteRepository> =
+        service::list_repositories(&client, account.provider, &account.host, &token)
+            .await?
+            .into_iter()
+            .filter(|repo| filter.matches(repo))
+            .collect();
+
+    service::annotate_local_clones(&db, &mut repos)?;
+    Ok(repos)
+}
+
+/// Queue clones of remote repositories into a scope.
+///
+/// Each repository is cloned into a folder named after it; repositories
+/// that can't be queued (e.g. the folder exists) are reported in `errors`.
+#[tauri::command]
+#[specta::specta]
+pub fn clone_remote_repositories(
+    app: AppHandle,
+    request: CloneRemoteRequest,
+) -> Result<CloneRemoteResult, String> {
+    let mut result = CloneRemoteResult::default();
+
+    for repo in request.repositories {
+        let url = if request.use_ssh || request.ssh_alias.is_some() {
+            repo.ssh_url
+        } else {
+            repo.https_url
+        };
+        let clone = CloneRequest {
+            scope_id: request.scope_id.clone(),
+            url,
+            folder_name: repo.name,
+            options: CloneOptions {
+                shallow: request.shallow,
+                branch: None,
+                use_ssh_alias: request.ssh_alias.clone(),
+            },
+        };
+
+        match clone_queue::service::enqueue(&app, clone) {
+            Ok((job, _)) => result.queued.push(job),
+            Err(e) => result.errors.push(format!("{}: {}", repo.full_name, e)),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Get the cached CI status of a project (None if it isn't tracked).
+#[tauri::command]
+#[specta::specta]
+pub fn get_project_ci_status(
+    db: State<Database>,
+    project_id: String,
+) -> Result<Option<ProjectCiStatus>, String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    status::get_status(&conn, &project_id)
+}
+
+/// Get the cached CI status of every tracked project.
+#[tauri::command]
+#[specta::specta]
+pub fn get_projects_ci_status(db: State<Database>) -> Result<Vec<ProjectCiStatus>, String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    status::get_all_statuses(&conn)
+}
+
+/// Get the cached open pull requests of a project.
+#[tauri::command]
+#[specta::specta]
+pub fn get_project_prs(
+    db: State<Database>,
+    project_id: String,
+) -> Result<Vec<PullRequestSummary>, String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    status::get_pull_requests(&conn, &project_id)
+}
+
+/// Refresh a project's CI status and pull requests now.
+#[tauri::command]
+#[specta::specta]
+pub async fn refresh_project_ci_status(
+    app: AppHandle,
+    db: State<'_, Database>,
+    project_id: String,
+) -> Result<Option<ProjectCiStatus>, String> {
+    status::refresh(&app, Some(&project_id)).await?;
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    status::get_status(&conn, &project_id)
+}
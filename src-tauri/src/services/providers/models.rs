@@ -0,0 +1,242 @@
+//! Git hosting provider models.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// A supported git hosting provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum ProviderKind {
+    GitHub,
+    GitLab,
+}
+
+impl ProviderKind {
+    /// Value stored in the `provider` column.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProviderKind::GitHub => "github",
+            ProviderKind::GitLab => "gitlab",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "github" => Some(ProviderKind::GitHub),
+            "gitlab" => Some(ProviderKind::GitLab),
+            _ => None,
+        }
+    }
+
+    /// Public host used when none is given.
+    pub fn default_host(&self) -> &'static str {
+        match self {
+            ProviderKind::GitHub => "github.com",
+            ProviderKind::GitLab => "gitlab.com",
+        }
+    }
+
+    /// Setting key holding the OAuth application client ID.
+    pub fn client_id_setting(&self) -> &'static str {
+        match self {
+            ProviderKind::GitHub => "github_oauth_client_id",
+            ProviderKind::GitLab => "gitlab_oauth_client_id",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ProviderKind::GitHub => "GitHub",
+            ProviderKind::GitLab => "GitLab",
+        }
+    }
+}
+
+/// A pending OAuth device-flow sign-in.
+///
+/// Show `user_code` and open `verification_uri`, then pass this back to
+/// `complete_provider_auth`.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceAuthorization {
+    pub provider: ProviderKind,
+    pub host: String,
+    pub device_code: String,
+    /// Code the user enters on the verification page
+    pub user_code: String,
+    pub verification_uri: String,
+    /// Verification page with the code pre-filled, if the provider offers one
+    pub verification_uri_complete: Option<String>,
+    /// Seconds until the codes expire
+    pub expires_in: u32,
+    /// Minimum seconds between polls
+    pub interval: u32,
+}
+
+/// A signed-in provider account (the token is never exposed).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderAccount {
+    pub id: String,
+    pub provider: ProviderKind,
+    pub host: String,
+    pub username: String,
+    pub created_at: String,
+}
+
+/// A repository on a provider, annotated with its local clone.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteRepository {
+    /// Owner or group path (e.g. "acme" or "acme/platform")
+    pub owner: String,
+    pub name: String,
+    /// "owner/name"
+    pub full_name: String,
+    pub description: Option<String>,
+    pub https_url: String,
+    pub ssh_url: String,
+    pub default_branch: Option<String>,
+    pub is_private: bool,
+    pub is_fork: bool,
+    pub is_archived: bool,
+    pub updated_at: Option<String>,
+    /// Project already registered for this repository, if any
+    pub local_project_id: Option<String>,
+    pub local_path: Option<String>,
+}
+
+/// Options for listing remote repositories.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryFilter {
+    /// Only repositories owned by these users/organizations/groups
+    pub owners: Option<Vec<String>>,
+    #[serde(default)]
+    pub include_forks: bool,
+    #[serde(default)]
+    pub include_archived: bool,
+}
+
+impl RepositoryFilter {
+    pub fn matches(&self, repo: &RemoteRepository) -> bool {
+        if repo.is_fork && !self.include_forks {
+            return false;
+        }
+        if repo.is_archived && !self.include_archived {
+            return false;
+        }
+        match self.owners {
+            Some(ref owners) if !owners.is_empty() => owners.iter().any(|owner| {
+                let owner = owner.to_lowercase();
+                let repo_owner = repo.owner.to_lowercase();
+                // GitLab subgroups belong to their parent group
+                repo_owner == owner || repo_owner.starts_with(&format!("{}/", owner))
+            }),
+            _ => true,
+        }
+    }
+}
+
+/// Remote repositories to clone into a scope.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CloneRemoteRequest {
+    pub scope_id: String,
+    pub repositories: Vec<RemoteRepository>,
+    /// Clone over SSH instead of HTTPS
+    pub use_ssh: bool,
+    /// SSH alias to rewrite the host with (implies SSH)
+    pub ssh_alias: Option<String>,
+    #[serde(default)]
+    pub shallow: bool,
+}
+
+/// Outcome of queueing remote clones.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CloneRemoteResult {
+    pub queued: Vec<crate::services::clone_queue::CloneJob>,
+    /// "owner/name: reason" for repositories that could not be queued
+    pub errors: Vec<String>,
+}
+
+/// Result of the latest CI pipeline / workflow run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum CiState {
+    Success,
+    Failed,
+    Running,
+    /// Queued, waiting for a runner or for manual action
+    Pending,
+    Cancelled,
+    Skipped,
+}
+
+impl CiState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CiState::Success => "success",
+            CiState::Failed => "failed",
+            CiState::Running => "running",
+            CiState::Pending => "pending",
+            CiState::Cancelled => "cancelled",
+            CiState::Skipped => "skipped",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "success" => Some(CiState::Success),
+            "failed" => Some(CiState::Failed),
+            "running" => Some(CiState::Running),
+            "pending" => Some(CiState::Pending),
+            "cancelled" => Some(CiState::Cancelled),
+            "skipped" => Some(CiState::Skipped),
+            _ => None,
+        }
+    }
+}
+
+/// Latest pipeline run on a provider.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pipeline {
+    pub state: Option<CiState>,
+    pub branch: Option<String>,
+    pub url: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+/// Cached CI and pull request summary for a project's origin remote.
+///
+/// Emitted via the `project-provider-status` event when it changes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectCiStatus {
+    pub project_id: String,
+    pub provider: ProviderKind,
+    /// None when the repository has no pipeline runs
+    pub state: Option<CiState>,
+    /// Branch of the latest run (the project's current branch when known)
+    pub branch: Option<String>,
+    /// Link to the run on the provider
+    pub url: Option<String>,
+    pub pipeline_updated_at: Option<String>,
+    pub open_pr_count: u32,
+    pub checked_at: String,
+}
+
+/// An open pull request (GitHub) or merge request (GitLab).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestSummary {
+    pub number: u32,
+    pub title: String,
+    pub author: Option<String>,
+    pub url: String,
+    pub is_draft: bool,
+    pub source_branch: Option<String>,
+    pub target_branch: Option<String>,
+    pub updated_at: Option<String>,
+}
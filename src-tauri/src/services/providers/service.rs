@@ -0,0 +1,528 @@
+//! Provider accounts, OAuth device flow and local clone matching.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use git2::Repository;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::db::Database;
+
+use super::models::{DeviceAuthorization, ProviderAccount, ProviderKind, RemoteRepository};
+use super::{github, gitlab};
+
+/// Page size for list requests.
+pub const PER_PAGE: u32 = 100;
+
+/// Upper bound on pages fetched per list (1000 repositories).
+pub const MAX_PAGES: u32 = 10;
+
+/// Build the HTTP client used for provider requests.
+pub fn http_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .user_agent(concat!("Panager/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// Normalize a user-supplied host ("https://GitHub.com/" -> "github.com").
+pub fn normalize_host(host: Option<&str>, provider: ProviderKind) -> String {
+    let host = host.map(str::trim).filter(|h| !h.is_empty());
+    match host {
+        Some(host) => host
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_end_matches('/')
+            .to_lowercase(),
+        None => provider.default_host().to_string(),
+    }
+}
+
+// =========================================================================
+// Device flow (RFC 8628)
+// =========================================================================
+
+#[derive(Deserialize)]
+struct DeviceCodeResponse {
+    device_code: String,
+    user_code: String,
+    verification_uri: String,
+    verification_uri_complete: Option<String>,
+    expires_in: u32,
+    interval: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+/// Request a device code for signing in.
+pub async fn start_device_flow(
+    client: &reqwest::Client,
+    provider: ProviderKind,
+    host: &str,
+    client_id: &str,
+) -> Result<DeviceAuthorization, String> {
+    let (url, scopes) = match provider {
+        ProviderKind::GitHub => (github::device_code_url(host), github::SCOPES),
+        ProviderKind::GitLab => (gitlab::device_code_url(host), gitlab::SCOPES),
+    };
+
+    let response = client
+        .post(&url)
+        .header("Accept", "application/json")
+        .form(&[("client_id", client_id), ("scope", scopes)])
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach {}: {}", host, e))?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "{} rejected the sign-in request: {}",
+            provider.display_name(),
+            response.status()
+        ));
+    }
+
+    let body: DeviceCodeResponse = response.json().await.map_err(|e| e.to_string())?;
+    Ok(DeviceAuthorization {
+        provider,
+        host: host.to_string(),
+        device_code: body.device_code,
+        user_code: body.user_code,
+        verification_uri: body.verification_uri,
+        verification_uri_complete: body.verification_uri_complete,
+        expires_in: body.expires_in,
+        interval: body.interval.unwrap_or(5),
+    })
+}
+
+/// Poll until the user approves the sign-in, returning the access token.
+pub async fn poll_device_flow(
+    client: &reqwest::Client,
+    authorization: &DeviceAuthorization,
+    client_id: &str,
+) -> Result<String, String> {
+    let url = match authorization.provider {
+        ProviderKind::GitHub => github::token_url(&authorization.host),
+        ProviderKind::GitLab => gitlab::token_url(&authorization.host),
+    };
+    let deadline = Instant::now() + Duration::from_secs(authorization.expires_in as u64);
+    let mut interval = Duration::from_secs(authorization.interval.max(1) as u64);
+
+    while Instant::now() < deadline {
+        tokio::time::sleep(interval).await;
+
+        // Errors come back as JSON with either a 200 (GitHub) or a 400 (GitLab)
+        let response: TokenResponse = client
+            .post(&url)
+            .header("Accept", "application/json")
+            .form(&[
+                ("client_id", client_id),
+                ("device_code", authorization.device_code.as_str()),
+                ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+            ])
+            .send()
+            .await
+            .map_err(|e| e.to_string())?
+            .json()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if let Some(token) = response.access_token {
+            return Ok(token);
+        }
+
+        match response.error.as_deref() {
+            Some("authorization_pending") => {}
+            Some("slow_down") => interval += Duration::from_secs(5),
+            Some("expired_token") => break,
+            Some("access_denied") => return Err("Sign-in was denied".to_string()),
+            Some(error) => {
+                return Err(response
+                    .error_description
+                    .unwrap_or_else(|| error.to_string()))
+            }
+            None => return Err("Unexpected response from the token endpoint".to_string()),
+        }
+    }
+
+    Err("Sign-in code expired; start again".to_string())
+}
+
+// =========================================================================
+// Provider API
+// =========================================================================
+
+/// Name of the user the token belongs to.
+pub async fn current_user(
+    client: &reqwest::Client,
+    provider: ProviderKind,
+    host: &str,
+    token: &str,
+) -> Result<String, String> {
+    match provider {
+        ProviderKind::GitHub => github::current_user(client, host, token).await,
+        ProviderKind::GitLab => gitlab::current_user(client, host, token).await,
+    }
+}
+
+/// All repositories visible to the account.
+pub async fn list_repositories(
+    client: &reqwest::Client,
+    provider: ProviderKind,
+    host: &str,
+    token: &str,
+) -> Result<Vec<RemoteRepository>, String> {
+    match provider {
+        ProviderKind::GitHub => github::list_repositories(client, host, token).await,
+        ProviderKind::GitLab => gitlab::list_repositories(client, host, token).await,
+    }
+}
+
+/// Organizations (GitHub) or groups (GitLab) the account belongs to.
+pub async fn list_owners(
+    client: &reqwest::Client,
+    provider: ProviderKind,
+    host: &str,
+    token: &str,
+) -> Result<Vec<String>, String> {
+    match provider {
+        ProviderKind::GitHub => github::list_owners(client, host, token).await,
+        ProviderKind::GitLab => gitlab::list_owners(client, host, token).await,
+    }
+}
+
+// =========================================================================
+// Accounts
+// =========================================================================
+
+/// Get the configured OAuth client ID for a provider.
+pub fn client_id(conn: &Connection, provider: ProviderKind) -> Result<String, String> {
+    crate::db::repository::get_setting(conn, provider.client_id_setting())
+        .map_err(|e| e.to_string())?
+        .and_then(|v| v.as_str().map(String::from))
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| {
+            format!(
+                "No {} OAuth client ID configured. Add one in Settings > Accounts.",
+                provider.display_name()
+            )
+        })
+}
+
+fn row_to_account(row: &rusqlite::Row) -> rusqlite::Result<ProviderAccount> {
+    let provider: String = row.get(1)?;
+    Ok(ProviderAccount {
+        id: row.get(0)?,
+        provider: ProviderKind::parse(&provider).unwrap_or(ProviderKind::GitHub),
+        host: row.get(2)?,
+        username: row.get(3)?,
+        created_at: row.get(4)?,
+    })
+}
+
+/// All signed-in accounts.
+pub fn list_accounts(conn: &Connection) -> Result<Vec<ProviderAccount>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, provider, host, username, created_at FROM provider_accounts ORDER BY provider, host, username",
+        )
+        .map_err(|e| e.to_string())?;
+    let accounts = stmt
+        .query_map([], row_to_account)
+        .map_err(|e| e.to_string())?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(accounts)
+}
+
+/// All signed-in accounts with their access tokens.
+pub fn list_account_tokens(conn: &Connection) -> Result<Vec<(ProviderAccount, String)>, String> {
+    let mut stmt = conn
+        .prepare("SELECT id, provider, host, username, created_at, access_token FROM provider_accounts")
+        .map_err(|e| e.to_string())?;
+    let accounts = stmt
+        .query_map([], |row| Ok((row_to_account(row)?, row.get::<_, String>(5)?)))
+        .map_err(|e| e.to_string())?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(accounts)
+}
+
+/// Get an account and its access token.
+pub fn get_account(conn: &Connection, account_id: &str) -> Result<(ProviderAccount, String), String> {
+    conn.query_row(
+        "SELECT id, provider, host, username, created_at, access_token FROM provider_accounts WHERE id = ?1",
+        [account_id],
+        |row| Ok((row_to_account(row)?, row.get::<_, String>(5)?)),
+    )
+    .optional()
+    .map_err(|e| e.to_string())?
+    .ok_or_else(|| format!("Provider account not found: {}", account_id))
+}
+
+/// Store an account, replacing the token if it is already signed in.
+pub fn save_account(
+    conn: &Connection,
+    provider: ProviderKind,
+    host: &str,
+    username: &str,
+    token: &str,
+) -> Result<ProviderAccount, String> {
+    conn.execute(
+        r#"
+        INSERT INTO provider_accounts (id, provider, host, username, access_token, created_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        ON CONFLICT(provider, host, username) DO UPDATE SET
+            access_token = excluded.access_token
+        "#,
+        params![
+            Uuid::new_v4().to_string(),
+            provider.as_str(),
+            host,
+            username,
+            token,
+            Utc::now().to_rfc3339(),
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+
+    let id: String = conn
+        .query_row(
+            "SELECT id FROM provider_accounts WHERE provider = ?1 AND host = ?2 AND username = ?3",
+            params![provider.as_str(), host, username],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+    get_account(conn, &id).map(|(account, _)| account)
+}
+
+/// Remove an account and its token.
+pub fn remove_account(conn: &Connection, account_id: &str) -> Result<(), String> {
+    conn.execute("DELETE FROM provider_accounts WHERE id = ?1", [account_id])
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Prefix of errors caused by a provider's rate limit.
+pub const RATE_LIMITED: &str = "Rate limited";
+
+/// Error for an unsuccessful provider response.
+///
+/// 429s, and 403s that carry rate limit headers (GitHub's primary and
+/// secondary limits), start with [`RATE_LIMITED`] so callers can back off.
+pub fn request_error(provider: &str, response: &reqwest::Response) -> String {
+    let status = response.status();
+    let headers = response.headers();
+    let exhausted = headers
+        .get("x-ratelimit-remaining")
+        .is_some_and(|remaining| remaining.as_bytes() == b"0");
+    let rate_limited = status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        || (status == reqwest::StatusCode::FORBIDDEN
+            && (exhausted || headers.contains_key("retry-after")));
+
+    if rate_limited {
+        format!("{} by {}: {}", RATE_LIMITED, provider, status)
+    } else {
+        format!("{} request failed: {}", provider, status)
+    }
+}
+
+/// Whether an error came from [`request_error`] for a rate limited response.
+pub fn is_rate_limited(error: &str) -> bool {
+    error.starts_with(RATE_LIMITED)
+}
+
+// =========================================================================
+// Local clone matching
+// =========================================================================
+
+/// Reduce a git remote URL to (host, path) for comparison.
+///
+/// Handles https, ssh:// and scp-style URLs, dropping credentials, ports
+/// and the `.git` suffix: `git@GitHub.com:Acme/App.git` -> ("github.com", "acme/app").
+pub fn remote_key(url: &str) -> Option<(String, String)> {
+    remote_parts(url).map(|(host, path)| (host, path.to_lowercase()))
+}
+
+/// Like [`remote_key`], but keeps the casing of the path.
+pub fn remote_parts(url: &str) -> Option<(String, String)> {
+    let url = url.trim();
+    let (rest, scp) = match url.split_once("://") {
+        Some((_, rest)) => (rest, false),
+        None => (url, true),
+    };
+    let rest = rest.rsplit_once('@').map_or(rest, |(_, r)| r);
+
+    let (host, path) = if scp {
+        rest.split_once(':')?
+    } else {
+        rest.split_once('/')?
+    };
+    let host = host.split(':').next()?.to_lowercase();
+    let path = path.trim_matches('/').trim_end_matches(".git").to_string();
+
+    if host.is_empty() || !path.contains('/') {
+        return None;
+    }
+    Some((host, path))
+}
+
+/// SSH config aliases mapped to their real host names (both lowercase).
+pub fn ssh_alias_hosts() -> HashMap<String, String> {
+    crate::ssh::config::read_ssh_aliases()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|alias| Some((alias.host.to_lowercase(), alias.host_name?.to_lowercase())))
+        .collect()
+}
+
+/// A project's origin URL: the cached one, else read from the repository.
+pub fn origin_url(cached: Option<String>, path: &str) -> Option<String> {
+    cached.or_else(|| {
+        let repo = Repository::open(Path::new(path)).ok()?;
+        let remote = repo.find_remote("origin").ok()?;
+        remote.url().map(String::from)
+    })
+}
+
+/// Fill `local_project_id`/`local_path` for repositories that are already
+/// registered as projects.
+///
+/// Remotes come from the git status cache, falling back to reading `origin`
+/// (after the database lock is released). A project whose remote host has no
+/// dot is assumed to use an SSH alias and is matched on the path alone.
+pub fn annotate_local_clones(db: &Database, repos: &mut [RemoteRepository]) -> Result<(), String> {
+    let projects: Vec<(String, String, Option<String>)> = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT p.id, p.path, g.remote_url
+                FROM projects p
+                LEFT JOIN git_status_cache g ON g.project_id = p.id
+                "#,
+            )
+            .map_err(|e| e.to_string())?;
+        let projects = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .map_err(|e| e.to_string())?
+            .filter_map(|r| r.ok())
+            .collect();
+        projects
+    };
+
+    let mut by_path: HashMap<String, Vec<(String, String, String)>> = HashMap::new();
+    for (id, path, remote_url) in projects {
+        if let Some((host, repo_path)) = origin_url(remote_url, &path).as_deref().and_then(remote_key) {
+            by_path.entry(repo_path).or_default().push((host, id, path));
+        }
+    }
+
+    for repo in repos.iter_mut() {
+        let Some((host, repo_path)) = remote_key(&repo.https_url) else {
+            continue;
+        };
+        let found = by_path.get(&repo_path).and_then(|candidates| {
+            candidates
+                .iter()
+                .find(|(h, _, _)| *h == host || !h.contains('.'))
+        });
+        if let Some((_, id, path)) = found {
+            repo.local_project_id = Some(id.clone());
+            repo.local_path = Some(path.clone());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::create_test_database;
+
+    #[test]
+    fn test_remote_key() {
+        let expected = Some(("github.com".to_string(), "acme/app".to_string()));
+        assert_eq!(remote_key("https://github.com/acme/app.git"), expected);
+        assert_eq!(remote_key("git@GitHub.com:Acme/App.git"), expected);
+        assert_eq!(remote_key("ssh://git@github.com:22/acme/app"), expected);
+        assert_eq!(remote_key("https://user:pw@github.com/acme/app/"), expected);
+        assert_eq!(
+            remote_key("https://gitlab.com/acme/platform/api.git"),
+            Some(("gitlab.com".to_string(), "acme/platform/api".to_string()))
+        );
+        assert_eq!(remote_key("not a url"), None);
+    }
+
+    #[test]
+    fn test_normalize_host() {
+        assert_eq!(normalize_host(None, ProviderKind::GitLab), "gitlab.com");
+        assert_eq!(
+            normalize_host(Some(" https://Git.Example.com/ "), ProviderKind::GitHub),
+            "git.example.com"
+        );
+    }
+
+    #[test]
+    fn test_account_roundtrip() {
+        let db = create_test_database();
+        let conn = db.conn.lock().unwrap();
+
+        let account = save_account(&conn, ProviderKind::GitHub, "github.com", "octo", "t1").unwrap();
+        let again = save_account(&conn, ProviderKind::GitHub, "github.com", "octo", "t2").unwrap();
+        assert_eq!(account.id, again.id);
+
+        let (_, token) = get_account(&conn, &account.id).unwrap();
+        assert_eq!(token, "t2");
+        assert_eq!(list_accounts(&conn).unwrap(), vec![again]);
+
+        remove_account(&conn, &account.id).unwrap();
+        assert!(list_accounts(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_annotate_local_clones() {
+        let db = create_test_database();
+        db.conn.lock().unwrap().execute_batch(
+            r#"
+            INSERT INTO scopes (id, name, created_at, updated_at) VALUES ('s1', 'Work', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z');
+            INSERT INTO projects (id, scope_id, name, path, is_temp, created_at, updated_at)
+                VALUES ('p1', 's1', 'app', '/nonexistent/panager_test_app', 0, '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z');
+            INSERT INTO git_status_cache (project_id, remote_url)
+                VALUES ('p1', 'git@github-work:acme/app.git');
+            "#,
+        )
+        .unwrap();
+
+        let repo = |name: &str| RemoteRepository {
+            owner: "acme".to_string(),
+            name: name.to_string(),
+            full_name: format!("acme/{}", name),
+            description: None,
+            https_url: format!("https://github.com/acme/{}.git", name),
+            ssh_url: format!("git@github.com:acme/{}.git", name),
+            default_branch: None,
+            is_private: false,
+            is_fork: false,
+            is_archived: false,
+            updated_at: None,
+            local_project_id: None,
+            local_path: None,
+        };
+        let mut repos = vec![repo("app"), repo("other")];
+        annotate_local_clones(&db, &mut repos).unwrap();
+
+        assert_eq!(repos[0].local_project_id.as_deref(), Some("p1"));
+        assert_eq!(repos[1].local_project_id, None);
+    }
+}
@@ -0,0 +1,12 @@
+//! State management for the provider status refresh service
+
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+/// State for the pull request / CI status refresh service
+#[derive(Default)]
+pub struct ProviderStatusState {
+    /// Whether the refresh loop is running
+    pub running: Arc<Mutex<bool>>,
+}
@@ -0,0 +1,540 @@
+//! Open pull requests and CI status for project remotes.
+//!
+//! Each project's `origin` is matched to a signed-in account by host (SSH
+//! aliases are resolved through `~/.ssh/config`). Projects without a
+//! matching account are skipped, so nothing is requested until the user
+//! signs in.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::db::Database;
+
+use super::models::{
+    CiState, Pipeline, ProjectCiStatus, ProviderAccount, ProviderKind, PullRequestSummary,
+};
+use super::service::{self, origin_url, remote_key};
+use super::{github, gitlab, ProviderStatusState};
+
+/// Event channel for changed project statuses.
+pub const PROVIDER_STATUS_EVENT: &str = "project-provider-status";
+
+/// How often statuses are refreshed.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// A project whose origin belongs to a signed-in account.
+pub struct ProjectRemote {
+    pub project_id: String,
+    pub account: ProviderAccount,
+    pub token: String,
+    /// Repository path on the provider ("owner/repo")
+    pub repo: String,
+    /// Currently checked-out branch, if known
+    pub branch: Option<String>,
+}
+
+/// A project row considered for remote resolution.
+struct ProjectOrigin {
+    project_id: String,
+    path: String,
+    remote_url: Option<String>,
+    branch: Option<String>,
+}
+
+/// Find the projects (all, or the given one) that have a matching account.
+///
+/// The database lock is only held for the queries; projects without a
+/// cached remote are opened afterwards to read `origin`.
+pub fn resolve_remotes(
+    db: &Database,
+    project_id: Option<&str>,
+) -> Result<Vec<ProjectRemote>, String> {
+    let (accounts, projects) = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        let accounts = service::list_account_tokens(&conn)?;
+        if accounts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT p.id, p.path, g.remote_url, g.branch
+                FROM projects p
+                LEFT JOIN git_status_cache g ON g.project_id = p.id
+                WHERE p.is_archived = 0 AND (?1 IS NULL OR p.id = ?1)
+                "#,
+            )
+            .map_err(|e| e.to_string())?;
+        let projects: Vec<ProjectOrigin> = stmt
+            .query_map([project_id], |row| {
+                Ok(ProjectOrigin {
+                    project_id: row.get(0)?,
+                    path: row.get(1)?,
+                    remote_url: row.get(2)?,
+                    branch: row.get(3)?,
+                })
+            })
+            .map_err(|e| e.to_string())?
+            .filter_map(|r| r.ok())
+            .collect();
+        (accounts, projects)
+    };
+
+    let aliases = service::ssh_alias_hosts();
+
+    Ok(projects
+        .into_iter()
+        .filter_map(|project| {
+            let (host, repo) = remote_key(&origin_url(project.remote_url, &project.path)?)?;
+            let host = aliases.get(&host).cloned().unwrap_or(host);
+            let (account, token) = accounts.iter().find(|(account, _)| account.host == host)?;
+            Some(ProjectRemote {
+                project_id: project.project_id,
+                account: account.clone(),
+                token: token.clone(),
+                repo,
+                branch: project.branch,
+            })
+        })
+        .collect())
+}
+
+/// Delete cached statuses and pull requests of projects that no longer
+/// match a signed-in account (e.g. after signing out).
+pub fn prune_statuses(db: &Database) -> Result<(), String> {
+    let covered: HashSet<String> = resolve_remotes(db, None)?
+        .into_iter()
+        .map(|remote| remote.project_id)
+        .collect();
+
+    let mut conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let stale: Vec<String> = get_all_statuses(&conn)?
+        .into_iter()
+        .map(|status| status.project_id)
+        .filter(|id| !covered.contains(id))
+        .collect();
+
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    for project_id in &stale {
+        tx.execute("DELETE FROM project_ci_status WHERE project_id = ?1", [project_id])
+            .map_err(|e| e.to_string())?;
+        tx.execute("DELETE FROM project_pull_requests WHERE project_id = ?1", [project_id])
+            .map_err(|e| e.to_string())?;
+    }
+    tx.commit().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Provider responses reused within one refresh, so several clones of the
+/// same repository cost one set of requests.
+#[derive(Default)]
+pub struct RefreshCache {
+    /// Keyed by (host, repo, branch)
+    pipelines: HashMap<(String, String, Option<String>), Option<Pipeline>>,
+    /// Keyed by (host, repo)
+    pull_requests: HashMap<(String, String), Vec<PullRequestSummary>>,
+}
+
+/// Fetch the latest pipeline and open pull requests for a project.
+pub async fn fetch_status(
+    client: &reqwest::Client,
+    remote: &ProjectRemote,
+    cache: &mut RefreshCache,
+) -> Result<(ProjectCiStatus, Vec<PullRequestSummary>), String> {
+    let host = &remote.account.host;
+    let branch = remote.branch.as_deref();
+
+    let pipeline_key = (host.clone(), remote.repo.clone(), remote.branch.clone());
+    let pipeline = match cache.pipelines.get(&pipeline_key) {
+        Some(pipeline) => pipeline.clone(),
+        None => {
+            let pipeline = match remote.account.provider {
+                ProviderKind::GitHub => {
+                    github::latest_pipeline(client, host, &remote.token, &remote.repo, branch).await?
+                }
+                ProviderKind::GitLab => {
+                    gitlab::latest_pipeline(client, host, &remote.token, &remote.repo, branch).await?
+                }
+            };
+            cache.pipelines.insert(pipeline_key, pipeline.clone());
+            pipeline
+        }
+    };
+
+    let prs_key = (host.clone(), remote.repo.clone());
+    let prs = match cache.pull_requests.get(&prs_key) {
+        Some(prs) => prs.clone(),
+        None => {
+            let prs = match remote.account.provider {
+                ProviderKind::GitHub => {
+                    github::list_open_pull_requests(client, host, &remote.token, &remote.repo).await?
+                }
+                ProviderKind::GitLab => {
+                    gitlab::list_open_pull_requests(client, host, &remote.token, &remote.repo).await?
+                }
+            };
+            cache.pull_requests.insert(prs_key, prs.clone());
+            prs
+        }
+    };
+
+    let status = ProjectCiStatus {
+        project_id: remote.project_id.clone(),
+        provider: remote.account.provider,
+        state: pipeline.as_ref().and_then(|p| p.state),
+        branch: pipeline
+            .as_ref()
+            .and_then(|p| p.branch.clone())
+            .or_else(|| remote.branch.clone()),
+        url: pipeline.as_ref().and_then(|p| p.url.clone()),
+        pipeline_updated_at: pipeline.and_then(|p| p.updated_at),
+        open_pr_count: prs.len() as u32,
+        checked_at: Utc::now().to_rfc3339(),
+    };
+    Ok((status, prs))
+}
+
+fn row_to_status(row: &rusqlite::Row) -> rusqlite::Result<ProjectCiStatus> {
+    let provider: String = row.get(1)?;
+    let state: Option<String> = row.get(2)?;
+    Ok(ProjectCiStatus {
+        project_id: row.get(0)?,
+        provider: ProviderKind::parse(&provider).unwrap_or(ProviderKind::GitHub),
+        state: state.as_deref().and_then(CiState::parse),
+        branch: row.get(3)?,
+        url: row.get(4)?,
+        pipeline_updated_at: row.get(5)?,
+        open_pr_count: row.get(6)?,
+        checked_at: row.get(7)?,
+    })
+}
+
+const STATUS_COLUMNS: &str =
+    "project_id, provider, state, branch, url, pipeline_updated_at, open_pr_count, checked_at";
+
+/// Get the cached status of a project.
+pub fn get_status(conn: &Connection, project_id: &str) -> Result<Option<ProjectCiStatus>, String> {
+    conn.query_row(
+        &format!("SELECT {} FROM project_ci_status WHERE project_id = ?1", STATUS_COLUMNS),
+        [project_id],
+        row_to_status,
+    )
+    .optional()
+    .map_err(|e| e.to_string())
+}
+
+/// Get the cached status of every project that has one.
+pub fn get_all_statuses(conn: &Connection) -> Result<Vec<ProjectCiStatus>, String> {
+    let mut stmt = conn
+        .prepare(&format!("SELECT {} FROM project_ci_status", STATUS_COLUMNS))
+        .map_err(|e| e.to_string())?;
+    let statuses = stmt
+        .query_map([], row_to_status)
+        .map_err(|e| e.to_string())?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(statuses)
+}
+
+/// Get the cached open pull requests of a project, most recent first.
+pub fn get_pull_requests(conn: &Connection, project_id: &str) -> Result<Vec<PullRequestSummary>, String> {
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT number, title, author, url, is_draft, source_branch, target_branch, updated_at
+            FROM project_pull_requests
+            WHERE project_id = ?1
+            ORDER BY updated_at DESC, number DESC
+            "#,
+        )
+        .map_err(|e| e.to_string())?;
+    let prs = stmt
+        .query_map([project_id], |row| {
+            Ok(PullRequestSummary {
+                number: row.get(0)?,
+                title: row.get(1)?,
+                author: row.get(2)?,
+                url: row.get(3)?,
+                is_draft: row.get(4)?,
+                source_branch: row.get(5)?,
+                target_branch: row.get(6)?,
+                updated_at: row.get(7)?,
+            })
+        })
+        .map_err(|e| e.to_string())?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(prs)
+}
+
+/// Replace a project's cached status and pull requests.
+///
+/// Returns true if anything other than `checked_at` changed.
+pub fn store_status(
+    conn: &mut Connection,
+    status: &ProjectCiStatus,
+    prs: &[PullRequestSummary],
+) -> Result<bool, String> {
+    let previous = get_status(conn, &status.project_id)?;
+    let previous_prs = get_pull_requests(conn, &status.project_id)?;
+
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    tx.execute(
+        &format!(
+            "INSERT OR REPLACE INTO project_ci_status ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            STATUS_COLUMNS
+        ),
+        params![
+            status.project_id,
+            status.provider.as_str(),
+            status.state.map(|s| s.as_str()),
+            status.branch,
+            status.url,
+            status.pipeline_updated_at,
+            status.open_pr_count,
+            status.checked_at,
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+
+    tx.execute(
+        "DELETE FROM project_pull_requests WHERE project_id = ?1",
+        [&status.project_id],
+    )
+    .map_err(|e| e.to_string())?;
+    for pr in prs {
+        tx.execute(
+            r#"
+            INSERT OR REPLACE INTO project_pull_requests
+                (project_id, number, title, author, url, is_draft, source_branch, target_branch, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            "#,
+            params![
+                status.project_id,
+                pr.number,
+                pr.title,
+                pr.author,
+                pr.url,
+                pr.is_draft,
+                pr.source_branch,
+                pr.target_branch,
+                pr.updated_at,
+            ],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    tx.commit().map_err(|e| e.to_string())?;
+
+    let changed = previous.is_none_or(|previous| {
+        ProjectCiStatus {
+            checked_at: status.checked_at.clone(),
+            ..previous
+        } != *status
+    });
+    let mut sorted_prs = prs.to_vec();
+    sorted_prs.sort_by_key(|pr| pr.number);
+    let mut previous_prs = previous_prs;
+    previous_prs.sort_by_key(|pr| pr.number);
+
+    Ok(changed || sorted_prs != previous_prs)
+}
+
+/// Refresh projects (all, or one) and emit changed statuses.
+///
+/// Failures for individual projects are logged and skipped. Hitting a
+/// provider's rate limit ends the run; the next interval tries again.
+pub async fn refresh(app: &AppHandle, project_id: Option<&str>) -> Result<(), String> {
+    let db = app.state::<Database>();
+    let remotes = resolve_remotes(&db, project_id)?;
+    if remotes.is_empty() {
+        return Ok(());
+    }
+
+    let client = service::http_client()?;
+    let mut cache = RefreshCache::default();
+    for remote in remotes {
+        let (status, prs) = match fetch_status(&client, &remote, &mut cache).await {
+            Ok(result) => result,
+            Err(e) if service::is_rate_limited(&e) => {
+                tracing::warn!("Stopping CI status refresh: {}", e);
+                break;
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to refresh CI status for {} ({}): {}",
+                    remote.repo,
+                    remote.project_id,
+                    e
+                );
+                continue;
+            }
+        };
+
+        let changed = {
+            let mut conn = db.conn.lock().map_err(|e| e.to_string())?;
+            store_status(&mut conn, &status, &prs)?
+        };
+        if changed {
+            let _ = app.emit(PROVIDER_STATUS_EVENT, &status);
+        }
+    }
+
+    Ok(())
+}
+
+/// Start the service that periodically refreshes pull request and CI status.
+pub async fn start_provider_status_service(app_handle: AppHandle) {
+    let state = app_handle.state::<ProviderStatusState>();
+
+    // Check if already running
+    {
+        let mut running = state.running.lock().await;
+        if *running {
+            return;
+        }
+        *running = true;
+    }
+
+    let app = app_handle.clone();
+    let running = state.running.clone();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(REFRESH_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            {
+                let is_running = running.lock().await;
+                if !*is_running {
+                    break;
+                }
+            }
+
+            if let Err(e) = refresh(&app, None).await {
+                tracing::error!("Provider status refresh error: {}", e);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::create_test_database;
+
+    fn setup(conn: &Connection) {
+        conn.execute_batch(
+            r#"
+            INSERT INTO scopes (id, name, created_at, updated_at) VALUES ('s1', 'Work', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z');
+            INSERT INTO projects (id, scope_id, name, path, is_temp, created_at, updated_at)
+                VALUES ('p1', 's1', 'app', '/nonexistent/panager_test_app', 0, '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z');
+            INSERT INTO projects (id, scope_id, name, path, is_temp, created_at, updated_at)
+                VALUES ('p2', 's1', 'lib', '/nonexistent/panager_test_lib', 0, '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z');
+            INSERT INTO git_status_cache (project_id, branch, remote_url)
+                VALUES ('p1', 'main', 'git@github.com:acme/app.git');
+            INSERT INTO git_status_cache (project_id, branch, remote_url)
+                VALUES ('p2', 'main', 'https://gitlab.com/acme/lib.git');
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_resolve_remotes_requires_account() {
+        let db = create_test_database();
+        setup(&db.conn.lock().unwrap());
+
+        assert!(resolve_remotes(&db, None).unwrap().is_empty());
+
+        service::save_account(&db.conn.lock().unwrap(), ProviderKind::GitHub, "github.com", "octo", "t")
+            .unwrap();
+        let remotes = resolve_remotes(&db, None).unwrap();
+        assert_eq!(remotes.len(), 1);
+        assert_eq!(remotes[0].project_id, "p1");
+        assert_eq!(remotes[0].repo, "acme/app");
+        assert_eq!(remotes[0].branch.as_deref(), Some("main"));
+
+        assert!(resolve_remotes(&db, Some("p2")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_prune_statuses_after_sign_out() {
+        let db = create_test_database();
+        let account = {
+            let conn = db.conn.lock().unwrap();
+            setup(&conn);
+            conn.execute_batch(
+                r#"
+                INSERT INTO project_ci_status (project_id, provider, open_pr_count, checked_at)
+                    VALUES ('p1', 'github', 1, '2024-01-01T00:00:00Z');
+                INSERT INTO project_pull_requests (project_id, number, title, url)
+                    VALUES ('p1', 7, 'Add feature', 'https://github.com/acme/app/pull/7');
+                "#,
+            )
+            .unwrap();
+            service::save_account(&conn, ProviderKind::GitHub, "github.com", "octo", "t").unwrap()
+        };
+
+        prune_statuses(&db).unwrap();
+        assert!(get_status(&db.conn.lock().unwrap(), "p1").unwrap().is_some());
+
+        service::remove_account(&db.conn.lock().unwrap(), &account.id).unwrap();
+        prune_statuses(&db).unwrap();
+        let conn = db.conn.lock().unwrap();
+        assert!(get_status(&conn, "p1").unwrap().is_none());
+        assert!(get_pull_requests(&conn, "p1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_store_status_reports_changes() {
+        let db = create_test_database();
+        let mut conn = db.conn.lock().unwrap();
+        setup(&conn);
+
+        let status = ProjectCiStatus {
+            project_id: "p1".to_string(),
+            provider: ProviderKind::GitHub,
+            state: Some(CiState::Running),
+            branch: Some("main".to_string()),
+            url: None,
+            pipeline_updated_at: None,
+            open_pr_count: 1,
+            checked_at: "2024-01-01T00:00:00Z".to_string(),
+        };
+        let prs = vec![PullRequestSummary {
+            number: 7,
+            title: "Add feature".to_string(),
+            author: Some("octo".to_string()),
+            url: "https://github.com/acme/app/pull/7".to_string(),
+            is_draft: false,
+            source_branch: Some("feature".to_string()),
+            target_branch: Some("main".to_string()),
+            updated_at: None,
+        }];
+
+        assert!(store_status(&mut conn, &status, &prs).unwrap());
+
+        // Only checked_at differs
+        let rechecked = ProjectCiStatus {
+            checked_at: "2024-01-01T00:15:00Z".to_string(),
+            ..status.clone()
+        };
+        assert!(!store_status(&mut conn, &rechecked, &prs).unwrap());
+
+        let passed = ProjectCiStatus {
+            state: Some(CiState::Success),
+            ..rechecked
+        };
+        assert!(store_status(&mut conn, &passed, &prs).unwrap());
+
+        assert_eq!(get_status(&conn, "p1").unwrap(), Some(passed));
+        assert_eq!(get_pull_requests(&conn, "p1").unwrap(), prs);
+        assert_eq!(get_all_statuses(&conn).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,82 @@
+//! Opt-in telemetry and crash reporting.
+//!
+//! This module captures panics and key performance metrics into a local
+//! queue in the app data directory. It is strictly opt-in:
+//! - Nothing is recorded unless `telemetry_enabled` is true
+//! - Queued reports can be inspected and purged at any time
+//! - Reports are only sent when the user explicitly requests an upload
+//!   to the configured `telemetry_endpoint`
+
+pub mod models;
+pub mod queue;
+pub mod service;
+
+pub use models::{TelemetryKind, TelemetryReport, TelemetryStatus};
+pub use queue::TelemetryQueue;
+pub use service::{
+    init, is_enabled, record_metric, reload_consent, set_consent, TELEMETRY_ENABLED_KEY,
+};
+
+use crate::db::Database;
+use service::TELEMETRY_ENDPOINT_KEY;
+use tauri::State;
+
+/// Get the default queue or fail with a readable error.
+fn default_queue() -> Result<TelemetryQueue, String> {
+    TelemetryQueue::default_location()
+        .ok_or_else(|| "Failed to determine app data directory".to_string())
+}
+
+// =========================================================================
+// Tauri Commands
+// =========================================================================
+
+/// Get telemetry consent and queue state.
+#[tauri::command]
+#[specta::specta]
+pub fn get_telemetry_status(db: State<Database>) -> Result<TelemetryStatus, String> {
+    Ok(TelemetryStatus {
+        enabled: is_enabled(),
+        endpoint: service::get_endpoint(&db),
+        queued_count: default_queue()?.count()? as u32,
+    })
+}
+
+/// Opt in to or out of telemetry.
+///
+/// Opting out also purges any reports already queued.
+#[tauri::command]
+#[specta::specta]
+pub fn set_telemetry_consent(db: State<Database>, enabled: bool) -> Result<(), String> {
+    service::set_consent(&db, enabled)
+}
+
+/// Get all queued telemetry reports so the user can review them.
+#[tauri::command]
+#[specta::specta]
+pub fn get_telemetry_queue() -> Result<Vec<TelemetryReport>, String> {
+    default_queue()?.list()
+}
+
+/// Delete all queued telemetry reports.
+#[tauri::command]
+#[specta::specta]
+pub fn purge_telemetry_queue() -> Result<u32, String> {
+    default_queue()?.purge().map(|n| n as u32)
+}
+
+/// Upload queued telemetry reports to the configured endpoint.
+///
+/// Requires the user to have opted in and an endpoint to be configured.
+#[tauri::command]
+#[specta::specta]
+pub async fn upload_telemetry_queue(db: State<'_, Database>) -> Result<u32, String> {
+    if !is_enabled() {
+        return Err("Telemetry is disabled".to_string());
+    }
+
+    let endpoint = service::get_endpoint(&db)
+        .ok_or_else(|| format!("No '{}' configured", TELEMETRY_ENDPOINT_KEY))?;
+
+    service::upload_reports(&endpoint, &default_queue()?).await
+}
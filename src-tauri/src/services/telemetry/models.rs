@@ -0,0 +1,60 @@
+//! Telemetry models.
+//!
+//! Reports are plain JSON documents so users can inspect exactly what
+//! would be sent before agreeing to upload anything.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Kind of telemetry report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum TelemetryKind {
+    /// A captured panic
+    Crash,
+    /// A timing measurement for a key operation
+    Metric,
+}
+
+/// A single queued telemetry report.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TelemetryReport {
+    /// Unique identifier (also used as the queue file name)
+    pub id: String,
+    pub kind: TelemetryKind,
+    /// Application version that produced the report
+    pub app_version: String,
+    /// Operating system (e.g., "macos", "linux")
+    pub os: String,
+    /// Report-specific data (panic message/location, metric name/duration)
+    pub payload: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TelemetryReport {
+    /// Create a new report for the running application.
+    pub fn new(kind: TelemetryKind, payload: serde_json::Value) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            kind,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            payload,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Current telemetry consent and queue state.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TelemetryStatus {
+    /// Whether the user has opted in to telemetry
+    pub enabled: bool,
+    /// Upload endpoint (None = uploads disabled)
+    pub endpoint: Option<String>,
+    /// Number of reports waiting in the local queue
+    pub queued_count: u32,
+}
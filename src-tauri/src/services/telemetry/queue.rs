@@ -0,0 +1,170 @@
+//! Local on-disk queue for telemetry reports.
+//!
+//! Each report is stored as its own JSON file so that a crash while writing
+//! one report can never corrupt the others, and so the panic hook does not
+//! need the database lock.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::models::TelemetryReport;
+
+/// Maximum number of reports kept locally; the oldest are pruned first.
+pub const MAX_QUEUED_REPORTS: usize = 200;
+
+/// File-backed telemetry queue.
+pub struct TelemetryQueue {
+    dir: PathBuf,
+}
+
+impl TelemetryQueue {
+    /// Create a queue stored in the given directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Create a queue in the default app data location.
+    pub fn default_location() -> Option<Self> {
+        crate::utils::paths::app_data_dir().map(|dir| Self::new(dir.join("telemetry").join("queue")))
+    }
+
+    /// Add a report to the queue, pruning the oldest if over capacity.
+    pub fn push(&self, report: &TelemetryReport) -> Result<(), String> {
+        fs::create_dir_all(&self.dir).map_err(|e| e.to_string())?;
+
+        // Timestamp prefix keeps files sorted by creation time
+        let file_name = format!(
+            "{}-{}.json",
+            report.created_at.format("%Y%m%d%H%M%S%3f"),
+            report.id
+        );
+        let json = serde_json::to_string_pretty(report).map_err(|e| e.to_string())?;
+        fs::write(self.dir.join(file_name), json).map_err(|e| e.to_string())?;
+
+        self.prune(MAX_QUEUED_REPORTS)
+    }
+
+    /// List all queued reports, oldest first.
+    ///
+    /// Unreadable files are skipped rather than failing the whole listing.
+    pub fn list(&self) -> Result<Vec<TelemetryReport>, String> {
+        Ok(self
+            .report_files()?
+            .iter()
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .filter_map(|json| serde_json::from_str(&json).ok())
+            .collect())
+    }
+
+    /// Number of queued reports.
+    pub fn count(&self) -> Result<usize, String> {
+        Ok(self.report_files()?.len())
+    }
+
+    /// Remove specific reports by ID.
+    pub fn remove(&self, ids: &[String]) -> Result<usize, String> {
+        let mut removed = 0;
+        for path in self.report_files()? {
+            let matches = file_report_id(&path)
+                .map(|id| ids.iter().any(|i| i == id))
+                .unwrap_or(false);
+            if matches && fs::remove_file(&path).is_ok() {
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Remove all queued reports.
+    pub fn purge(&self) -> Result<usize, String> {
+        let files = self.report_files()?;
+        let count = files.len();
+        for path in files {
+            fs::remove_file(&path).map_err(|e| e.to_string())?;
+        }
+        Ok(count)
+    }
+
+    /// Drop the oldest reports beyond `max`.
+    fn prune(&self, max: usize) -> Result<(), String> {
+        let files = self.report_files()?;
+        if files.len() > max {
+            for path in &files[..files.len() - max] {
+                let _ = fs::remove_file(path);
+            }
+        }
+        Ok(())
+    }
+
+    /// Queue files sorted by name (i.e., creation time).
+    fn report_files(&self) -> Result<Vec<PathBuf>, String> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut files: Vec<PathBuf> = fs::read_dir(&self.dir)
+            .map_err(|e| e.to_string())?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("json"))
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+}
+
+/// Extract the report ID from a queue file name (`{timestamp}-{id}.json`).
+fn file_report_id(path: &Path) -> Option<&str> {
+    path.file_stem()
+        .and_then(|s| s.to_str())
+        .and_then(|s| s.split_once('-'))
+        .map(|(_, id)| id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::telemetry::models::TelemetryKind;
+    use std::env;
+
+    fn test_queue(name: &str) -> TelemetryQueue {
+        let dir = env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        TelemetryQueue::new(dir)
+    }
+
+    #[test]
+    fn test_push_list_and_purge() {
+        let queue = test_queue("panager_test_telemetry_queue");
+
+        let report = TelemetryReport::new(
+            TelemetryKind::Metric,
+            serde_json::json!({ "name": "scan", "durationMs": 12 }),
+        );
+        queue.push(&report).unwrap();
+
+        let reports = queue.list().unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].id, report.id);
+
+        assert_eq!(queue.purge().unwrap(), 1);
+        assert_eq!(queue.count().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_remove_by_id() {
+        let queue = test_queue("panager_test_telemetry_remove");
+
+        let first = TelemetryReport::new(TelemetryKind::Crash, serde_json::json!({}));
+        let second = TelemetryReport::new(TelemetryKind::Crash, serde_json::json!({}));
+        queue.push(&first).unwrap();
+        queue.push(&second).unwrap();
+
+        assert_eq!(queue.remove(std::slice::from_ref(&first.id)).unwrap(), 1);
+        let remaining = queue.list().unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, second.id);
+
+        let _ = queue.purge();
+    }
+}
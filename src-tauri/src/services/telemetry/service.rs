@@ -0,0 +1,188 @@
+//! Telemetry capture and upload.
+//!
+//! Nothing is captured until the user opts in via `telemetry_enabled`, and
+//! nothing leaves the machine until they explicitly trigger an upload.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use crate::db::Database;
+
+use super::models::{TelemetryKind, TelemetryReport};
+use super::queue::TelemetryQueue;
+
+/// Setting key for the opt-in flag.
+pub const TELEMETRY_ENABLED_KEY: &str = "telemetry_enabled";
+
+/// Setting key for the upload endpoint.
+pub const TELEMETRY_ENDPOINT_KEY: &str = "telemetry_endpoint";
+
+/// In-memory copy of the consent flag, readable from the panic hook
+/// without touching the database.
+static TELEMETRY_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Initialize telemetry: load consent and install the panic hook.
+///
+/// The panic hook is always installed but only records crashes when
+/// the user has opted in. The previous hook still runs afterwards.
+pub fn init(db: &Database) {
+    reload_consent(db);
+
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if is_enabled() {
+            let message = info
+                .payload()
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| info.payload().downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            let location = info
+                .location()
+                .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()));
+            let thread = std::thread::current().name().map(String::from);
+
+            enqueue(TelemetryReport::new(
+                TelemetryKind::Crash,
+                serde_json::json!({
+                    "message": message,
+                    "location": location,
+                    "thread": thread,
+                    "backtrace": std::backtrace::Backtrace::force_capture().to_string(),
+                }),
+            ));
+        }
+        previous_hook(info);
+    }));
+}
+
+/// Re-read the consent flag from the settings table.
+///
+/// Called at startup and whenever settings are replaced wholesale (e.g. a
+/// backup import). Queued reports are purged if consent is now off.
+pub fn reload_consent(db: &Database) {
+    let enabled = db
+        .get_setting(TELEMETRY_ENABLED_KEY)
+        .ok()
+        .flatten()
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let was_enabled = is_enabled();
+    set_enabled(enabled);
+
+    if was_enabled && !enabled {
+        purge_queue();
+    }
+}
+
+/// Store the consent flag and apply it immediately.
+///
+/// Every write of [`TELEMETRY_ENABLED_KEY`] goes through here so the
+/// in-memory flag can't drift from the setting. Opting out also purges any
+/// reports already queued.
+pub fn set_consent(db: &Database, enabled: bool) -> Result<(), String> {
+    {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        crate::db::repository::set_setting(
+            &conn,
+            TELEMETRY_ENABLED_KEY,
+            &serde_json::Value::Bool(enabled),
+        )
+        .map_err(|e| e.to_string())?;
+    }
+
+    set_enabled(enabled);
+
+    if !enabled {
+        purge_queue();
+    }
+
+    tracing::info!(
+        "Telemetry {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+fn purge_queue() {
+    if let Some(queue) = TelemetryQueue::default_location() {
+        if let Err(e) = queue.purge() {
+            tracing::warn!("Failed to purge telemetry queue: {}", e);
+        }
+    }
+}
+
+/// Whether the user has opted in to telemetry.
+pub fn is_enabled() -> bool {
+    TELEMETRY_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Update the in-memory consent flag.
+pub fn set_enabled(enabled: bool) {
+    TELEMETRY_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Record a timing metric for a key operation.
+///
+/// This is a no-op unless the user has opted in.
+pub fn record_metric(name: &str, duration_ms: u64) {
+    if !is_enabled() {
+        return;
+    }
+
+    enqueue(TelemetryReport::new(
+        TelemetryKind::Metric,
+        serde_json::json!({ "name": name, "durationMs": duration_ms }),
+    ));
+}
+
+/// Write a report to the local queue, logging (never panicking) on failure.
+fn enqueue(report: TelemetryReport) {
+    let Some(queue) = TelemetryQueue::default_location() else {
+        return;
+    };
+    if let Err(e) = queue.push(&report) {
+        tracing::warn!("Failed to queue telemetry report: {}", e);
+    }
+}
+
+/// Get the configured upload endpoint, if any.
+pub fn get_endpoint(db: &Database) -> Option<String> {
+    db.get_setting(TELEMETRY_ENDPOINT_KEY)
+        .ok()
+        .flatten()
+        .and_then(|v| v.as_str().map(String::from))
+        .filter(|s| !s.trim().is_empty())
+}
+
+/// Upload queued reports to the endpoint and remove them on success.
+///
+/// Returns the number of reports uploaded.
+pub async fn upload_reports(endpoint: &str, queue: &TelemetryQueue) -> Result<u32, String> {
+    let reports = queue.list()?;
+    if reports.is_empty() {
+        return Ok(0);
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let response = client
+        .post(endpoint)
+        .json(&reports)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to upload telemetry: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Telemetry endpoint returned {}",
+            response.status()
+        ));
+    }
+
+    let ids: Vec<String> = reports.into_iter().map(|r| r.id).collect();
+    queue.remove(&ids).map(|n| n as u32)
+}
@@ -153,6 +153,73 @@ pub fn copy_file(from: &str, to: &str) -> Result<u64> {
     fs::copy(&from, &to).map_err(PanagerError::Io)
 }
 
+/// Get the total size of all files under a directory
+///
+/// Symlinks are not followed and unreadable entries are skipped.
+///
+/// # Arguments
+/// * `path` - Directory to measure
+///
+/// # Returns
+/// The total size in bytes
+pub fn dir_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// Folders skipped by [`estimate_dir_size`]: VCS data, dependencies and
+/// build output, which are large and regenerated anyway.
+const ESTIMATE_SKIP_DIRS: &[&str] = &[
+    ".git",
+    "node_modules",
+    "target",
+    ".venv",
+    "venv",
+    "__pycache__",
+    ".next",
+    ".gradle",
+    "Pods",
+];
+
+/// Estimate the size of a project's own files
+///
+/// Like [`dir_size`], but skips dependency and build folders and gives up
+/// after visiting `max_entries` entries so huge trees can't stall the caller.
+///
+/// # Arguments
+/// * `path` - Directory to measure
+/// * `max_entries` - Most files and folders to visit
+///
+/// # Returns
+/// The total size in bytes, or None if the walk hit `max_entries`
+pub fn estimate_dir_size(path: &Path, max_entries: usize) -> Option<u64> {
+    let walker = walkdir::WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0
+                || !entry.file_type().is_dir()
+                || !ESTIMATE_SKIP_DIRS.contains(&entry.file_name().to_string_lossy().as_ref())
+        });
+
+    let mut total = 0;
+    for (visited, entry) in walker.filter_map(|entry| entry.ok()).enumerate() {
+        if visited >= max_entries {
+            return None;
+        }
+        if entry.file_type().is_file() {
+            total += entry.metadata().map(|m| m.len()).unwrap_or(0);
+        }
+    }
+    Some(total)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -211,4 +278,19 @@ mod tests {
         // Clean up
         let _ = std::fs::remove_file(&temp_file);
     }
+
+    #[test]
+    fn test_estimate_dir_size() {
+        let temp_dir = env::temp_dir().join("panager_test_estimate_size");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        std::fs::create_dir_all(temp_dir.join("src")).unwrap();
+        std::fs::create_dir_all(temp_dir.join("node_modules/dep")).unwrap();
+        std::fs::write(temp_dir.join("src/main.rs"), [0u8; 10]).unwrap();
+        std::fs::write(temp_dir.join("node_modules/dep/index.js"), [0u8; 100]).unwrap();
+
+        assert_eq!(estimate_dir_size(&temp_dir, 100), Some(10));
+        assert_eq!(estimate_dir_size(&temp_dir, 2), None);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
 }
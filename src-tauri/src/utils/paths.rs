@@ -92,6 +92,18 @@ pub fn is_subdirectory(parent: &str, child: &str) -> bool {
     }
 }
 
+/// Get Panager's application data directory
+///
+/// This is where the database and other app-managed files are stored
+/// (e.g. `~/.local/share/panager` on Linux).
+///
+/// # Returns
+/// The data directory path, or None if it cannot be determined
+pub fn app_data_dir() -> Option<PathBuf> {
+    directories::ProjectDirs::from("com", "krona", "panager")
+        .map(|dirs| dirs.data_dir().to_path_buf())
+}
+
 /// Extract the filename from a path
 ///
 /// # Arguments
@@ -51,6 +51,25 @@ pub static GIT_HTTP_CREDENTIALS_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r#"https?://[^@]+@"#).expect("Invalid GIT_HTTP_CREDENTIALS_REGEX pattern")
 });
 
+// Git progress patterns
+pub static GIT_PROGRESS_PERCENT_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(\d{1,3})%"#).expect("Invalid GIT_PROGRESS_PERCENT_REGEX pattern")
+});
+
+// JetBrains recentProjects.xml patterns
+pub static JETBRAINS_RECENT_ENTRY_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"<entry key="([^"]+)""#).expect("Invalid JETBRAINS_RECENT_ENTRY_REGEX pattern")
+});
+
+pub static JETBRAINS_RECENT_OPTION_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"<option value="([^"]+)""#).expect("Invalid JETBRAINS_RECENT_OPTION_REGEX pattern")
+});
+
+// Scope policy patterns
+pub static LAYOUT_PLACEHOLDER_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\{[^}]*\}"#).expect("Invalid LAYOUT_PLACEHOLDER_REGEX pattern")
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,7 +214,11 @@ export type RuleGroup =
 /**
  * Security-related rules (security/*)
  */
-"security"
+"security" | 
+/**
+ * Per-scope convention rules (policy/*)
+ */
+"policy"
 
 /**
  * Metadata about a diagnostic rule.
@@ -337,8 +341,656 @@ scopeId: string | null; ruleId: string; createdAt: string }
  */
 export type ScanState = { scopeId: string; lastScanAt: string | null; scanDurationMs: number | null; issuesFound: number }
 
+/**
+ * Conventions enforced for a scope's projects by the `policy/*` rules.
+ * 
+ * Unset fields are not checked.
+ */
+export type ScopePolicy = { scopeId: string; 
+/**
+ * Project location relative to the scope's default folder, with
+ * `{owner}`, `{repo}` and `{name}` placeholders (e.g. "{owner}/{repo}")
+ */
+folderLayout: string | null; 
+/**
+ * Regex project folder names must match (e.g. "^[a-z0-9-]+$")
+ */
+repoNamePattern: string | null; 
+/**
+ * Expected default branch (e.g. "main")
+ */
+defaultBranch: string | null; 
+/**
+ * Host the `origin` remote must point to (e.g. "github.com")
+ */
+requiredRemoteHost: string | null }
+
 /**
  * Summary of diagnostics for a scope.
  */
 export type ScopeDiagnosticsSummary = { scopeId: string; errorCount: number; warningCount: number; infoCount: number; totalCount: number; lastScanAt: string | null }
 
+
+/**
+ * Broad event categories used to filter event delivery.
+ * 
+ * Frontend windows subscribe to the categories they render so that, for
+ * example, a settings window is not woken up by every git status refresh.
+ */
+export type EventCategory = "project" | "scope" | "settings" | "scanner" | "diagnostics"
+
+/**
+ * Filter describing which events a window wants to receive.
+ */
+export type EventSubscription = { 
+/**
+ * Categories to receive (empty = all categories)
+ */
+categories: EventCategory[]; 
+/**
+ * Scopes to receive events for (empty = all scopes).
+ * Global events without a scope are always delivered.
+ */
+scopeIds: string[] }
+
+/**
+ * Kind of telemetry report.
+ */
+export type TelemetryKind = 
+/**
+ * A captured panic
+ */
+"crash" | 
+/**
+ * A timing measurement for a key operation
+ */
+"metric"
+
+/**
+ * A single queued telemetry report.
+ */
+export type TelemetryReport = { 
+/**
+ * Unique identifier (also used as the queue file name)
+ */
+id: string; kind: TelemetryKind; 
+/**
+ * Application version that produced the report
+ */
+appVersion: string; 
+/**
+ * Operating system (e.g., "macos", "linux")
+ */
+os: string; 
+/**
+ * Report-specific data (panic message/location, metric name/duration)
+ */
+payload: JsonValue; createdAt: string }
+
+/**
+ * Current telemetry consent and queue state.
+ */
+export type TelemetryStatus = { 
+/**
+ * Whether the user has opted in to telemetry
+ */
+enabled: boolean; 
+/**
+ * Upload endpoint (None = uploads disabled)
+ */
+endpoint: string | null; 
+/**
+ * Number of reports waiting in the local queue
+ */
+queuedCount: number }
+
+/**
+ * Current logging configuration exposed to the frontend
+ */
+export type LogSettings = { 
+/**
+ * Base filter directives (default level or RUST_LOG value)
+ */
+base: string; 
+/**
+ * Per-module level overrides
+ */
+overrides: { [key in string]: string }; 
+/**
+ * Path of the active log file, if file logging is available
+ */
+logFile: string | null }
+
+/**
+ * Overall health rating for a project card.
+ */
+export type HealthStatus = 
+/**
+ * Clean, in sync, no diagnostics
+ */
+"healthy" | 
+/**
+ * Something needs attention (uncommitted work, unpushed commits, warnings, stale)
+ */
+"warning" | 
+/**
+ * Folder missing or error-level diagnostics
+ */
+"critical"
+
+/**
+ * Non-dismissed diagnostics counts for a single project.
+ */
+export type HealthDiagnostics = { errorCount: number; warningCount: number; infoCount: number }
+
+/**
+ * Aggregated health information for a project.
+ * 
+ * Combines git status, last commit age, diagnostics and disk usage so the
+ * dashboard can render a card from a single call.
+ */
+export type ProjectHealth = { projectId: string; scopeId: string; 
+/**
+ * Whether the project folder exists on disk
+ */
+exists: boolean; 
+/**
+ * Whether the folder is a git repository
+ */
+isGitRepo: boolean; branch: string | null; hasUncommitted: boolean; hasUntracked: boolean; 
+/**
+ * Commits on the current branch not yet pushed to origin
+ */
+unpushedCount: number; 
+/**
+ * Commits on origin not yet pulled
+ */
+behindCount: number; hasRemote: boolean; lastCommitAt: string | null; 
+/**
+ * Whole days since the last commit
+ */
+lastCommitAgeDays: number | null; diagnostics: HealthDiagnostics; 
+/**
+ * Total size of the project folder (including .git)
+ */
+diskSizeBytes: number | null; status: HealthStatus; computedAt: string }
+
+/**
+ * Commit count for a single day of the heatmap.
+ */
+export type ActivityDay = { 
+/**
+ * Date in `YYYY-MM-DD` (UTC)
+ */
+date: string; commits: number }
+
+/**
+ * Activity of a single commit author.
+ */
+export type AuthorActivity = { name: string; email: string; commitCount: number; lastCommitAt: string }
+
+/**
+ * Commit activity statistics for a project, computed from git history.
+ */
+export type ProjectActivity = { projectId: string; 
+/**
+ * HEAD commit the statistics were computed for
+ */
+head: string | null; 
+/**
+ * History was longer than the walk limit: `totalCommits` and
+ * `firstCommitAt` only cover the newest commits
+ */
+truncated: boolean; totalCommits: number; commitsLast30Days: number; 
+/**
+ * Commits per week, oldest first, for sparkline rendering
+ */
+weeklyCommits: number[]; 
+/**
+ * Days with at least one commit in the last year, oldest first
+ */
+heatmap: ActivityDay[]; 
+/**
+ * Authors ordered by most recent activity
+ */
+authors: AuthorActivity[]; localBranchCount: number; remoteBranchCount: number; firstCommitAt: string | null; lastCommitAt: string | null; computedAt: string }
+
+/**
+ * Background fetch policy for a scope.
+ */
+export type ScopeFetchPolicy = { scopeId: string; enabled: boolean; intervalMinutes: number; 
+/**
+ * Skip fetching while running on battery power
+ */
+skipOnBattery: boolean; 
+/**
+ * Skip fetching on metered network connections
+ */
+skipOnMetered: boolean }
+
+/**
+ * Power and network state reported by the platform.
+ */
+export type PlatformConditions = { onBattery: boolean; meteredNetwork: boolean }
+
+/**
+ * Result of fetching one scope.
+ */
+export type ScopeFetchResult = { scopeId: string; fetched: number; failed: number; 
+/**
+ * Projects whose ahead/behind counts changed
+ */
+changed: number }
+
+/**
+ * Folder operation being checked
+ */
+export type FolderOperation = "move" | "delete"
+
+/**
+ * What to do when a project has work that could be lost
+ */
+export type DirtyWorkPolicy = 
+/**
+ * Fail the operation (default)
+ */
+"refuse" | 
+/**
+ * Stash uncommitted and untracked changes first (moves only)
+ */
+"stash" | 
+/**
+ * Proceed anyway
+ */
+"force"
+
+/**
+ * Unsaved work found in a project folder
+ */
+export type WorkInspection = { exists: boolean; isGitRepo: boolean; 
+/**
+ * Modified or staged tracked files
+ */
+uncommittedFiles: number; untrackedFiles: number; 
+/**
+ * Local branches with commits not on their upstream (or never pushed)
+ */
+unpushedBranches: string[]; stashCount: number; hasRemote: boolean }
+
+/**
+ * Preflight result for one project
+ */
+export type ProjectPreflight = { projectId: string; projectName: string; path: string; inspection: WorkInspection; 
+/**
+ * Reasons the operation could lose work (empty = safe)
+ */
+risks: string[] }
+
+/**
+ * Lifecycle of a queued clone.
+ */
+export type CloneJobStatus = "queued" | "running" | 
+/**
+ * Waiting for the user to answer a credential prompt
+ */
+"awaitingCredentials" | "completed" | "failed" | "cancelled"
+
+/**
+ * Phase of a running clone, as reported by git.
+ */
+export type CloneStage = 
+/**
+ * Remote is counting/compressing objects
+ */
+"preparing" | "receiving" | "resolving" | "checkingOut" | 
+/**
+ * Registering the project and applying the scope identity
+ */
+"registering"
+
+/**
+ * Request to queue a clone.
+ */
+export type CloneRequest = { scopeId: string; url: string; 
+/**
+ * Folder name inside the scope's default folder
+ */
+folderName: string; options: CloneOptions }
+
+/**
+ * A queued, running or finished clone.
+ * 
+ * Emitted on every change via the `clone-job-progress` event.
+ */
+export type CloneJob = { id: string; scopeId: string; url: string; folderName: string; targetPath: string; status: CloneJobStatus; stage: CloneStage | null; 
+/**
+ * Overall progress across all stages (0-100)
+ */
+percent: number; 
+/**
+ * Latest git progress line
+ */
+message: string | null; error: string | null; 
+/**
+ * Set once the clone is registered as a project
+ */
+projectId: string | null; createdAt: string; finishedAt: string | null }
+
+/**
+ * A credential prompt from git or ssh, surfaced to the UI.
+ * 
+ * Emitted via the `clone-credential-request` event and answered with
+ * `answer_clone_credential`.
+ */
+export type CredentialRequest = { jobId: string; promptId: string; 
+/**
+ * Prompt text from git/ssh (e.g. "Password for 'https://github.com':")
+ */
+prompt: string; 
+/**
+ * Whether the answer should be masked (password, passphrase, token)
+ */
+isSecret: boolean }
+
+/**
+ * A supported git hosting provider.
+ */
+export type ProviderKind = "github" | "gitlab"
+
+/**
+ * A pending OAuth device-flow sign-in.
+ * 
+ * Show `user_code` and open `verification_uri`, then pass this back to
+ * `complete_provider_auth`.
+ */
+export type DeviceAuthorization = { provider: ProviderKind; host: string; deviceCode: string; 
+/**
+ * Code the user enters on the verification page
+ */
+userCode: string; verificationUri: string; 
+/**
+ * Verification page with the code pre-filled, if the provider offers one
+ */
+verificationUriComplete: string | null; 
+/**
+ * Seconds until the codes expire
+ */
+expiresIn: number; 
+/**
+ * Minimum seconds between polls
+ */
+interval: number }
+
+/**
+ * A signed-in provider account (the token is never exposed).
+ */
+export type ProviderAccount = { id: string; provider: ProviderKind; host: string; username: string; createdAt: string }
+
+/**
+ * A repository on a provider, annotated with its local clone.
+ */
+export type RemoteRepository = { 
+/**
+ * Owner or group path (e.g. "acme" or "acme/platform")
+ */
+owner: string; name: string; 
+/**
+ * "owner/name"
+ */
+fullName: string; description: string | null; httpsUrl: string; sshUrl: string; defaultBranch: string | null; isPrivate: boolean; isFork: boolean; isArchived: boolean; updatedAt: string | null; 
+/**
+ * Project already registered for this repository, if any
+ */
+localProjectId: string | null; localPath: string | null }
+
+/**
+ * Options for listing remote repositories.
+ */
+export type RepositoryFilter = { 
+/**
+ * Only repositories owned by these users/organizations/groups
+ */
+owners: string[] | null; includeForks?: boolean; includeArchived?: boolean }
+
+/**
+ * Remote repositories to clone into a scope.
+ */
+export type CloneRemoteRequest = { scopeId: string; repositories: RemoteRepository[]; 
+/**
+ * Clone over SSH instead of HTTPS
+ */
+useSsh: boolean; 
+/**
+ * SSH alias to rewrite the host with (implies SSH)
+ */
+sshAlias: string | null; shallow?: boolean }
+
+/**
+ * Outcome of queueing remote clones.
+ */
+export type CloneRemoteResult = { queued: CloneJob[]; 
+/**
+ * "owner/name: reason" for repositories that could not be queued
+ */
+errors: string[] }
+
+/**
+ * Result of the latest CI pipeline / workflow run.
+ */
+export type CiState = "success" | "failed" | "running" | 
+/**
+ * Queued, waiting for a runner or for manual action
+ */
+"pending" | "cancelled" | "skipped"
+
+/**
+ * Cached CI and pull request summary for a project's origin remote.
+ * 
+ * Emitted via the `project-provider-status` event when it changes.
+ */
+export type ProjectCiStatus = { projectId: string; provider: ProviderKind; 
+/**
+ * None when the repository has no pipeline runs
+ */
+state: CiState | null; 
+/**
+ * Branch of the latest run (the project's current branch when known)
+ */
+branch: string | null; 
+/**
+ * Link to the run on the provider
+ */
+url: string | null; pipelineUpdatedAt: string | null; openPrCount: number; checkedAt: string }
+
+/**
+ * An open pull request (GitHub) or merge request (GitLab).
+ */
+export type PullRequestSummary = { number: number; title: string; author: string | null; url: string; isDraft: boolean; sourceBranch: string | null; targetBranch: string | null; updatedAt: string | null }
+
+/**
+ * How a template produces a new project folder
+ */
+export type TemplateKind = 
+/**
+ * Clone a git repository URL into the new folder
+ */
+"git" | 
+/**
+ * Run a generator command in the scope folder (e.g. `npm create vite@latest {{name}}`)
+ */
+"command"
+
+/**
+ * A variable that is substituted into the template source as `{{name}}`
+ */
+export type TemplateVariable = { name: string; label: string | null; defaultValue: string | null; required: boolean }
+
+/**
+ * A scope-level project template
+ */
+export type ProjectTemplate = { id: string; scopeId: string; name: string; description: string | null; kind: TemplateKind; 
+/**
+ * Repository URL (git) or generator command line (command)
+ */
+source: string; variables: TemplateVariable[]; sortOrder: number; createdAt: string; updatedAt: string }
+
+/**
+ * Request to create a new project template
+ */
+export type CreateProjectTemplateRequest = { scopeId: string; name: string; description: string | null; kind: TemplateKind; source: string; variables: TemplateVariable[] }
+
+/**
+ * Request to update a project template (None = unchanged)
+ */
+export type UpdateProjectTemplateRequest = { name: string | null; description: string | null; kind: TemplateKind | null; source: string | null; variables: TemplateVariable[] | null }
+
+/**
+ * Request to create a project from a template
+ */
+export type CreateFromTemplateRequest = { templateId: string; 
+/**
+ * Folder name of the new project inside the scope's default folder
+ */
+name: string; 
+/**
+ * Values for the template variables, keyed by variable name
+ */
+variables: { [key in string]: string } }
+
+/**
+ * An operation applied to every project in a batch.
+ */
+export type BatchOperation = 
+/**
+ * Move projects to another scope (database only, folders stay in place)
+ */
+{ type: "moveToScope"; newScopeId: string } | 
+/**
+ * Add a tag to projects
+ */
+{ type: "addTag"; tag: string } | 
+/**
+ * Remove projects, optionally deleting their folders
+ */
+{ type: "delete"; deleteFolders: boolean; 
+/**
+ * How to handle uncommitted or unpushed work when deleting folders
+ */
+dirtyPolicy?: DirtyWorkPolicy } | 
+/**
+ * Run `git fetch` in each project
+ */
+{ type: "gitFetch" }
+
+/**
+ * Status of a single item within a batch.
+ */
+export type BatchItemStatus = "running" | "succeeded" | "failed"
+
+/**
+ * Emitted on "batch-progress" for every item state change.
+ */
+export type BatchProgress = { jobId: string; operation: BatchOperation; projectId: string; 
+/**
+ * Zero-based position of the item in the batch
+ */
+index: number; total: number; status: BatchItemStatus; error: string | null }
+
+/**
+ * Error for a single failed batch item.
+ */
+export type BatchItemError = { projectId: string; error: string }
+
+/**
+ * Emitted on "batch-completed" once every item has been processed.
+ */
+export type BatchSummary = { jobId: string; total: number; succeeded: number; failed: number; 
+/**
+ * Per-project error messages for failed items
+ */
+errors: BatchItemError[] }
+
+/**
+ * Compression format for project archives.
+ */
+export type ArchiveFormat = "zip" | "tar.zst"
+
+/**
+ * A project whose folder has been compressed into an archive.
+ */
+export type ProjectArchive = { id: string; projectId: string; projectName: string; scopeId: string; 
+/**
+ * Where the folder lived before archiving (and is restored to)
+ */
+originalPath: string; archivePath: string; format: ArchiveFormat; 
+/**
+ * Size of the archive file
+ */
+sizeBytes: number; 
+/**
+ * Size of the folder before compression
+ */
+originalSizeBytes: number; archivedAt: string }
+
+/**
+ * File format for exported backups.
+ */
+export type BackupFormat = "json" | "sqlite"
+
+/**
+ * Row counts per table for an export or import.
+ */
+export type BackupSummary = { path: string; format: BackupFormat | null; tableCounts: { [key in string]: number } }
+
+/**
+ * A backup file in the automatic backup folder.
+ */
+export type BackupInfo = { path: string; fileName: string; sizeBytes: number; createdAt: string }
+
+/**
+ * Application a project was discovered in.
+ */
+export type ImportSource = "vscode" | "cursor" | "jetbrains" | "sublime"
+
+/**
+ * A folder found in another application's recent projects.
+ */
+export type ImportCandidate = { path: string; name: string; 
+/**
+ * Every application that lists this folder
+ */
+sources: ImportSource[]; 
+/**
+ * Scope whose default folder contains this path, if any
+ */
+suggestedScopeId: string | null; 
+/**
+ * Whether a project with this path is already registered
+ */
+alreadyAdded: boolean }
+
+/**
+ * A candidate the user chose to import into a scope.
+ */
+export type ExternalProjectImport = { path: string; scopeId: string; 
+/**
+ * Project name (defaults to the folder name)
+ */
+name: string | null }
+
+/**
+ * Outcome of an import run.
+ */
+export type ImportResult = { 
+/**
+ * IDs of newly created projects
+ */
+imported: string[]; 
+/**
+ * Paths skipped because a project with that path already exists
+ */
+skippedExisting: string[]; 
+/**
+ * Paths skipped because the folder no longer exists
+ */
+skippedMissing: string[] }
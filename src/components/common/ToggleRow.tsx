@@ -17,6 +17,7 @@ export function ToggleRow({
 }: ToggleRowProps) {
   return (
     <button
+      type="button"
       onClick={() => !disabled && onChange(!checked)}
       disabled={disabled}
       className={cn(
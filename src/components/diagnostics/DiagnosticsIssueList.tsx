@@ -1,6 +1,6 @@
 import type { ReactElement } from "react";
 import { useMemo } from "react";
-import { AlertCircle, AlertTriangle, Info, GitBranch, Folder, Shield, Activity } from "lucide-react";
+import { AlertCircle, AlertTriangle, Info, GitBranch, Folder, Shield, Activity, ClipboardCheck } from "lucide-react";
 import { cn } from "../../lib/utils";
 import { DiagnosticsIssueCard } from "./DiagnosticsIssueCard";
 import type { DiagnosticIssue, RuleGroup, Severity } from "../../types";
@@ -57,6 +57,11 @@ const ruleGroups: Record<RuleGroup, GroupInfo> = {
     label: "Security",
     color: "text-red-600 dark:text-red-400",
   },
+  policy: {
+    icon: <ClipboardCheck className="h-3.5 w-3.5" />,
+    label: "Scope Policies",
+    color: "text-teal-600 dark:text-teal-400",
+  },
 };
 
 function getGroupInfo(groupKey: string, groupBy: string): GroupInfo {
@@ -84,7 +89,7 @@ function getGroupInfo(groupKey: string, groupBy: string): GroupInfo {
 }
 
 const severityOrder: Severity[] = ["error", "warning", "info"];
-const ruleGroupOrder: RuleGroup[] = ["git", "repo", "project", "security"];
+const ruleGroupOrder: RuleGroup[] = ["git", "repo", "project", "security", "policy"];
 
 function sortGroupKeys(keys: string[], groupBy: string): string[] {
   if (groupBy === "severity") {
@@ -8,6 +8,7 @@ import {
   DialogFooter,
 } from "../ui/Dialog";
 import { Button } from "../ui/Button";
+import { Input } from "../ui/Input";
 import { SelectableOptionCard } from "../ui/SelectableOptionCard";
 import { useDiagnosticsStore } from "../../stores/diagnostics";
 import { useProjectsStore } from "../../stores/projects";
@@ -26,6 +27,8 @@ import {
   ExternalLink,
   GitPullRequest,
   GitBranch,
+  Archive,
+  PencilLine,
 } from "lucide-react";
 
 interface FixDiagnosticDialogProps {
@@ -41,6 +44,18 @@ interface FixOption {
   icon: React.ReactNode;
   fixType: string;
   params?: Record<string, unknown>;
+  /** Text the user must enter, sent as `params[input.param]` */
+  input?: { param: string; label: string; placeholder?: string };
+}
+
+/** Where the default-branch rule read the branch name from */
+function defaultBranchSource(issue: DiagnosticIssue): string | null {
+  const metadata = issue.metadata;
+  if (metadata && typeof metadata === "object" && !Array.isArray(metadata)) {
+    const source = metadata.source;
+    return typeof source === "string" ? source : null;
+  }
+  return null;
 }
 
 function getFixOptionsForIssue(issue: DiagnosticIssue | null): FixOption[] {
@@ -165,6 +180,56 @@ function getFixOptionsForIssue(issue: DiagnosticIssue | null): FixOption[] {
         },
       ];
 
+    case "policy/folder-layout":
+      return [
+        {
+          id: "move_to_layout",
+          title: "Move to layout folder",
+          description: `Move the project to ${issue.expectedValue}`,
+          icon: <FolderInput className="h-4 w-4" />,
+          fixType: "move_to_layout",
+          params: { dirtyPolicy: "refuse" },
+        },
+        {
+          id: "move_to_layout_stash",
+          title: "Stash changes and move",
+          description: "Stash uncommitted changes first, then move the project",
+          icon: <Archive className="h-4 w-4" />,
+          fixType: "move_to_layout",
+          params: { dirtyPolicy: "stash" },
+        },
+      ];
+
+    case "policy/repo-naming":
+      return [
+        {
+          id: "rename_folder",
+          title: "Rename folder",
+          description: `Rename the folder to match ${issue.expectedValue}`,
+          icon: <PencilLine className="h-4 w-4" />,
+          fixType: "rename_folder",
+          params: { dirtyPolicy: "refuse" },
+          input: {
+            param: "name",
+            label: "New folder name",
+            placeholder: issue.actualValue ?? undefined,
+          },
+        },
+      ];
+
+    case "policy/default-branch":
+      // A remote's default branch has to be changed on the remote itself
+      if (defaultBranchSource(issue) === "remote") return [];
+      return [
+        {
+          id: "rename_branch",
+          title: "Rename branch",
+          description: `Rename "${issue.actualValue}" to "${issue.expectedValue}"`,
+          icon: <GitBranch className="h-4 w-4" />,
+          fixType: "rename_branch",
+        },
+      ];
+
     default:
       return [];
   }
@@ -177,6 +242,8 @@ export function FixDiagnosticDialog({
 }: FixDiagnosticDialogProps) {
   const [selectedFix, setSelectedFix] = useState<string | null>(null);
   const [loading, setLoading] = useState(false);
+  const [inputValue, setInputValue] = useState("");
+  const [error, setError] = useState<string | null>(null);
 
   const { fixIssue, dismissIssue } = useDiagnosticsStore();
   const { allProjects } = useProjectsStore();
@@ -213,8 +280,13 @@ export function FixDiagnosticDialog({
     } else if (!open) {
       setSelectedFix(null);
     }
+    setInputValue("");
+    setError(null);
   }, [open, fixOptions]);
 
+  const selectedOption = fixOptions.find((o) => o.id === selectedFix);
+  const missingInput = !!selectedOption?.input && !inputValue.trim();
+
   const handleApplyFix = async () => {
     if (!issue || !selectedFix) return;
 
@@ -222,6 +294,7 @@ export function FixDiagnosticDialog({
     if (!option) return;
 
     setLoading(true);
+    setError(null);
     try {
       // Handle open_editor client-side - don't call backend, just open and close dialog
       if (option.fixType === "open_editor") {
@@ -233,17 +306,22 @@ export function FixDiagnosticDialog({
         return;
       }
 
+      const params = option.input
+        ? { ...option.params, [option.input.param]: inputValue.trim() }
+        : option.params;
+
       const fix: DiagnosticFix = {
         issueId: issue.id,
         ruleId: issue.ruleId,
         fixType: option.fixType,
-        params: (option.params as JsonValue) || null,
+        params: (params as JsonValue) || null,
       };
 
       await fixIssue(fix, issue.scopeId);
       onOpenChange(false);
     } catch (error) {
       console.error("Failed to apply fix:", error);
+      setError(String(error));
     } finally {
       setLoading(false);
     }
@@ -309,8 +387,23 @@ export function FixDiagnosticDialog({
                   icon={option.icon}
                   title={option.title}
                   description={option.description}
-                />
+                >
+                  {option.input && selectedFix === option.id && (
+                    <Input
+                      value={inputValue}
+                      onChange={(e) => setInputValue(e.target.value)}
+                      placeholder={option.input.placeholder}
+                      aria-label={option.input.label}
+                      className="h-8"
+                    />
+                  )}
+                </SelectableOptionCard>
               ))}
+              {error && (
+                <p className="text-[11px] text-red-600 dark:text-red-400 break-words">
+                  {error}
+                </p>
+              )}
             </div>
           ) : (
             <div className="text-center py-4">
@@ -343,7 +436,7 @@ export function FixDiagnosticDialog({
                 <Button
                   onClick={handleApplyFix}
                   loading={loading}
-                  disabled={!selectedFix}
+                  disabled={!selectedFix || missingInput}
                   variant="glass-scope"
                 >
                   Apply Fix
@@ -1,8 +1,9 @@
 import { ConfirmDialog } from "../ui/ConfirmDialog";
 import { useProjectsStore } from "../../stores/projects";
-import { useState } from "react";
+import { useEffect, useState } from "react";
+import { preflightFolderOperation } from "../../lib/tauri";
 import type { ProjectWithStatus } from "../../types";
-import { Folder } from "lucide-react";
+import { AlertTriangle, Folder } from "lucide-react";
 
 interface DeleteProjectDialogProps {
   project: ProjectWithStatus | null;
@@ -16,17 +17,53 @@ export function DeleteProjectDialog({
   onOpenChange,
 }: DeleteProjectDialogProps) {
   const [loading, setLoading] = useState(false);
+  const [risks, setRisks] = useState<string[]>([]);
+  const [isGitRepo, setIsGitRepo] = useState(true);
+  const [checking, setChecking] = useState(false);
+  const [error, setError] = useState<string | null>(null);
   const { deleteProjectWithFolder } = useProjectsStore();
 
+  // Check for uncommitted or unpushed work the delete would lose
+  useEffect(() => {
+    if (!open || !project) return;
+
+    setRisks([]);
+    setIsGitRepo(true);
+    setError(null);
+    setChecking(true);
+    let cancelled = false;
+    preflightFolderOperation([project.project.id], "delete")
+      .then(([preflight]) => {
+        if (cancelled) return;
+        setRisks(preflight?.risks ?? []);
+        setIsGitRepo(
+          !preflight?.inspection.exists || preflight.inspection.isGitRepo
+        );
+      })
+      .catch((err) => console.error("Failed to check project folder:", err))
+      .finally(() => {
+        if (!cancelled) setChecking(false);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [open, project]);
+
   const handleDelete = async () => {
-    if (!project) return;
+    if (!project || checking) return;
 
     setLoading(true);
+    setError(null);
     try {
-      await deleteProjectWithFolder(project.project.id);
+      // The risks are listed above, so confirming means deleting anyway
+      await deleteProjectWithFolder(
+        project.project.id,
+        risks.length > 0 ? "force" : "refuse"
+      );
       onOpenChange(false);
-    } catch (error) {
-      console.error("Failed to delete project:", error);
+    } catch (err) {
+      console.error("Failed to delete project:", err);
+      setError(String(err));
     } finally {
       setLoading(false);
     }
@@ -42,8 +79,17 @@ export function DeleteProjectDialog({
       title="Remove Project"
       description="This action cannot be undone."
       variant="danger"
-      confirmLabel={loading ? "Removing..." : "Remove"}
+      confirmLabel={
+        loading
+          ? "Removing..."
+          : checking
+            ? "Checking..."
+            : risks.length > 0
+              ? "Remove Anyway"
+              : "Remove"
+      }
       loading={loading}
+      confirmDisabled={checking}
       onConfirm={handleDelete}
       maxWidth="sm:max-w-[450px]"
     >
@@ -62,8 +108,36 @@ export function DeleteProjectDialog({
             <p className="text-[11px] text-muted-foreground mt-1 break-all">
               {displayPath}
             </p>
+            {!isGitRepo && (
+              <p className="text-[11px] text-muted-foreground mt-1">
+                This folder is not a git repository, so nothing is backed up
+                on a remote.
+              </p>
+            )}
           </div>
         </div>
+
+        {risks.length > 0 && (
+          <div className="flex items-start gap-2 p-3 rounded-lg bg-amber-500/5 border border-amber-500/10">
+            <AlertTriangle className="h-4 w-4 text-amber-500 mt-0.5 shrink-0" />
+            <div className="min-w-0">
+              <p className="text-[12px] font-medium text-amber-600 dark:text-amber-400">
+                Work in this folder will be lost
+              </p>
+              <ul className="text-[11px] text-muted-foreground mt-1 space-y-0.5">
+                {risks.map((risk) => (
+                  <li key={risk}>{risk}</li>
+                ))}
+              </ul>
+            </div>
+          </div>
+        )}
+
+        {error && (
+          <p className="text-[11px] text-red-600 dark:text-red-400 break-words">
+            {error}
+          </p>
+        )}
       </div>
     </ConfirmDialog>
   );
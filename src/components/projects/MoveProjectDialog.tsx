@@ -12,9 +12,13 @@ import { Input } from "../ui/Input";
 import { SelectableOptionCard } from "../ui/SelectableOptionCard";
 import { useProjectsStore } from "../../stores/projects";
 import { useScopesStore } from "../../stores/scopes";
-import { checkFolderExists } from "../../lib/tauri";
+import { checkFolderExists, preflightFolderOperation } from "../../lib/tauri";
 import { open } from "@tauri-apps/plugin-dialog";
-import type { ProjectWithStatus, ScopeWithLinks } from "../../types";
+import type {
+  DirtyWorkPolicy,
+  ProjectWithStatus,
+  ScopeWithLinks,
+} from "../../types";
 import {
   ArrowRightLeft,
   FolderInput,
@@ -23,6 +27,7 @@ import {
   Loader2,
   AlertTriangle,
   FolderPlus,
+  Archive,
 } from "lucide-react";
 
 type MoveOption =
@@ -54,6 +59,10 @@ export function MoveProjectDialog({
   const [folderConflict, setFolderConflict] = useState(false);
   const [loading, setLoading] = useState(false);
   const [checkingConflict, setCheckingConflict] = useState(false);
+  const [moveRisks, setMoveRisks] = useState<string[]>([]);
+  const [checkingRisks, setCheckingRisks] = useState(false);
+  const [dirtyPolicy, setDirtyPolicy] =
+    useState<Exclude<DirtyWorkPolicy, "refuse">>("stash");
 
   const { moveProjectToScopeWithFolder } = useProjectsStore();
   const { updateScope } = useScopesStore();
@@ -118,6 +127,35 @@ export function MoveProjectDialog({
     return () => clearTimeout(debounce);
   }, [isOpen, targetScope, newFolderName, projectFolderName, selectedOption]);
 
+  // Check for uncommitted changes that a physical move would stash
+  useEffect(() => {
+    setMoveRisks([]);
+    setDirtyPolicy("stash");
+    if (!isOpen || !project || scenario === 4) {
+      setCheckingRisks(false);
+      return;
+    }
+
+    setCheckingRisks(true);
+    let cancelled = false;
+    preflightFolderOperation([project.project.id], "move")
+      .then(([preflight]) => {
+        if (!cancelled) setMoveRisks(preflight?.risks ?? []);
+      })
+      .catch((err) => console.error("Failed to check project folder:", err))
+      .finally(() => {
+        if (!cancelled) setCheckingRisks(false);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [isOpen, project, scenario]);
+
+  const movesFolder =
+    selectedOption === "move_to_target" ||
+    selectedOption === "move_to_custom" ||
+    selectedOption === "set_target_folder";
+
   const handleBrowseFolder = async () => {
     const selected = await open({
       directory: true,
@@ -162,7 +200,8 @@ export function MoveProjectDialog({
         project.project.id,
         targetScope.scope.id,
         targetFolderPath,
-        folderName
+        folderName,
+        moveRisks.length > 0 ? dirtyPolicy : "refuse"
       );
 
       onOpenChange(false);
@@ -405,6 +444,29 @@ export function MoveProjectDialog({
               />
             </>
           )}
+
+          {movesFolder && moveRisks.length > 0 && (
+            <div className="space-y-2 pt-1">
+              <p className="text-[11px] text-amber-600 dark:text-amber-400 flex items-start gap-1">
+                <AlertTriangle className="h-3 w-3 mt-0.5 shrink-0" />
+                The folder has {moveRisks.join(", ")}.
+              </p>
+              <SelectableOptionCard
+                selected={dirtyPolicy === "stash"}
+                onClick={() => setDirtyPolicy("stash")}
+                icon={<Archive className="h-4 w-4" />}
+                title="Stash changes first"
+                description="Save the changes with git stash, then move the folder"
+              />
+              <SelectableOptionCard
+                selected={dirtyPolicy === "force"}
+                onClick={() => setDirtyPolicy("force")}
+                icon={<AlertTriangle className="h-4 w-4" />}
+                title="Move with changes"
+                description="Leave the working tree as it is and move the folder anyway"
+              />
+            </div>
+          )}
         </div>
 
         <DialogFooter>
@@ -418,6 +480,7 @@ export function MoveProjectDialog({
             onClick={handleConfirm}
             loading={loading}
             disabled={
+              (movesFolder && checkingRisks) ||
               (selectedOption === "move_to_target" && folderConflict) ||
               ((selectedOption === "move_to_custom" || selectedOption === "set_target_folder") && !customFolderPath)
             }
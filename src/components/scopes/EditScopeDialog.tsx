@@ -26,6 +26,7 @@ import {
   AlertTriangle,
   Package,
   UserCircle,
+  CloudDownload,
 } from "lucide-react";
 import { open as openDialog } from "@tauri-apps/plugin-dialog";
 import { SshAliasDialog } from "../ssh/SshAliasDialog";
@@ -37,6 +38,7 @@ import {
   FolderTab,
   IdentityTab,
   TempProjectsTab,
+  FetchTab,
   DangerTab,
 } from "./settings";
 
@@ -194,6 +196,12 @@ export function EditScopeDialog({
               >
                 Folder
               </TabTrigger>
+              <TabTrigger
+                value="fetch"
+                icon={<CloudDownload className="h-4 w-4" />}
+              >
+                Fetch
+              </TabTrigger>
               <TabTrigger value="links" icon={<LinkIcon className="h-4 w-4" />}>
                 Links
               </TabTrigger>
@@ -249,6 +257,10 @@ export function EditScopeDialog({
                   />
                 </Tabs.Content>
 
+                <Tabs.Content value="fetch" className="px-6 pt-2 pb-6">
+                  {scope && <FetchTab scopeId={scope.scope.id} />}
+                </Tabs.Content>
+
                 <Tabs.Content
                   value="links"
                   className="px-6 pt-2 pb-6 overflow-hidden"
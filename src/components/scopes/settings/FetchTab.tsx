@@ -0,0 +1,142 @@
+import { useEffect, useState } from "react";
+import { cn } from "../../../lib/utils";
+import { Section, FormHint, ToggleRow } from "../../common";
+import { CloudDownload, RefreshCw } from "lucide-react";
+import {
+  fetchScopeNow,
+  getScopeFetchPolicy,
+  setScopeFetchPolicy,
+} from "../../../lib/tauri";
+import type { ScopeFetchPolicy } from "../../../types";
+
+interface FetchTabProps {
+  scopeId: string;
+}
+
+export function FetchTab({ scopeId }: FetchTabProps) {
+  const [policy, setPolicy] = useState<ScopeFetchPolicy | null>(null);
+  const [fetching, setFetching] = useState(false);
+  const [message, setMessage] = useState<string | null>(null);
+
+  useEffect(() => {
+    getScopeFetchPolicy(scopeId)
+      .then(setPolicy)
+      .catch((err) => setMessage(String(err)));
+  }, [scopeId]);
+
+  const update = async (changes: Partial<ScopeFetchPolicy>) => {
+    if (!policy) return;
+    const next = { ...policy, ...changes };
+    setPolicy(next);
+    try {
+      await setScopeFetchPolicy(next);
+    } catch (err) {
+      setMessage(String(err));
+    }
+  };
+
+  const handleFetchNow = async () => {
+    setFetching(true);
+    setMessage(null);
+    try {
+      const result = await fetchScopeNow(scopeId);
+      setMessage(
+        `Fetched ${result.fetched} projects (${result.failed} failed, ${result.changed} changed)`
+      );
+    } catch (err) {
+      setMessage(String(err));
+    } finally {
+      setFetching(false);
+    }
+  };
+
+  if (!policy) return null;
+
+  return (
+    <div className="space-y-6">
+      <Section
+        title="Background Fetch"
+        icon={<CloudDownload className="h-4 w-4" />}
+      >
+        <div className="space-y-4">
+          <ToggleRow
+            label="Fetch in the background"
+            description="Periodically run git fetch for this scope's projects so ahead/behind counts stay current"
+            checked={policy.enabled}
+            onChange={(enabled) => update({ enabled })}
+          />
+
+          <div className="space-y-2">
+            <label className="text-[12px] text-muted-foreground block">
+              Interval
+            </label>
+            <div className="flex items-center gap-3">
+              <select
+                value={policy.intervalMinutes}
+                disabled={!policy.enabled}
+                onChange={(e) =>
+                  update({ intervalMinutes: Number(e.target.value) })
+                }
+                className={cn(
+                  "flex-1 px-3 py-2 rounded-md text-[13px]",
+                  "bg-white dark:bg-white/5",
+                  "border border-black/10 dark:border-white/10",
+                  "focus:outline-none focus:ring-2 focus:ring-primary/50",
+                  "disabled:opacity-50"
+                )}
+              >
+                <option value={15}>Every 15 minutes</option>
+                <option value={30}>Every 30 minutes</option>
+                <option value={60}>Every hour</option>
+                <option value={240}>Every 4 hours</option>
+              </select>
+              <button
+                type="button"
+                onClick={handleFetchNow}
+                disabled={fetching}
+                className={cn(
+                  "flex items-center gap-1.5 px-3 py-2 rounded-md text-[12px]",
+                  "bg-primary/10 text-primary",
+                  "hover:bg-primary/20 transition-colors",
+                  "disabled:opacity-50"
+                )}
+              >
+                <RefreshCw
+                  className={cn("h-3.5 w-3.5", fetching && "animate-spin")}
+                />
+                {fetching ? "Fetching..." : "Fetch Now"}
+              </button>
+            </div>
+            <FormHint>
+              Fetches never prompt for credentials; repositories that need
+              interactive sign-in are skipped
+            </FormHint>
+          </div>
+
+          <div className="space-y-2 pt-2 border-t border-black/5 dark:border-white/5">
+            <ToggleRow
+              label="Skip on battery"
+              description="Don't fetch while running on battery power"
+              checked={policy.skipOnBattery}
+              onChange={(skipOnBattery) => update({ skipOnBattery })}
+              disabled={!policy.enabled}
+            />
+            <ToggleRow
+              label="Skip on metered connections"
+              description="Don't fetch on metered networks"
+              checked={policy.skipOnMetered}
+              onChange={(skipOnMetered) => update({ skipOnMetered })}
+              disabled={!policy.enabled}
+            />
+          </div>
+
+          {message && (
+            <p className="text-[11px] text-muted-foreground break-words">
+              {message}
+            </p>
+          )}
+        </div>
+      </Section>
+    </div>
+  );
+}
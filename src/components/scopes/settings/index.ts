@@ -3,3 +3,4 @@ export { FolderTab } from "./FolderTab";
 export { IdentityTab } from "./IdentityTab";
 export { TempProjectsTab } from "./TempProjectsTab";
 export { DangerTab } from "./DangerTab";
+export { FetchTab } from "./FetchTab";
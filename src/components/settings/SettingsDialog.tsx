@@ -11,6 +11,7 @@ import {
   Droplet,
   Activity,
   Terminal,
+  UserCircle,
 } from "lucide-react";
 import {
   GeneralSettingsSection,
@@ -19,6 +20,7 @@ import {
   TerminalsSettingsSection,
   ShortcutsSettingsSection,
   DiagnosticsSettingsSection,
+  AccountsSettingsSection,
   MaxSettingsSection,
 } from "./sections";
 
@@ -75,6 +77,12 @@ export function SettingsDialog({ open, onOpenChange }: SettingsDialogProps) {
             >
               Diagnostics
             </TabTrigger>
+            <TabTrigger
+              value="accounts"
+              icon={<UserCircle className="h-4 w-4" />}
+            >
+              Accounts
+            </TabTrigger>
             <TabTrigger value="max" icon={<Sparkles className="h-4 w-4" />}>
               Max
             </TabTrigger>
@@ -105,6 +113,9 @@ export function SettingsDialog({ open, onOpenChange }: SettingsDialogProps) {
               <Tabs.Content value="diagnostics" className="px-6 pt-2 pb-6">
                 <DiagnosticsSettingsSection />
               </Tabs.Content>
+              <Tabs.Content value="accounts" className="px-6 pt-2 pb-6">
+                <AccountsSettingsSection />
+              </Tabs.Content>
               <Tabs.Content value="max" className="px-6 pt-2 pb-6">
                 <MaxSettingsSection />
               </Tabs.Content>
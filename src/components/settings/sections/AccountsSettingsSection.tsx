@@ -0,0 +1,158 @@
+import { useEffect, useState } from "react";
+import { openUrl } from "@tauri-apps/plugin-opener";
+import { KeyRound, Trash2, UserCircle } from "lucide-react";
+import { useSettingsStore } from "../../../stores/settings";
+import {
+  completeProviderAuth,
+  getProviderAccounts,
+  removeProviderAccount,
+  startProviderAuth,
+} from "../../../lib/tauri";
+import type {
+  DeviceAuthorization,
+  ProviderAccount,
+  ProviderKind,
+} from "../../../types";
+import { Section } from "../../common";
+import { Button } from "../../ui/Button";
+import { Input } from "../../ui/Input";
+
+const PROVIDERS: {
+  kind: ProviderKind;
+  label: string;
+  setting: "github_oauth_client_id" | "gitlab_oauth_client_id";
+}[] = [
+  { kind: "github", label: "GitHub", setting: "github_oauth_client_id" },
+  { kind: "gitlab", label: "GitLab", setting: "gitlab_oauth_client_id" },
+];
+
+export function AccountsSettingsSection() {
+  const { settings, updateSetting } = useSettingsStore();
+  const [accounts, setAccounts] = useState<ProviderAccount[]>([]);
+  const [pending, setPending] = useState<DeviceAuthorization | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const refreshAccounts = () =>
+    getProviderAccounts()
+      .then(setAccounts)
+      .catch((err) => setError(String(err)));
+
+  useEffect(() => {
+    refreshAccounts();
+  }, []);
+
+  const handleSignIn = async (provider: ProviderKind) => {
+    setError(null);
+    try {
+      const authorization = await startProviderAuth(provider);
+      setPending(authorization);
+      await openUrl(
+        authorization.verificationUriComplete ?? authorization.verificationUri
+      );
+      await completeProviderAuth(authorization);
+      await refreshAccounts();
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setPending(null);
+    }
+  };
+
+  const handleRemove = async (accountId: string) => {
+    setError(null);
+    try {
+      await removeProviderAccount(accountId);
+      await refreshAccounts();
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  return (
+    <div className="space-y-6">
+      <Section title="OAuth Applications" icon={<KeyRound className="h-4 w-4" />}>
+        <p className="text-[12px] text-muted-foreground mb-3">
+          Sign-in uses the device flow of an OAuth application you register
+          with each provider. Paste its client ID here.
+        </p>
+        <div className="space-y-2">
+          {PROVIDERS.map(({ kind, label, setting }) => (
+            <div key={kind} className="flex items-center gap-3">
+              <span className="w-16 text-[12px] text-muted-foreground">
+                {label}
+              </span>
+              <Input
+                defaultValue={settings[setting]}
+                placeholder="Client ID"
+                onBlur={(e) => {
+                  const value = e.target.value.trim();
+                  if (value !== settings[setting]) {
+                    updateSetting(setting, value);
+                  }
+                }}
+              />
+            </div>
+          ))}
+        </div>
+      </Section>
+
+      <Section title="Accounts" icon={<UserCircle className="h-4 w-4" />}>
+        <div className="space-y-2">
+          {accounts.map((account) => (
+            <div
+              key={account.id}
+              className="flex items-center justify-between px-3 py-2 rounded-lg bg-black/[0.02] dark:bg-white/[0.02] border border-black/5 dark:border-white/5"
+            >
+              <div className="min-w-0">
+                <p className="text-[13px] font-medium truncate">
+                  {account.username}
+                </p>
+                <p className="text-[11px] text-muted-foreground">
+                  {account.host}
+                </p>
+              </div>
+              <Button
+                variant="ghost"
+                size="icon-sm"
+                onClick={() => handleRemove(account.id)}
+              >
+                <Trash2 className="h-3.5 w-3.5" />
+              </Button>
+            </div>
+          ))}
+
+          {pending ? (
+            <div className="p-3 rounded-lg bg-primary/5 border border-primary/10">
+              <p className="text-[12px] text-muted-foreground">
+                Enter this code on {pending.verificationUri}:
+              </p>
+              <p className="text-[16px] font-mono font-semibold mt-1 select-all">
+                {pending.userCode}
+              </p>
+            </div>
+          ) : (
+            <div className="flex gap-2">
+              {PROVIDERS.map(({ kind, label, setting }) => (
+                <Button
+                  key={kind}
+                  variant="glass"
+                  size="sm"
+                  disabled={!settings[setting]}
+                  onClick={() => handleSignIn(kind)}
+                >
+                  Sign in to {label}
+                </Button>
+              ))}
+            </div>
+          )}
+
+          {error && (
+            <p className="text-[11px] text-red-600 dark:text-red-400 break-words">
+              {error}
+            </p>
+          )}
+        </div>
+      </Section>
+    </div>
+  );
+}
@@ -1,5 +1,5 @@
 import { useEffect } from "react";
-import { Activity, Folder, GitBranch, Shield } from "lucide-react";
+import { Activity, ClipboardCheck, Folder, GitBranch, Shield } from "lucide-react";
 import { useSettingsStore } from "../../../stores/settings";
 import { useDiagnosticsStore } from "../../../stores/diagnostics";
 import { Section, ToggleRow, ToggleSwitch } from "../../common";
@@ -32,6 +32,11 @@ const RULE_GROUP_INFO: Record<
     description: "Secrets, credentials, and access",
     icon: Shield,
   },
+  policy: {
+    name: "Scope Policies",
+    description: "Folder layout, naming, branch, and remote conventions",
+    icon: ClipboardCheck,
+  },
 };
 
 export function DiagnosticsSettingsSection() {
@@ -4,4 +4,5 @@ export { EditorsSettingsSection } from "./EditorsSettingsSection";
 export { TerminalsSettingsSection } from "./TerminalsSettingsSection";
 export { ShortcutsSettingsSection } from "./ShortcutsSettingsSection";
 export { DiagnosticsSettingsSection } from "./DiagnosticsSettingsSection";
+export { AccountsSettingsSection } from "./AccountsSettingsSection";
 export { MaxSettingsSection } from "./MaxSettingsSection";
@@ -22,6 +22,7 @@ interface ConfirmDialogProps {
   confirmLabel?: string;
   cancelLabel?: string;
   loading?: boolean;
+  confirmDisabled?: boolean;
   onConfirm: () => void;
   maxWidth?: string;
 }
@@ -57,6 +58,7 @@ export function ConfirmDialog({
   confirmLabel = "Confirm",
   cancelLabel = "Cancel",
   loading = false,
+  confirmDisabled = false,
   onConfirm,
   maxWidth = "sm:max-w-[400px]",
 }: ConfirmDialogProps) {
@@ -104,6 +106,7 @@ export function ConfirmDialog({
             variant={config.buttonVariant}
             onClick={onConfirm}
             loading={loading}
+            disabled={confirmDisabled}
           >
             {confirmLabel}
           </Button>
@@ -11,8 +11,10 @@ import type {
   CreateScopeLinkRequest,
   CreateScopeRequest,
   CreateSshAliasRequest,
+  DirtyWorkPolicy,
   Editor,
   EditorInfo,
+  FolderOperation,
   GitIncludeIf,
   GitStatusCache,
   GpgSigningMethod,
@@ -21,6 +23,7 @@ import type {
   ProjectCommand,
   ProjectGroup,
   ProjectLink,
+  ProjectPreflight,
   ProjectStatistics,
   ProjectWithStatus,
   Scope,
@@ -99,6 +102,18 @@ export async function getAllProjects(): Promise<ProjectWithStatus[]> {
   return invoke("get_all_projects");
 }
 
+export async function searchProjects(
+  query: string,
+  scopeId?: string,
+  limit?: number
+): Promise<ProjectWithStatus[]> {
+  return invoke("search_projects", {
+    query,
+    scopeId: scopeId ?? null,
+    limit: limit ?? null,
+  });
+}
+
 export async function createProject(
   request: CreateProjectRequest
 ): Promise<Project> {
@@ -129,8 +144,14 @@ export async function deleteProject(id: string): Promise<void> {
   return invoke("delete_project", { id });
 }
 
-export async function deleteProjectWithFolder(id: string): Promise<void> {
-  return invoke("delete_project_with_folder", { id });
+export async function deleteProjectWithFolder(
+  id: string,
+  dirtyPolicy?: DirtyWorkPolicy
+): Promise<void> {
+  return invoke("delete_project_with_folder", {
+    id,
+    dirtyPolicy: dirtyPolicy ?? null,
+  });
 }
 
 export async function updateProjectLastOpened(id: string): Promise<void> {
@@ -151,16 +172,31 @@ export async function moveProjectToScopeWithFolder(
   projectId: string,
   newScopeId: string,
   targetFolder?: string,
-  folderName?: string
+  folderName?: string,
+  dirtyPolicy?: DirtyWorkPolicy
 ): Promise<string> {
   return invoke("move_project_to_scope_with_folder", {
     projectId,
     newScopeId,
     targetFolder,
     folderName,
+    dirtyPolicy: dirtyPolicy ?? null,
   });
 }
 
+export async function preflightFolderOperation(
+  projectIds: string[],
+  operation: FolderOperation
+): Promise<ProjectPreflight[]> {
+  return invoke("preflight_folder_operation", { projectIds, operation });
+}
+
+export async function stashProjectChanges(
+  projectIds: string[]
+): Promise<string[]> {
+  return invoke("stash_project_changes", { projectIds });
+}
+
 // Project Tags
 export async function addProjectTag(
   projectId: string,
@@ -297,9 +333,10 @@ export async function unpinProject(projectId: string): Promise<void> {
 
 // Project Statistics
 export async function getProjectStatistics(
+  projectId: string,
   projectPath: string
 ): Promise<ProjectStatistics> {
-  return invoke("get_project_statistics", { projectPath });
+  return invoke("get_project_statistics", { projectId, projectPath });
 }
 
 // Terminal
@@ -565,6 +602,129 @@ export function onCloneProgress(
   });
 }
 
+// Clone Queue
+import type { CloneJob, CloneRequest, CredentialRequest } from "../types";
+
+export async function queueClone(request: CloneRequest): Promise<CloneJob> {
+  return invoke("queue_clone", { request });
+}
+
+export async function getCloneJobs(): Promise<CloneJob[]> {
+  return invoke("get_clone_jobs");
+}
+
+export async function cancelClone(jobId: string): Promise<boolean> {
+  return invoke("cancel_clone", { jobId });
+}
+
+export async function answerCloneCredential(
+  promptId: string,
+  answer?: string | null
+): Promise<void> {
+  return invoke("answer_clone_credential", { promptId, answer: answer ?? null });
+}
+
+export async function clearFinishedClones(): Promise<number> {
+  return invoke("clear_finished_clones");
+}
+
+export function onCloneJobProgress(
+  callback: (job: CloneJob) => void
+): Promise<UnlistenFn> {
+  return listen<CloneJob>("clone-job-progress", (event) => {
+    callback(event.payload);
+  });
+}
+
+export function onCloneCredentialRequest(
+  callback: (request: CredentialRequest) => void
+): Promise<UnlistenFn> {
+  return listen<CredentialRequest>("clone-credential-request", (event) => {
+    callback(event.payload);
+  });
+}
+
+// Git Providers
+import type {
+  CloneRemoteRequest,
+  CloneRemoteResult,
+  DeviceAuthorization,
+  ProjectCiStatus,
+  ProviderAccount,
+  ProviderKind,
+  PullRequestSummary,
+  RemoteRepository,
+  RepositoryFilter,
+} from "../types";
+
+export async function startProviderAuth(
+  provider: ProviderKind,
+  host?: string | null
+): Promise<DeviceAuthorization> {
+  return invoke("start_provider_auth", { provider, host: host ?? null });
+}
+
+export async function completeProviderAuth(
+  authorization: DeviceAuthorization
+): Promise<ProviderAccount> {
+  return invoke("complete_provider_auth", { authorization });
+}
+
+export async function getProviderAccounts(): Promise<ProviderAccount[]> {
+  return invoke("get_provider_accounts");
+}
+
+export async function removeProviderAccount(accountId: string): Promise<void> {
+  return invoke("remove_provider_account", { accountId });
+}
+
+export async function getProviderOwners(accountId: string): Promise<string[]> {
+  return invoke("get_provider_owners", { accountId });
+}
+
+export async function listRemoteRepositories(
+  accountId: string,
+  filter?: RepositoryFilter | null
+): Promise<RemoteRepository[]> {
+  return invoke("list_remote_repositories", { accountId, filter: filter ?? null });
+}
+
+export async function cloneRemoteRepositories(
+  request: CloneRemoteRequest
+): Promise<CloneRemoteResult> {
+  return invoke("clone_remote_repositories", { request });
+}
+
+export async function getProjectCiStatus(
+  projectId: string
+): Promise<ProjectCiStatus | null> {
+  return invoke("get_project_ci_status", { projectId });
+}
+
+export async function getProjectsCiStatus(): Promise<ProjectCiStatus[]> {
+  return invoke("get_projects_ci_status");
+}
+
+export async function getProjectPrs(
+  projectId: string
+): Promise<PullRequestSummary[]> {
+  return invoke("get_project_prs", { projectId });
+}
+
+export async function refreshProjectCiStatus(
+  projectId: string
+): Promise<ProjectCiStatus | null> {
+  return invoke("refresh_project_ci_status", { projectId });
+}
+
+export function onProjectCiStatus(
+  callback: (status: ProjectCiStatus) => void
+): Promise<UnlistenFn> {
+  return listen<ProjectCiStatus>("project-provider-status", (event) => {
+    callback(event.payload);
+  });
+}
+
 // Diagnostics
 import type {
   DiagnosticIssue,
@@ -572,6 +732,7 @@ import type {
   DisabledRule,
   RuleMetadata,
   ScanState,
+  ScopePolicy,
   ScopeDiagnosticsSummary,
 } from "../types";
 
@@ -633,3 +794,267 @@ export async function getDiagnosticRuleMetadata(): Promise<RuleMetadata[]> {
 export async function fixDiagnosticIssue(fix: DiagnosticFix): Promise<void> {
   return invoke("fix_diagnostic_issue", { fix });
 }
+
+export async function getScopePolicy(scopeId: string): Promise<ScopePolicy> {
+  return invoke("get_scope_policy", { scopeId });
+}
+
+export async function setScopePolicy(policy: ScopePolicy): Promise<ScopePolicy> {
+  return invoke("set_scope_policy", { policy });
+}
+
+// Event Subscriptions
+import type { EventSubscription } from "../types";
+
+export async function subscribeEvents(
+  subscription: EventSubscription
+): Promise<void> {
+  return invoke("subscribe_events", { subscription });
+}
+
+export async function unsubscribeEvents(): Promise<void> {
+  return invoke("unsubscribe_events");
+}
+
+export async function getEventSubscription(): Promise<EventSubscription | null> {
+  return invoke("get_event_subscription");
+}
+
+// Telemetry
+import type { TelemetryReport, TelemetryStatus } from "../types";
+
+export async function getTelemetryStatus(): Promise<TelemetryStatus> {
+  return invoke("get_telemetry_status");
+}
+
+export async function setTelemetryConsent(enabled: boolean): Promise<void> {
+  return invoke("set_telemetry_consent", { enabled });
+}
+
+export async function getTelemetryQueue(): Promise<TelemetryReport[]> {
+  return invoke("get_telemetry_queue");
+}
+
+export async function purgeTelemetryQueue(): Promise<number> {
+  return invoke("purge_telemetry_queue");
+}
+
+export async function uploadTelemetryQueue(): Promise<number> {
+  return invoke("upload_telemetry_queue");
+}
+
+// Logging
+import type { LogSettings } from "../types";
+
+export async function getLogSettings(): Promise<LogSettings> {
+  return invoke("get_log_settings");
+}
+
+export async function setLogLevel(
+  target: string | null,
+  level: string | null
+): Promise<LogSettings> {
+  return invoke("set_log_level", { target, level });
+}
+
+export async function getRecentLogs(lines?: number): Promise<string[]> {
+  return invoke("get_recent_logs", { lines: lines ?? null });
+}
+
+// Project Health
+import type { ProjectHealth } from "../types";
+
+export async function getProjectHealth(
+  projectId: string,
+  forceRefresh?: boolean
+): Promise<ProjectHealth> {
+  return invoke("get_project_health", {
+    projectId,
+    forceRefresh: forceRefresh ?? null,
+  });
+}
+
+export async function getProjectsHealth(
+  scopeId: string | null,
+  forceRefresh?: boolean
+): Promise<ProjectHealth[]> {
+  return invoke("get_projects_health", {
+    scopeId,
+    forceRefresh: forceRefresh ?? null,
+  });
+}
+
+export async function invalidateProjectHealth(
+  projectId?: string
+): Promise<void> {
+  return invoke("invalidate_project_health", { projectId: projectId ?? null });
+}
+
+// Project Activity
+import type { ProjectActivity } from "../types";
+
+export async function getProjectActivity(
+  projectId: string,
+  forceRefresh?: boolean
+): Promise<ProjectActivity> {
+  return invoke("get_project_activity", {
+    projectId,
+    forceRefresh: forceRefresh ?? null,
+  });
+}
+
+// Background Fetch
+import type {
+  ScopeFetchPolicy,
+  PlatformConditions,
+  ScopeFetchResult,
+} from "../types";
+
+export async function getScopeFetchPolicy(
+  scopeId: string
+): Promise<ScopeFetchPolicy> {
+  return invoke("get_scope_fetch_policy", { scopeId });
+}
+
+export async function setScopeFetchPolicy(
+  policy: ScopeFetchPolicy
+): Promise<void> {
+  return invoke("set_scope_fetch_policy", { policy });
+}
+
+export async function getFetchConditions(): Promise<PlatformConditions> {
+  return invoke("get_fetch_conditions");
+}
+
+export async function fetchScopeNow(scopeId: string): Promise<ScopeFetchResult> {
+  return invoke("fetch_scope_now", { scopeId });
+}
+
+// Project Templates
+import type {
+  ProjectTemplate,
+  CreateProjectTemplateRequest,
+  UpdateProjectTemplateRequest,
+  CreateFromTemplateRequest,
+} from "../types";
+
+export async function getProjectTemplates(
+  scopeId: string
+): Promise<ProjectTemplate[]> {
+  return invoke("get_project_templates", { scopeId });
+}
+
+export async function createProjectTemplate(
+  request: CreateProjectTemplateRequest
+): Promise<ProjectTemplate> {
+  return invoke("create_project_template", { request });
+}
+
+export async function updateProjectTemplate(
+  templateId: string,
+  request: UpdateProjectTemplateRequest
+): Promise<ProjectTemplate> {
+  return invoke("update_project_template", { templateId, request });
+}
+
+export async function deleteProjectTemplate(templateId: string): Promise<void> {
+  return invoke("delete_project_template", { templateId });
+}
+
+export async function createProjectFromTemplate(
+  request: CreateFromTemplateRequest
+): Promise<Project> {
+  return invoke("create_project_from_template", { request });
+}
+
+// Batch Operations
+// Each call returns a job ID; listen to "batch-progress" and "batch-completed"
+
+export async function moveProjectsToScope(
+  projectIds: string[],
+  newScopeId: string
+): Promise<string> {
+  return invoke("move_projects_to_scope", { projectIds, newScopeId });
+}
+
+export async function addTagToProjects(
+  projectIds: string[],
+  tag: string
+): Promise<string> {
+  return invoke("add_tag_to_projects", { projectIds, tag });
+}
+
+export async function deleteProjects(
+  projectIds: string[],
+  deleteFolders: boolean,
+  dirtyPolicy?: DirtyWorkPolicy
+): Promise<string> {
+  return invoke("delete_projects", {
+    projectIds,
+    deleteFolders,
+    dirtyPolicy: dirtyPolicy ?? null,
+  });
+}
+
+export async function gitFetchProjects(projectIds: string[]): Promise<string> {
+  return invoke("git_fetch_projects", { projectIds });
+}
+
+// Project Archiving
+import type { ProjectArchive } from "../types";
+
+export async function archiveProject(projectId: string): Promise<ProjectArchive> {
+  return invoke("archive_project", { projectId });
+}
+
+export async function unarchiveProject(projectId: string): Promise<string> {
+  return invoke("unarchive_project", { projectId });
+}
+
+export async function getArchivedProjects(
+  scopeId?: string
+): Promise<ProjectArchive[]> {
+  return invoke("get_archived_projects", { scopeId: scopeId ?? null });
+}
+
+// Database Backup
+import type { BackupFormat, BackupSummary, BackupInfo } from "../types";
+
+export async function exportDatabase(
+  path: string,
+  format: BackupFormat
+): Promise<BackupSummary> {
+  return invoke("export_database", { path, format });
+}
+
+export async function importDatabase(path: string): Promise<BackupSummary> {
+  return invoke("import_database", { path });
+}
+
+export async function createBackupNow(): Promise<BackupInfo> {
+  return invoke("create_backup_now");
+}
+
+export async function listBackups(): Promise<BackupInfo[]> {
+  return invoke("list_backups");
+}
+
+// External Project Import
+import type {
+  ImportSource,
+  ImportCandidate,
+  ExternalProjectImport,
+  ImportResult,
+} from "../types";
+
+export async function getExternalProjectCandidates(
+  sources?: ImportSource[]
+): Promise<ImportCandidate[]> {
+  return invoke("get_external_project_candidates", { sources: sources ?? null });
+}
+
+export async function importExternalProjects(
+  imports: ExternalProjectImport[]
+): Promise<ImportResult> {
+  return invoke("import_external_projects", { imports });
+}
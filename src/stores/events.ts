@@ -56,6 +56,7 @@ export type AppEvent =
       payload: { key: string; old_value: string; new_value: string };
     }
   | { type: "MaxFeatureToggled"; payload: { feature: string; enabled: boolean } }
+  | { type: "DatabaseImported"; payload: { path: string } }
   | {
       type: "FolderScanCompleted";
       payload: { scope_id: string; projects_found: string[] };
@@ -120,6 +121,18 @@ function handleAppEvent(event: AppEvent): void {
       useDiagnosticsStore.getState().fetchAllSummaries();
       break;
 
+    case "DatabaseImported": {
+      // Everything may have changed - reload settings, scopes and projects
+      useSettingsStore.getState().fetchSettings();
+      useScopesStore.getState().fetchScopes();
+      const scopeId = useScopesStore.getState().currentScopeId;
+      if (scopeId) {
+        useProjectsStore.getState().fetchProjects(scopeId);
+      }
+      useDiagnosticsStore.getState().fetchAllSummaries();
+      break;
+    }
+
     // Folder scanner events
     case "FolderScanCompleted":
       // Refresh the project list for the scanned scope
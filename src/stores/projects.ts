@@ -4,6 +4,7 @@ import type {
   CreateProjectGroupRequest,
   CreateProjectLinkRequest,
   CreateProjectRequest,
+  DirtyWorkPolicy,
   ProjectCommand,
   ProjectGroup,
   ProjectLink,
@@ -32,13 +33,17 @@ interface ProjectsState {
     workspaceFile?: string
   ) => Promise<void>;
   deleteProject: (id: string) => Promise<void>;
-  deleteProjectWithFolder: (id: string) => Promise<void>;
+  deleteProjectWithFolder: (
+    id: string,
+    dirtyPolicy?: DirtyWorkPolicy
+  ) => Promise<void>;
   moveProjectToScope: (projectId: string, newScopeId: string) => Promise<void>;
   moveProjectToScopeWithFolder: (
     projectId: string,
     newScopeId: string,
     targetFolder?: string,
-    folderName?: string
+    folderName?: string,
+    dirtyPolicy?: DirtyWorkPolicy
   ) => Promise<void>;
 
   // Tags
@@ -235,9 +240,9 @@ export const useProjectsStore = create<ProjectsState>((set, get) => ({
     }
   },
 
-  deleteProjectWithFolder: async (id) => {
+  deleteProjectWithFolder: async (id, dirtyPolicy) => {
     try {
-      await api.deleteProjectWithFolder(id);
+      await api.deleteProjectWithFolder(id, dirtyPolicy);
       set((state) => ({
         projects: state.projects.filter((p) => p.project.id !== id),
         allProjects: state.allProjects.filter((p) => p.project.id !== id),
@@ -272,14 +277,16 @@ export const useProjectsStore = create<ProjectsState>((set, get) => ({
     projectId,
     newScopeId,
     targetFolder,
-    folderName
+    folderName,
+    dirtyPolicy
   ) => {
     try {
       const newPath = await api.moveProjectToScopeWithFolder(
         projectId,
         newScopeId,
         targetFolder,
-        folderName
+        folderName,
+        dirtyPolicy
       );
       set((state) => ({
         projects: state.projects.filter((p) => p.project.id !== projectId),
@@ -643,9 +650,9 @@ export const useProjectsStore = create<ProjectsState>((set, get) => ({
   },
 
   // Project Statistics
-  fetchProjectStatistics: async (_projectId, projectPath) => {
+  fetchProjectStatistics: async (projectId, projectPath) => {
     try {
-      return await api.getProjectStatistics(projectPath);
+      return await api.getProjectStatistics(projectId, projectPath);
     } catch (error) {
       set({ error: String(error) });
       throw error;
@@ -16,6 +16,9 @@ interface Settings {
   // Liquid Glass
   liquid_glass_enabled: boolean;
   liquid_glass_intensity: "subtle" | "medium" | "strong";
+  // Provider accounts
+  github_oauth_client_id: string;
+  gitlab_oauth_client_id: string;
 }
 
 const defaultSettings: Settings = {
@@ -33,6 +36,9 @@ const defaultSettings: Settings = {
   // Liquid Glass - enabled by default
   liquid_glass_enabled: true,
   liquid_glass_intensity: "medium",
+  // Provider accounts - sign-in needs an OAuth app's client ID
+  github_oauth_client_id: "",
+  gitlab_oauth_client_id: "",
 };
 
 interface SettingsState {
@@ -92,6 +98,12 @@ export const useSettingsStore = create<SettingsState>((set, get) => ({
         liquid_glass_intensity:
           (allSettings.liquid_glass_intensity as Settings["liquid_glass_intensity"]) ??
           defaultSettings.liquid_glass_intensity,
+        github_oauth_client_id:
+          (allSettings.github_oauth_client_id as string) ??
+          defaultSettings.github_oauth_client_id,
+        gitlab_oauth_client_id:
+          (allSettings.gitlab_oauth_client_id as string) ??
+          defaultSettings.gitlab_oauth_client_id,
       };
       set({ settings, loading: false });
 
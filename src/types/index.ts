@@ -42,10 +42,79 @@ export type {
   DiagnosticFix,
   DisabledRule,
   ScanState,
+  ScopePolicy,
   ScopeDiagnosticsSummary,
   LastCommitInfo,
   LanguageInfo,
   ContributorInfo,
+  // Events
+  EventCategory,
+  EventSubscription,
+  // Telemetry
+  TelemetryKind,
+  TelemetryReport,
+  TelemetryStatus,
+  // Logging
+  LogSettings,
+  // Project Templates
+  TemplateKind,
+  TemplateVariable,
+  ProjectTemplate,
+  CreateProjectTemplateRequest,
+  UpdateProjectTemplateRequest,
+  CreateFromTemplateRequest,
+  // Project Archiving
+  ArchiveFormat,
+  ProjectArchive,
+  // Database Backup
+  BackupFormat,
+  BackupSummary,
+  BackupInfo,
+  // Batch Operations
+  BatchOperation,
+  BatchItemStatus,
+  BatchProgress,
+  BatchItemError,
+  BatchSummary,
+  // External Project Import
+  ImportSource,
+  ImportCandidate,
+  ExternalProjectImport,
+  ImportResult,
+  // Project Health
+  HealthStatus,
+  HealthDiagnostics,
+  ProjectHealth,
+  // Project Activity
+  ActivityDay,
+  AuthorActivity,
+  ProjectActivity,
+  // Background Fetch
+  ScopeFetchPolicy,
+  PlatformConditions,
+  ScopeFetchResult,
+  // Folder Operation Preflight
+  FolderOperation,
+  DirtyWorkPolicy,
+  WorkInspection,
+  ProjectPreflight,
+  // Clone Queue
+  CloneJobStatus,
+  CloneStage,
+  CloneRequest,
+  CloneJob,
+  CredentialRequest,
+  // Git Providers
+  ProviderKind,
+  DeviceAuthorization,
+  ProviderAccount,
+  RemoteRepository,
+  RepositoryFilter,
+  CloneRemoteRequest,
+  CloneRemoteResult,
+  CiState,
+  ProjectCiStatus,
+  PullRequestSummary,
 } from "../bindings/types";
 
 // Override TempProjectSettings to use strict PackageManager union type